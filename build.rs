@@ -196,18 +196,56 @@ fn to_snake_case(name: &str) -> String {
     result.trim_matches('_').to_string()
 }
 
+/// Enums which also derive `PartialOrd` and `Ord`, so that they can be used as the keys of `BTreeMap`.
+const ORDERED_ENUMS: [&str; 1] = ["Resource"];
+
+/// The max width of a line in the generated code, which is the same as the default `max_width` of rustfmt.
+const MAX_WIDTH: usize = 100;
+
+/// The max width of the arguments of a function-like attribute such as `derive`,
+/// which is the same as the default `attr_fn_like_width` of rustfmt.
+const ATTR_FN_LIKE_WIDTH: usize = 70;
+
+/// Pushes a match arm `pattern => expression,` to `output`, indented by 12 spaces.
+///
+/// When the arm is longer than [`MAX_WIDTH`], the expression is wrapped in a block as rustfmt does,
+/// so that the generated code passes `cargo fmt --check`.
+fn push_match_arm(output: &mut String, pattern: &str, expression: &str) {
+    let arm = format!("            {} => {},", pattern, expression);
+    if arm.len() <= MAX_WIDTH {
+        output.push_str(&arm);
+        output.push('\n');
+    } else {
+        output.push_str(&format!("            {} => {{\n", pattern));
+        output.push_str(&format!("                {}\n", expression));
+        output.push_str("            }\n");
+    }
+}
+
 fn generate_enum_code(enum_name: &str, enum_variants: &[String], names: &[&str]) -> String {
     let mut output = String::new();
     output.push_str("// Auto-generated by build.rs, DO NOT EDIT\n");
     output.push_str("use super::EnumStr;\n"); // Import the EnumStr trait from parent module
+    if enum_name == "Resource" {
+        // Used by `Resource::category()`, see `generate_resource_category_code`
+        output.push_str("use crate::ruleset::ResourceCategory;\n");
+    }
     output.push_str("use enum_map::Enum;\n");
     output.push_str("use serde::{Deserialize, Serialize};\n");
     output.push('\n');
 
     // Generate enum definition with required derives
-    output.push_str(
-        "#[derive(Enum, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize, Debug)]\n",
-    );
+    let derives = if ORDERED_ENUMS.contains(&enum_name) {
+        "Enum, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Serialize, Deserialize, Debug"
+    } else {
+        "Enum, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize, Debug"
+    };
+    // Split the derives into multiple lines as rustfmt does when they are too long.
+    if derives.len() <= ATTR_FN_LIKE_WIDTH {
+        output.push_str(&format!("#[derive({})]\n", derives));
+    } else {
+        output.push_str(&format!("#[derive(\n    {},\n)]\n", derives));
+    }
     output.push_str(&format!("pub enum {} {{\n", enum_name));
 
     // Add enum variants to output
@@ -225,10 +263,11 @@ fn generate_enum_code(enum_name: &str, enum_variants: &[String], names: &[&str])
     output.push_str("        match self {\n");
 
     for (variant, name) in enum_variants.iter().zip(names.iter()) {
-        output.push_str(&format!(
-            "            {}::{} => \"{}\",\n",
-            enum_name, variant, name
-        ));
+        push_match_arm(
+            &mut output,
+            &format!("{}::{}", enum_name, variant),
+            &format!("\"{}\"", name),
+        );
     }
 
     output.push_str("        }\n");
@@ -239,10 +278,11 @@ fn generate_enum_code(enum_name: &str, enum_variants: &[String], names: &[&str])
     output.push_str("        match s {\n");
 
     for (variant, name) in enum_variants.iter().zip(names.iter()) {
-        output.push_str(&format!(
-            "            \"{}\" => {}::{},\n",
-            name, enum_name, variant
-        ));
+        push_match_arm(
+            &mut output,
+            &format!("\"{}\"", name),
+            &format!("{}::{}", enum_name, variant),
+        );
     }
 
     output.push_str("            _ => panic!(\"Invalid value for {}: {{}}\", s),\n");
@@ -296,7 +336,50 @@ fn create_enum_from_json(json_path: PathBuf, dest_path: PathBuf, enum_name: &str
         .collect();
 
     // Generate the Rust code
-    let output = generate_enum_code(enum_name, &enum_variants, &names);
+    let mut output = generate_enum_code(enum_name, &enum_variants, &names);
+
+    // `Resource` also gets its category from the 'resourceType' field
+    if enum_name == "Resource" {
+        let resource_types: Vec<&str> = value_list
+            .iter()
+            .map(|value| {
+                value
+                    .get("resourceType")
+                    .and_then(|v| v.as_str())
+                    .expect("Can't get resourceType")
+            })
+            .collect();
+
+        output.push_str(&generate_resource_category_code(
+            &enum_variants,
+            &resource_types,
+        ));
+    }
+
+    // `BaseTerrain` and `TerrainType` also get their display colors from the 'RGB' field
+    if enum_name == "BaseTerrain" || enum_name == "TerrainType" {
+        let colors: Vec<[u8; 3]> = value_list
+            .iter()
+            .map(|value| {
+                let rgb = value
+                    .get("RGB")
+                    .and_then(|v| v.as_array())
+                    .expect("Can't get RGB");
+                std::array::from_fn(|i| {
+                    rgb.get(i)
+                        .and_then(|v| v.as_u64())
+                        .and_then(|v| u8::try_from(v).ok())
+                        .expect("Invalid RGB")
+                })
+            })
+            .collect();
+
+        output.push_str(&generate_display_color_code(
+            enum_name,
+            &enum_variants,
+            &colors,
+        ));
+    }
 
     // Write generated code to output file
     let mut file = File::create(dest_path).expect("Could not create output file");
@@ -304,6 +387,61 @@ fn create_enum_from_json(json_path: PathBuf, dest_path: PathBuf, enum_name: &str
         .expect("Could not write to file");
 }
 
+/// Generates `display_color()` for the enum, which maps every variant to its 'RGB' in the JSON file.
+fn generate_display_color_code(
+    enum_name: &str,
+    enum_variants: &[String],
+    colors: &[[u8; 3]],
+) -> String {
+    let mut output = String::new();
+    output.push('\n');
+    output.push_str(&format!("impl {} {{\n", enum_name));
+    output.push_str("    /// Returns the default RGB color to render it, shared by the exporters and the UIs.\n");
+    output.push_str("    ///\n");
+    output.push_str("    /// The colors are generated from the 'RGB' field in the JSON file of the default ruleset,\n");
+    output.push_str("    /// so they don't follow a custom ruleset.\n");
+    output.push_str("    pub const fn display_color(&self) -> [u8; 3] {\n");
+    output.push_str("        match self {\n");
+
+    for (variant, [r, g, b]) in enum_variants.iter().zip(colors.iter()) {
+        push_match_arm(
+            &mut output,
+            &format!("{}::{}", enum_name, variant),
+            &format!("[{}, {}, {}]", r, g, b),
+        );
+    }
+
+    output.push_str("        }\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    output
+}
+
+/// Generates `Resource::category()`, which maps every resource to the `ResourceCategory` of its 'resourceType' in the JSON file.
+fn generate_resource_category_code(enum_variants: &[String], resource_types: &[&str]) -> String {
+    let mut output = String::new();
+    output.push('\n');
+    output.push_str("impl Resource {\n");
+    output.push_str("    /// Returns the category of the resource.\n");
+    output.push_str("    pub const fn category(&self) -> ResourceCategory {\n");
+    output.push_str("        match self {\n");
+
+    for (variant, resource_type) in enum_variants.iter().zip(resource_types.iter()) {
+        push_match_arm(
+            &mut output,
+            &format!("Resource::{}", variant),
+            &format!("ResourceCategory::{}", resource_type),
+        );
+    }
+
+    output.push_str("        }\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    output
+}
+
 fn create_technology_enum_from_json(json_path: PathBuf, dest_path: PathBuf) {
     let enum_name = "Technology";
 
@@ -1,4 +1,43 @@
 //! This module provides functionality for generating and manipulating fractal maps which can be used in games like Civilization.
+//!
+//! The map generators in this crate build all their noise with [`CvFractalBuilder`],
+//! and the same API can be used to build custom generators on top of the same noise:
+//!
+//! - [`CvFractalBuilder::build`] creates a [`CvFractal`] with the size of a grid, see [`CvFractalBuilder`] for the options.
+//! - [`CvFractal::height`] returns the height of a cell of the grid.
+//! - [`CvFractal::ridge_builder`] adds ridge lines to the fractal, like the tectonic plate boundaries of the mountains.
+//! - [`CvFractal::height_threshold_from_percent`] and [`CvFractal::height_thresholds_from_percents`]
+//!   return the height below which the given percentage of the fractal lies, e.g. the water level of the map.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use civ_map_generator::fractal::{CvFractalBuilder, FractalFlags};
+//! use civ_map_generator::grid::*;
+//! use rand::{SeedableRng, rngs::StdRng};
+//!
+//! let grid = HexGrid::new(
+//!     Size { width: 80, height: 40 },
+//!     HexLayout {
+//!         orientation: HexOrientation::Flat,
+//!         size: [8., 8.],
+//!         origin: [0., 0.],
+//!     },
+//!     Offset::Odd,
+//!     WrapFlags::WrapX,
+//! );
+//! let mut rng = StdRng::seed_from_u64(42);
+//!
+//! let fractal = CvFractalBuilder::new(grid)
+//!     .grain(3)
+//!     .flags(FractalFlags::empty())
+//!     .build(&mut rng);
+//!
+//! // About 70% of the fractal is below the water level.
+//! let water_level = fractal.height_threshold_from_percent(70);
+//! let is_water = |x: u32, y: u32| fractal.height(x, y) <= water_level;
+//! # let _ = is_water(0, 0);
+//! ```
 
 use crate::grid::*;
 use bitflags::bitflags;
@@ -448,6 +487,18 @@ impl<G: Grid> CvFractal<G> {
         })
     }
 
+    /// Calculate the height threshold from a percentile of the fractal array.
+    ///
+    /// It is the same as [`CvFractal::height_thresholds_from_percents`] with only one percentage.
+    /// The percentage is clamped between 0 and 100.
+    ///
+    /// For example, when the input is `72`, the output might be `120`,
+    /// meaning that 72% of all values will be below the height threshold of 120, and 28% will be above it.
+    pub fn height_threshold_from_percent(&self, percent: u32) -> u32 {
+        let [threshold] = self.height_thresholds_from_percents([percent]);
+        threshold
+    }
+
     fn tectonic_action(&mut self, rifts: &CvFractal<G>) {
         let fractal_exp = self.fractal_exp;
         let fractal_width = fractal_exp.fractal_width();
@@ -1080,3 +1131,51 @@ impl FractalExp {
         1 << self.height_exp
     }
 }
+
+impl Default for FractalExp {
+    /// Returns [`DEFAULT_WIDTH_EXP`] (7) and [`DEFAULT_HEIGHT_EXP`] (6), the same as original CIV5.
+    fn default() -> Self {
+        Self::new(DEFAULT_WIDTH_EXP, DEFAULT_HEIGHT_EXP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    /// Tests that the height threshold from a percentile splits the heights of the grid at that percentile.
+    #[test]
+    fn test_height_threshold_from_percent() {
+        let grid = HexGrid::new(
+            Size {
+                width: 80,
+                height: 40,
+            },
+            HexLayout {
+                orientation: HexOrientation::Flat,
+                size: [8., 8.],
+                origin: [0., 0.],
+            },
+            Offset::Odd,
+            WrapFlags::WrapX,
+        );
+        let mut rng = StdRng::seed_from_u64(42);
+        let fractal = CvFractalBuilder::new(grid).grain(3).build(&mut rng);
+
+        for percent in [10, 30, 50, 70, 90] {
+            let threshold = fractal.height_threshold_from_percent(percent);
+            let below_count = (0..grid.width())
+                .flat_map(|x| (0..grid.height()).map(move |y| (x, y)))
+                .filter(|&(x, y)| fractal.height(x, y) < threshold)
+                .count();
+            let below_ratio = below_count as f64 / (grid.width() * grid.height()) as f64;
+
+            assert!(
+                (below_ratio - percent as f64 / 100.).abs() < 0.05,
+                "percent: {percent}, below ratio: {below_ratio}"
+            );
+        }
+    }
+}
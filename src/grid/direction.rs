@@ -17,6 +17,8 @@
 //! ```
 //!
 
+use crate::grid::{HexOrientation, SquareOrientation};
+
 #[repr(u8)]
 #[derive(Eq, PartialEq, Clone, Copy, Debug)]
 pub enum Direction {
@@ -44,4 +46,105 @@ impl Direction {
             Direction::NorthWest => Direction::SouthEast,
         }
     }
+
+    /// Returns the next edge direction in clockwise order on a hexagonal grid with the given orientation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the direction is not a valid edge direction for the hexagon orientation.
+    pub const fn rotate_clockwise(self, orientation: HexOrientation) -> Self {
+        orientation.edge_clockwise(self)
+    }
+
+    /// Returns the next edge direction in counter clockwise order on a hexagonal grid with the given orientation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the direction is not a valid edge direction for the hexagon orientation.
+    pub const fn rotate_counter_clockwise(self, orientation: HexOrientation) -> Self {
+        orientation.edge_counter_clockwise(self)
+    }
+
+    /// Returns the next edge direction in clockwise order on a square grid with the given orientation.
+    ///
+    /// This is the square grid equivalent of [`Direction::rotate_clockwise`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the direction is not a valid edge direction for the square orientation.
+    pub const fn rotate_clockwise_on_square_grid(self, orientation: SquareOrientation) -> Self {
+        orientation.edge_clockwise(self)
+    }
+
+    /// Returns the next edge direction in counter clockwise order on a square grid with the given orientation.
+    ///
+    /// This is the square grid equivalent of [`Direction::rotate_counter_clockwise`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the direction is not a valid edge direction for the square orientation.
+    pub const fn rotate_counter_clockwise_on_square_grid(
+        self,
+        orientation: SquareOrientation,
+    ) -> Self {
+        orientation.edge_counter_clockwise(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Direction;
+    use crate::grid::{HexOrientation, SquareOrientation};
+
+    const ALL_DIRECTIONS: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    #[test]
+    fn test_opposite() {
+        for direction in ALL_DIRECTIONS {
+            assert_ne!(direction.opposite(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+        }
+    }
+
+    #[test]
+    fn test_rotate_on_hex_grid() {
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            let edge_direction = orientation.edge_direction();
+            for (index, direction) in edge_direction.into_iter().enumerate() {
+                let clockwise = direction.rotate_clockwise(orientation);
+                assert_eq!(clockwise, edge_direction[(index + 1) % 6]);
+                assert_eq!(clockwise.rotate_counter_clockwise(orientation), direction);
+            }
+
+            // Rotating 6 times returns to the starting direction.
+            let start = edge_direction[0];
+            let end = (0..6).fold(start, |direction, _| {
+                direction.rotate_clockwise(orientation)
+            });
+            assert_eq!(end, start);
+        }
+    }
+
+    #[test]
+    fn test_rotate_on_square_grid() {
+        let orientation = SquareOrientation::Orthogonal;
+        let edge_direction = orientation.edge_direction();
+        for (index, direction) in edge_direction.into_iter().enumerate() {
+            let clockwise = direction.rotate_clockwise_on_square_grid(orientation);
+            assert_eq!(clockwise, edge_direction[(index + 1) % 4]);
+            assert_eq!(
+                clockwise.rotate_counter_clockwise_on_square_grid(orientation),
+                direction
+            );
+        }
+    }
 }
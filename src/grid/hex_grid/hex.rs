@@ -124,6 +124,8 @@ impl Hex {
     /// Return a [`Vec<Hex>`] containing all [`Hex`] which are exactly at a given `distance` from `self`.
     /// If `distance` = 0 the [`Vec<Hex>`] will be empty. \
     /// The number of returned hexes is equal to `6 * distance`.
+    ///
+    /// The hexes are in clockwise order, starting from the hex in the north-west of `self`.
     pub fn hexes_at_distance(self, distance: u32) -> Vec<Hex> {
         // If distance is 0, return an empty vector
         if distance == 0 {
@@ -169,6 +171,28 @@ impl Hex {
         hex_list
     }
 
+    /// Return a [`Vec<Hex>`] containing all [`Hex`] on the straight line from `self` to `rhs`, including both ends. \
+    /// The number of returned hexes is equal to `self.distance_to(rhs) + 1`.
+    pub fn line_to(self, rhs: Self) -> Vec<Hex> {
+        let distance = self.distance_to(rhs);
+
+        // The line is computed relative to `self` to keep the precision of `f32`.
+        // The line is nudged a little, so that the points exactly on the edge between 2 hexes are always rounded to the same side.
+        let delta = (rhs - self).0.as_vec2();
+        let nudge = Vec2::new(1e-4, 2e-4);
+
+        (0..=distance)
+            .map(|step| {
+                let t = if distance == 0 {
+                    0.0
+                } else {
+                    step as f32 / distance as f32
+                };
+                self + Hex::round(delta * t + nudge)
+            })
+            .collect()
+    }
+
     /// Rounds floating point coordinates to [`Hex`].
     #[inline(always)]
     pub fn round(fractional_hex: Vec2) -> Self {
@@ -258,9 +282,18 @@ impl HexLayout {
     ///
     /// The returned array is ordered and usually used to draw a hexagon.
     pub fn all_corners(self, hex: Hex) -> [[f32; 2]; 6] {
+        self.hex_corners(hex).map(|corner| corner.to_array())
+    }
+
+    /// Returns the pixel positions of all 6 corners of the given hexagonal coordinates.
+    ///
+    /// The corners are in the same order as [`HexOrientation::corner_direction`], for both pointy and flat orientations.
+    /// It's the same as [`HexLayout::all_corners`], but returns [`Vec2`] to pair with [`HexLayout::hex_to_pixel`].
+    pub fn hex_corners(self, hex: Hex) -> [Vec2; 6] {
+        let center = self.hex_to_pixel(hex);
         self.orientation
             .corner_direction()
-            .map(|direction| self.corner(hex, direction))
+            .map(|direction| center + self.corner_offset(direction))
     }
 
     #[inline(always)]
@@ -800,6 +833,35 @@ mod tests {
         assert_eq!(Hex::new(3, -4).length(), 4, "Longer distance");
     }
 
+    #[test]
+    fn test_hex_line_to() {
+        let start = Hex::new(1, -2);
+        assert_eq!(start.line_to(start), vec![start], "Line to itself");
+
+        let end = Hex::new(4, -4);
+        let line = start.line_to(end);
+        assert_eq!(line.len() as i32, start.distance_to(end) + 1, "Line length");
+        assert_hex_eq(line[0], start, "Line start");
+        assert_hex_eq(*line.last().unwrap(), end, "Line end");
+        assert!(
+            line.windows(2)
+                .all(|pair| pair[0].distance_to(pair[1]) == 1),
+            "Line is continuous"
+        );
+
+        let straight_line = Hex::new(0, 0).line_to(Hex::new(3, 0));
+        assert_eq!(
+            straight_line,
+            vec![
+                Hex::new(0, 0),
+                Hex::new(1, 0),
+                Hex::new(2, 0),
+                Hex::new(3, 0)
+            ],
+            "Straight line"
+        );
+    }
+
     #[test]
     fn test_hex_equality() {
         let a = Hex::new(2, -3);
@@ -808,4 +870,19 @@ mod tests {
         assert_eq!(a, b, "Equal hexes");
         assert_ne!(a, c, "Different hexes");
     }
+
+    #[test]
+    fn test_hex_corners_equidistant_from_center() {
+        let hex = Hex::new(3, -4);
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            let layout = HexLayout::new(orientation, [10.0, 10.0], [35.0, 71.0]);
+            let center = layout.hex_to_pixel(hex);
+            let corners = layout.hex_corners(hex);
+
+            for (corner, direction) in corners.iter().zip(orientation.corner_direction()) {
+                assert!((corner.distance(center) - 10.0).abs() < 1e-4);
+                assert_eq!(corner.to_array(), layout.corner(hex, direction));
+            }
+        }
+    }
 }
@@ -1,5 +1,6 @@
 use crate::grid::*;
 use glam::{IVec3, Vec2};
+use std::collections::HashSet;
 
 mod hex;
 pub use hex::*;
@@ -12,6 +13,22 @@ pub struct HexGrid {
     pub wrap_flags: WrapFlags,
 }
 
+impl Default for HexGrid {
+    /// Returns a pointy [`WorldSizeType::Standard`] grid with odd offset, which wraps on the x-axis.
+    fn default() -> Self {
+        Self {
+            size: HexGrid::default_size(WorldSizeType::Standard),
+            layout: HexLayout {
+                orientation: HexOrientation::Pointy,
+                size: [50., 50.],
+                origin: [0., 0.],
+            },
+            offset: Offset::Odd,
+            wrap_flags: WrapFlags::WrapX,
+        }
+    }
+}
+
 impl HexGrid {
     /// Creates a new `HexGrid` with the specified size, layout, offset, and wrap flags.
     pub const fn new(size: Size, layout: HexLayout, offset: Offset, wrap_flags: WrapFlags) -> Self {
@@ -36,6 +53,15 @@ impl HexGrid {
         }
     }
 
+    /// Returns whether the hexes within `distance` from a center may wrap around the map and reach the same cell twice.
+    ///
+    /// The hexes within `distance` span at most `2 * distance + 1` columns and rows,
+    /// so they can't overlap when the map is larger than that along every wrapping axis.
+    fn ring_may_overlap(&self, distance: u32) -> bool {
+        let span = 2 * distance + 1;
+        (self.wrap_x() && self.width() <= span) || (self.wrap_y() && self.height() <= span)
+    }
+
     /// Returns a new `HexGrid` with the specified layout size, keeping other properties unchanged.
     pub const fn with_resized_layout(&self, layout_size: [f32; 2]) -> Self {
         Self {
@@ -156,37 +182,34 @@ impl Grid for HexGrid {
         let start = self.cell_to_offset(start);
         let dest = self.cell_to_offset(dest);
 
-        let [mut dest_x, mut dest_y] = dest.to_array();
-
-        let [x, y] = (dest.0 - start.0).to_array();
-        if self.wrap_x() {
-            if x > self.width() as i32 / 2 {
-                // Wrap around the x-axis
-                dest_x -= self.width() as i32;
-            }
-            if x < -(self.width() as i32) / 2 {
-                // Wrap around the x-axis
-                dest_x += self.width() as i32;
-            }
-        }
-
-        if self.wrap_y() {
-            if y > self.height() as i32 / 2 {
-                // Wrap around the y-axis
-                dest_y -= self.height() as i32;
-            }
-            if y < -(self.height() as i32) / 2 {
-                // Wrap around the y-axis
-                dest_y += self.height() as i32;
-            }
-        }
-
-        let dest = OffsetCoordinate::new(dest_x, dest_y);
-
-        let dest_hex = Hex::from_offset(dest, self.layout.orientation, self.offset);
         let start_hex = Hex::from_offset(start, self.layout.orientation, self.offset);
 
-        start_hex.distance_to(dest_hex)
+        // When the map is wrapping, the dest has a copy on each side of the map along the wrapping axis.
+        // The offset of hexes is not linear, so we can't pick the nearest copy on each axis separately,
+        // especially when the map wraps on both axes. We measure the distance to every copy and use the shortest one.
+        let width = self.width() as i32;
+        let height = self.height() as i32;
+        let x_shifts: &[i32] = if self.wrap_x() {
+            &[0, -width, width]
+        } else {
+            &[0]
+        };
+        let y_shifts: &[i32] = if self.wrap_y() {
+            &[0, -height, height]
+        } else {
+            &[0]
+        };
+
+        x_shifts
+            .iter()
+            .flat_map(|&x_shift| y_shifts.iter().map(move |&y_shift| (x_shift, y_shift)))
+            .map(|(x_shift, y_shift)| {
+                let dest = OffsetCoordinate::new(dest.0.x + x_shift, dest.0.y + y_shift);
+                let dest_hex = Hex::from_offset(dest, self.layout.orientation, self.offset);
+                start_hex.distance_to(dest_hex)
+            })
+            .min()
+            .unwrap()
     }
 
     fn neighbor(self, center: Cell, direction: Direction) -> Option<Cell> {
@@ -200,23 +223,46 @@ impl Grid for HexGrid {
     }
 
     fn cells_at_distance(self, center: Cell, distance: u32) -> impl Iterator<Item = Cell> {
+        let center_cell = center;
         let center = self.cell_to_offset(center);
 
         let center_hex = Hex::from_offset(center, self.layout.orientation, self.offset);
+
+        // On a small wrapping map, the ring may wrap around the map and overlap itself,
+        // or reach a cell which is closer to the center from the other side of the map.
+        // Only in that case the cells are checked, so the common case stays a plain walk along the ring.
+        let mut visited_cells = self.ring_may_overlap(distance).then(HashSet::new);
+
         center_hex
             .hexes_at_distance(distance)
             .into_iter()
             .filter_map(move |hex| self.grid_coordinate_to_cell(hex))
+            .filter(move |&cell| match &mut visited_cells {
+                None => true,
+                Some(visited_cells) => {
+                    self.distance_to(center_cell, cell) == distance as i32
+                        && visited_cells.insert(cell)
+                }
+            })
     }
 
     fn cells_within_distance(self, center: Cell, distance: u32) -> impl Iterator<Item = Cell> {
         let center = self.cell_to_offset(center);
 
         let center_hex = Hex::from_offset(center, self.layout.orientation, self.offset);
+
+        // On a small wrapping map, the area may wrap around the map and overlap itself.
+        // Only in that case the cells are deduplicated.
+        let mut visited_cells = self.ring_may_overlap(distance).then(HashSet::new);
+
         center_hex
             .hexes_in_distance(distance)
             .into_iter()
             .filter_map(move |hex| self.grid_coordinate_to_cell(hex))
+            .filter(move |&cell| match &mut visited_cells {
+                None => true,
+                Some(visited_cells) => visited_cells.insert(cell),
+            })
     }
 
     fn estimate_direction(&self, start: Cell, dest: Cell) -> Option<Direction> {
@@ -319,3 +365,248 @@ impl GridSize for HexGrid {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+
+    /// Creates a small hex grid which wraps on both axes.
+    fn toroidal_grid(orientation: HexOrientation, width: u32, height: u32) -> HexGrid {
+        HexGrid::new(
+            Size::new(width, height),
+            HexLayout::new(orientation, [8., 8.], [0., 0.]),
+            Offset::Odd,
+            WrapFlags::WrapX | WrapFlags::WrapY,
+        )
+    }
+
+    #[test]
+    fn test_toroidal_neighbor_and_distance_symmetry() {
+        for grid in [
+            toroidal_grid(HexOrientation::Pointy, 6, 4),
+            toroidal_grid(HexOrientation::Flat, 4, 6),
+        ] {
+            for cell in grid.all_cells() {
+                let neighbors: Vec<_> = grid.cells_at_distance(cell, 1).collect();
+                // Every cell has 6 neighbors on a toroidal map.
+                assert_eq!(neighbors.len(), 6);
+
+                for neighbor in neighbors {
+                    assert!(grid.cells_at_distance(neighbor, 1).any(|c| c == cell));
+                    assert_eq!(grid.distance_to(cell, neighbor), 1);
+                }
+
+                for other in grid.all_cells() {
+                    assert_eq!(grid.distance_to(cell, other), grid.distance_to(other, cell));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_toroidal_rings_do_not_double_count() {
+        for grid in [
+            toroidal_grid(HexOrientation::Pointy, 6, 4),
+            toroidal_grid(HexOrientation::Flat, 4, 6),
+        ] {
+            let num_cells = (grid.width() * grid.height()) as usize;
+
+            for center in grid.all_cells() {
+                // The center and the rings around it partition the map,
+                // every cell appears exactly once in the ring at its distance.
+                let mut count = vec![0; num_cells];
+                count[center.index()] += 1;
+                for distance in 1..=num_cells as u32 {
+                    for cell in grid.cells_at_distance(center, distance) {
+                        assert_eq!(grid.distance_to(center, cell), distance as i32);
+                        count[cell.index()] += 1;
+                    }
+                }
+                assert!(count.iter().all(|&count| count == 1));
+
+                let cells_within_distance: Vec<_> = grid.cells_within_distance(center, 3).collect();
+                let mut deduplicated = cells_within_distance.clone();
+                deduplicated.sort();
+                deduplicated.dedup();
+                assert_eq!(deduplicated.len(), cells_within_distance.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_ring_and_spiral() {
+        let hex_grid = HexGrid::new(
+            Size::new(20, 12),
+            HexLayout::new(HexOrientation::Pointy, [8., 8.], [0., 0.]),
+            Offset::Odd,
+            WrapFlags::WrapX,
+        );
+        let center = hex_grid
+            .offset_to_cell(OffsetCoordinate::new(10, 6))
+            .unwrap();
+
+        for radius in 0..=4 {
+            let ring = hex_grid.ring(center, radius);
+            assert_eq!(
+                ring.len(),
+                hex_grid.cells_at_distance(center, radius).count()
+            );
+            // The center is an interior cell, so no cell is skipped,
+            // and the ring is walked cell by cell, so every 2 consecutive cells are neighbors.
+            for (i, &cell) in ring.iter().enumerate() {
+                let next_cell = ring[(i + 1) % ring.len()];
+                assert_eq!(hex_grid.distance_to(cell, next_cell), 1);
+            }
+        }
+
+        let spiral = hex_grid.spiral(center, 4);
+        let mut deduplicated = spiral.clone();
+        deduplicated.sort();
+        deduplicated.dedup();
+        assert_eq!(deduplicated.len(), spiral.len());
+        assert_eq!(
+            spiral.len(),
+            hex_grid.cells_within_distance(center, 4).count()
+        );
+        assert_eq!(spiral[0], center);
+        // The cells are ordered from the center out.
+        assert!(spiral.windows(2).all(|pair| {
+            hex_grid.distance_to(center, pair[0]) <= hex_grid.distance_to(center, pair[1])
+        }));
+
+        let square_grid = SquareGrid::new(
+            Size::new(20, 12),
+            SquareLayout::new(SquareOrientation::Orthogonal, [8., 8.], [0., 0.]),
+            WrapFlags::empty(),
+        );
+        let center = square_grid
+            .offset_to_cell(OffsetCoordinate::new(10, 6))
+            .unwrap();
+
+        let mut north_cell = center;
+        for radius in 0..=4 {
+            let ring = square_grid.ring(center, radius);
+            assert_eq!(
+                ring.len(),
+                square_grid.cells_at_distance(center, radius).count()
+            );
+            if radius == 0 {
+                continue;
+            }
+
+            // The ring starts from the north corner and goes clockwise, i.e. toward the east first.
+            north_cell = square_grid.neighbor(north_cell, Direction::North).unwrap();
+            assert_eq!(ring[0], north_cell);
+            let [x, _] = square_grid.cell_to_offset(ring[0]).to_array();
+            let [next_x, _] = square_grid.cell_to_offset(ring[1]).to_array();
+            assert_eq!(next_x, x + 1);
+            // The center is an interior cell, so no cell is skipped,
+            // and the ring is a diamond walked diagonally, so every 2 consecutive cells touch at a corner.
+            for (i, &cell) in ring.iter().enumerate() {
+                let next_cell = ring[(i + 1) % ring.len()];
+                let [x, y] = square_grid.cell_to_offset(cell).to_array();
+                let [next_x, next_y] = square_grid.cell_to_offset(next_cell).to_array();
+                assert_eq!(((next_x - x).abs(), (next_y - y).abs()), (1, 1));
+            }
+        }
+
+        let spiral = square_grid.spiral(center, 4);
+        let mut deduplicated = spiral.clone();
+        deduplicated.sort();
+        deduplicated.dedup();
+        assert_eq!(deduplicated.len(), spiral.len());
+        assert_eq!(
+            spiral.len(),
+            square_grid.cells_within_distance(center, 4).count()
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let hex_grid = HexGrid::new(
+            Size::new(20, 12),
+            HexLayout::new(HexOrientation::Pointy, [8., 8.], [0., 0.]),
+            Offset::Odd,
+            WrapFlags::WrapX,
+        );
+        let cell = |x, y| {
+            hex_grid
+                .offset_to_cell(OffsetCoordinate::new(x, y))
+                .unwrap()
+        };
+
+        // The cells straddle the X seam, so the box crosses the seam instead of spanning the whole width.
+        let (origin, size) = hex_grid.bounding_box(&[cell(19, 3), cell(0, 5), cell(1, 4)]);
+        assert_eq!(origin, OffsetCoordinate::new(19, 3));
+        assert_eq!(size, Size::new(3, 3));
+
+        // The grid does not wrap in the Y direction.
+        let (origin, size) = hex_grid.bounding_box(&[cell(5, 11), cell(6, 0)]);
+        assert_eq!(origin, OffsetCoordinate::new(5, 0));
+        assert_eq!(size, Size::new(2, 12));
+
+        let (origin, size) = hex_grid.bounding_box(&[cell(7, 7)]);
+        assert_eq!(origin, OffsetCoordinate::new(7, 7));
+        assert_eq!(size, Size::new(1, 1));
+    }
+
+    #[test]
+    fn test_all_cells() {
+        let hex_grid = toroidal_grid(HexOrientation::Pointy, 6, 4);
+        assert_eq!(
+            hex_grid.all_cells().count(),
+            hex_grid.size().area() as usize
+        );
+
+        let square_grid = SquareGrid::new(
+            Size::new(5, 3),
+            SquareLayout::new(SquareOrientation::Orthogonal, [8., 8.], [0., 0.]),
+            WrapFlags::empty(),
+        );
+        assert_eq!(
+            square_grid.all_cells().count(),
+            square_grid.size().area() as usize
+        );
+        assert!(
+            square_grid
+                .all_cells()
+                .enumerate()
+                .all(|(index, cell)| cell.index() == index)
+        );
+    }
+
+    /// Tests that an offset coordinate out of bounds on the x-axis of a grid which only wraps on the y-axis returns `OutOfBoundsX`.
+    #[test]
+    fn test_offset_error() {
+        let grid = HexGrid {
+            wrap_flags: WrapFlags::WrapY,
+            ..HexGrid::default()
+        };
+        let width = grid.width() as i32;
+        let height = grid.height() as i32;
+
+        let offset_coordinate = OffsetCoordinate::new(width, 0);
+        assert_eq!(
+            grid.offset_to_cell(offset_coordinate),
+            Err(OffsetError::OutOfBoundsX(offset_coordinate))
+        );
+        assert_eq!(
+            grid.normalize_offset(OffsetCoordinate::new(-1, height)),
+            Err(OffsetError::OutOfBoundsX(OffsetCoordinate::new(-1, 0)))
+        );
+        assert!(
+            grid.offset_to_cell(OffsetCoordinate::new(0, height))
+                .is_ok()
+        );
+
+        let grid = HexGrid {
+            wrap_flags: WrapFlags::WrapX,
+            ..grid
+        };
+        let offset_coordinate = OffsetCoordinate::new(0, height);
+        assert_eq!(
+            grid.normalize_offset(offset_coordinate),
+            Err(OffsetError::OutOfBoundsY(offset_coordinate))
+        );
+    }
+}
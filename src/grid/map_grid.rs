@@ -0,0 +1,363 @@
+use crate::grid::*;
+use arrayvec::ArrayVec;
+use glam::Vec2;
+
+/// The edge or corner directions of a [`MapGrid`].
+///
+/// It has 6 directions on a hex grid and 4 directions on a square grid.
+pub type DirectionArray = ArrayVec<Direction, 6>;
+
+/// The grid of a map, which is either a [`HexGrid`] or a [`SquareGrid`].
+///
+/// It is the grid stored in [`WorldGrid`](crate::map_parameters::WorldGrid), so its variant selects the kind of grid the map is generated on.
+/// It implements [`Grid`] by delegating to the inner grid, and provides the methods which depend on the shape of the cells,
+/// such as the corners of a cell, so the map generation doesn't need to handle each kind of grid separately.
+///
+/// The grid coordinates of the inner grids are different, so its grid coordinate type is [`OffsetCoordinate`].
+/// The methods taking an offset coordinate accept coordinates out of the grid bounds,
+/// e.g. the coordinate of a neighbor across the wrapped edge of the map, which keeps the pixel positions continuous.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum MapGrid {
+    Hex(HexGrid),
+    Square(SquareGrid),
+}
+
+impl MapGrid {
+    /// Returns a new `MapGrid` with the specified wrap flags, keeping other properties unchanged.
+    pub const fn with_wrap_flags(&self, wrap_flags: WrapFlags) -> Self {
+        match *self {
+            MapGrid::Hex(grid) => MapGrid::Hex(HexGrid { wrap_flags, ..grid }),
+            MapGrid::Square(grid) => MapGrid::Square(SquareGrid { wrap_flags, ..grid }),
+        }
+    }
+
+    /// Get world size type of the grid based on its size, see [`GridSize::world_size_type`].
+    pub fn world_size_type(&self) -> WorldSizeType {
+        match self {
+            MapGrid::Hex(grid) => grid.world_size_type(),
+            MapGrid::Square(grid) => grid.world_size_type(),
+        }
+    }
+
+    /// Get the index of the edge `direction` in [`Grid::edge_direction_array`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the direction is not a valid edge direction of the grid.
+    pub const fn edge_index(&self, direction: Direction) -> usize {
+        match self {
+            MapGrid::Hex(grid) => grid.layout.orientation.edge_index(direction),
+            MapGrid::Square(grid) => grid.layout.orientation.edge_index(direction),
+        }
+    }
+
+    /// Get the index of the corner `direction` in [`Grid::corner_direction_array`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the direction is not a valid corner direction of the grid.
+    pub const fn corner_index(&self, direction: Direction) -> usize {
+        match self {
+            MapGrid::Hex(grid) => grid.layout.orientation.corner_index(direction),
+            MapGrid::Square(grid) => grid.layout.orientation.corner_index(direction),
+        }
+    }
+
+    /// Returns the offset coordinate of the neighbor of `offset_coordinate` in the edge `direction`.
+    ///
+    /// Unlike [`Grid::neighbor`], the result is not wrapped, so it may be out of the grid bounds.
+    pub fn neighbor_offset(
+        &self,
+        offset_coordinate: OffsetCoordinate,
+        direction: Direction,
+    ) -> OffsetCoordinate {
+        match self {
+            MapGrid::Hex(grid) => {
+                Hex::from_offset(offset_coordinate, grid.layout.orientation, grid.offset)
+                    .neighbor(grid.layout.orientation, direction)
+                    .to_offset(grid.layout.orientation, grid.offset)
+            }
+            MapGrid::Square(grid) => Square::from_offset(offset_coordinate)
+                .neighbor(grid.layout.orientation, direction)
+                .to_offset(),
+        }
+    }
+
+    /// Returns the pixel position of the corner in `direction` of the cell at `offset_coordinate`.
+    pub fn corner(&self, offset_coordinate: OffsetCoordinate, direction: Direction) -> Vec2 {
+        let corner = match self {
+            MapGrid::Hex(grid) => grid.layout.corner(
+                Hex::from_offset(offset_coordinate, grid.layout.orientation, grid.offset),
+                direction,
+            ),
+            MapGrid::Square(grid) => grid
+                .layout
+                .corner(Square::from_offset(offset_coordinate), direction),
+        };
+        Vec2::from(corner)
+    }
+
+    /// Returns the pixel positions of all corners of the cell at `offset_coordinate`.
+    ///
+    /// The corners are in the same order as [`Grid::corner_direction_array`], which is clockwise.
+    pub fn corners(&self, offset_coordinate: OffsetCoordinate) -> ArrayVec<Vec2, 6> {
+        self.corner_direction_array()
+            .into_iter()
+            .map(|direction| self.corner(offset_coordinate, direction))
+            .collect()
+    }
+
+    /// Returns the offset coordinates within `distance` from `center`, including `center`.
+    ///
+    /// Unlike [`Grid::cells_within_distance`], the results are not wrapped, so they may be out of the grid bounds,
+    /// and different results may refer to the same cell on a wrapped grid.
+    pub fn offsets_within_distance(
+        &self,
+        center: OffsetCoordinate,
+        distance: u32,
+    ) -> Vec<OffsetCoordinate> {
+        match self {
+            MapGrid::Hex(grid) => Hex::from_offset(center, grid.layout.orientation, grid.offset)
+                .hexes_in_distance(distance)
+                .into_iter()
+                .map(|hex| hex.to_offset(grid.layout.orientation, grid.offset))
+                .collect(),
+            MapGrid::Square(_) => Square::from_offset(center)
+                .squares_in_distance(distance)
+                .into_iter()
+                .map(Square::to_offset)
+                .collect(),
+        }
+    }
+
+    /// Returns the offset coordinates on the straight line from `start` to `end`, including both ends.
+    ///
+    /// See [`Hex::line_to`] and [`Square::line_to`]. Like [`MapGrid::offsets_within_distance`], the results are not wrapped.
+    pub fn line(&self, start: OffsetCoordinate, end: OffsetCoordinate) -> Vec<OffsetCoordinate> {
+        match self {
+            MapGrid::Hex(grid) => {
+                let orientation = grid.layout.orientation;
+                Hex::from_offset(start, orientation, grid.offset)
+                    .line_to(Hex::from_offset(end, orientation, grid.offset))
+                    .into_iter()
+                    .map(|hex| hex.to_offset(orientation, grid.offset))
+                    .collect()
+            }
+            MapGrid::Square(_) => Square::from_offset(start)
+                .line_to(Square::from_offset(end))
+                .into_iter()
+                .map(Square::to_offset)
+                .collect(),
+        }
+    }
+}
+
+impl From<HexGrid> for MapGrid {
+    fn from(grid: HexGrid) -> Self {
+        MapGrid::Hex(grid)
+    }
+}
+
+impl From<SquareGrid> for MapGrid {
+    fn from(grid: SquareGrid) -> Self {
+        MapGrid::Square(grid)
+    }
+}
+
+impl Grid for MapGrid {
+    type GridCoordinateType = OffsetCoordinate;
+
+    type DirectionArrayType = DirectionArray;
+
+    fn with_dimensions(&self, width: u32, height: u32) -> Self {
+        match self {
+            MapGrid::Hex(grid) => MapGrid::Hex(grid.with_dimensions(width, height)),
+            MapGrid::Square(grid) => MapGrid::Square(grid.with_dimensions(width, height)),
+        }
+    }
+
+    fn edge_direction_array(&self) -> DirectionArray {
+        match self {
+            MapGrid::Hex(grid) => grid.edge_direction_array().into_iter().collect(),
+            MapGrid::Square(grid) => grid.edge_direction_array().into_iter().collect(),
+        }
+    }
+
+    fn corner_direction_array(&self) -> DirectionArray {
+        match self {
+            MapGrid::Hex(grid) => grid.corner_direction_array().into_iter().collect(),
+            MapGrid::Square(grid) => grid.corner_direction_array().into_iter().collect(),
+        }
+    }
+
+    fn size(&self) -> Size {
+        match self {
+            MapGrid::Hex(grid) => grid.size(),
+            MapGrid::Square(grid) => grid.size(),
+        }
+    }
+
+    fn wrap_flags(&self) -> WrapFlags {
+        match self {
+            MapGrid::Hex(grid) => grid.wrap_flags(),
+            MapGrid::Square(grid) => grid.wrap_flags(),
+        }
+    }
+
+    fn center(&self) -> [f32; 2] {
+        match self {
+            MapGrid::Hex(grid) => grid.center(),
+            MapGrid::Square(grid) => grid.center(),
+        }
+    }
+
+    fn left_bottom(&self) -> [f32; 2] {
+        match self {
+            MapGrid::Hex(grid) => grid.left_bottom(),
+            MapGrid::Square(grid) => grid.left_bottom(),
+        }
+    }
+
+    fn right_top(&self) -> [f32; 2] {
+        match self {
+            MapGrid::Hex(grid) => grid.right_top(),
+            MapGrid::Square(grid) => grid.right_top(),
+        }
+    }
+
+    fn offset_to_pixel(&self, offset_coordinate: OffsetCoordinate) -> [f32; 2] {
+        match self {
+            MapGrid::Hex(grid) => grid.offset_to_pixel(offset_coordinate),
+            MapGrid::Square(grid) => grid.offset_to_pixel(offset_coordinate),
+        }
+    }
+
+    fn pixel_to_offset(&self, pixel: [f32; 2]) -> OffsetCoordinate {
+        match self {
+            MapGrid::Hex(grid) => grid.pixel_to_offset(pixel),
+            MapGrid::Square(grid) => grid.pixel_to_offset(pixel),
+        }
+    }
+
+    fn grid_coordinate_to_cell(&self, grid_coordinate: OffsetCoordinate) -> Option<Cell> {
+        self.offset_to_cell(grid_coordinate).ok()
+    }
+
+    fn distance_to(&self, start: Cell, dest: Cell) -> i32 {
+        match self {
+            MapGrid::Hex(grid) => grid.distance_to(start, dest),
+            MapGrid::Square(grid) => grid.distance_to(start, dest),
+        }
+    }
+
+    fn neighbor(self, center: Cell, direction: Direction) -> Option<Cell> {
+        match self {
+            MapGrid::Hex(grid) => grid.neighbor(center, direction),
+            MapGrid::Square(grid) => grid.neighbor(center, direction),
+        }
+    }
+
+    fn cells_at_distance(self, center: Cell, distance: u32) -> impl Iterator<Item = Cell> {
+        match self {
+            MapGrid::Hex(grid) => CellIter::Hex(grid.cells_at_distance(center, distance)),
+            MapGrid::Square(grid) => CellIter::Square(grid.cells_at_distance(center, distance)),
+        }
+    }
+
+    fn cells_within_distance(self, center: Cell, distance: u32) -> impl Iterator<Item = Cell> {
+        match self {
+            MapGrid::Hex(grid) => CellIter::Hex(grid.cells_within_distance(center, distance)),
+            MapGrid::Square(grid) => CellIter::Square(grid.cells_within_distance(center, distance)),
+        }
+    }
+
+    fn estimate_direction(&self, start: Cell, dest: Cell) -> Option<Direction> {
+        match self {
+            MapGrid::Hex(grid) => grid.estimate_direction(start, dest),
+            MapGrid::Square(grid) => grid.estimate_direction(start, dest),
+        }
+    }
+}
+
+/// The cell iterator of either kind of grid, returned by the [`Grid`] methods of [`MapGrid`].
+enum CellIter<H, S> {
+    Hex(H),
+    Square(S),
+}
+
+impl<H, S> Iterator for CellIter<H, S>
+where
+    H: Iterator<Item = Cell>,
+    S: Iterator<Item = Cell>,
+{
+    type Item = Cell;
+
+    fn next(&mut self) -> Option<Cell> {
+        match self {
+            CellIter::Hex(iter) => iter.next(),
+            CellIter::Square(iter) => iter.next(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+
+    fn square_grid() -> MapGrid {
+        MapGrid::Square(SquareGrid::new(
+            Size::new(10, 8),
+            SquareLayout::new(SquareOrientation::Orthogonal, [2., 2.], [0., 0.]),
+            WrapFlags::WrapX,
+        ))
+    }
+
+    /// Tests that the corners of neighboring squares are shared, also across the wrapped edge of the map.
+    #[test]
+    fn test_square_neighbor_corners() {
+        let grid = square_grid();
+
+        let west_edge = OffsetCoordinate::new(0, 3);
+        let east_neighbor = grid.neighbor_offset(west_edge, Direction::West);
+        assert_eq!(east_neighbor, OffsetCoordinate::new(-1, 3));
+        assert_eq!(
+            grid.offset_to_cell(east_neighbor),
+            grid.offset_to_cell(OffsetCoordinate::new(9, 3))
+        );
+
+        assert_eq!(
+            grid.corner(west_edge, Direction::NorthWest),
+            grid.corner(east_neighbor, Direction::NorthEast)
+        );
+        assert_eq!(
+            grid.corner(west_edge, Direction::SouthWest),
+            grid.corner(east_neighbor, Direction::SouthEast)
+        );
+        assert_eq!(grid.corners(west_edge).len(), 4);
+    }
+
+    /// Tests that every 2 consecutive squares on a line are neighbors.
+    #[test]
+    fn test_square_line() {
+        let grid = square_grid();
+
+        let start = OffsetCoordinate::new(1, 1);
+        for end in [
+            OffsetCoordinate::new(1, 1),
+            OffsetCoordinate::new(5, 1),
+            OffsetCoordinate::new(4, 4),
+            OffsetCoordinate::new(-2, 6),
+            OffsetCoordinate::new(3, -1),
+        ] {
+            let line = grid.line(start, end);
+            let distance = Square::from_offset(start).distance_to(Square::from_offset(end));
+
+            assert_eq!(line.len(), distance as usize + 1);
+            assert_eq!(line.first(), Some(&start));
+            assert_eq!(line.last(), Some(&end));
+            assert!(line.windows(2).all(|pair| {
+                Square::from_offset(pair[0]).distance_to(Square::from_offset(pair[1])) == 1
+            }));
+        }
+    }
+}
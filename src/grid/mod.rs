@@ -67,11 +67,13 @@ use bitflags::bitflags;
 
 mod direction;
 mod hex_grid;
+mod map_grid;
 mod offset_coordinate;
 mod square_grid;
 
 pub use direction::*;
 pub use hex_grid::*;
+pub use map_grid::*;
 pub use offset_coordinate::*;
 pub use square_grid::*;
 
@@ -144,6 +146,14 @@ pub trait Grid {
         self.size().height
     }
 
+    /// Returns an iterator over all cells in the grid.
+    ///
+    /// Cells are yielded in the order of the cell index, i.e. row-major order (left-to-right, bottom-to-top).
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    fn all_cells(&self) -> impl Iterator<Item = Cell> + use<Self> {
+        (0..self.size().area() as usize).map(Cell)
+    }
+
     /// Returns the flags that indicate how a grid/map wraps at its borders.
     ///
     /// See [`WrapFlags`] for details on wrapping behavior.
@@ -263,7 +273,7 @@ pub trait Grid {
     ///
     /// # Returns
     ///
-    /// - `Result<Cell, String>`: The cell if the coordinate is valid, otherwise an error message.
+    /// - `Result<Cell, OffsetError>`: The cell if the coordinate is valid, otherwise an [`OffsetError`] telling which axis is out of bounds.
     ///
     /// # Examples
     ///
@@ -275,7 +285,7 @@ pub trait Grid {
     /// }
     /// ```
     #[must_use = "this `Result` may be an `Err` variant, which should be handled"]
-    fn offset_to_cell(&self, offset_coordinate: OffsetCoordinate) -> Result<Cell, String> {
+    fn offset_to_cell(&self, offset_coordinate: OffsetCoordinate) -> Result<Cell, OffsetError> {
         self.normalize_offset(offset_coordinate)
             .map(|normalized_coordinate| {
                 let [x, y] = normalized_coordinate.to_array();
@@ -287,19 +297,19 @@ pub trait Grid {
     ///
     /// # Returns
     ///
-    /// Returns a normalized `OffsetCoordinate` that fits within the grid's bounds or an error message.
+    /// Returns a normalized `OffsetCoordinate` that fits within the grid's bounds or an [`OffsetError`].
     /// The normalized `OffsetCoordinate` should meet the conditions:
     /// - x ∈ [0, width)
     /// - y ∈ [0, height)
     ///
     /// If the coordinate is out of bounds, an error is returned.
-    /// - If the grid is not wrapped in the X direction, and `offset_coordinate`'s `x` is out of bounds, i.e., not in the range `[0, width)`, the function will return an error.
-    /// - If the grid is not wrapped in the Y direction, and `offset_coordinate`'s `y` is out of bounds, i.e., not in the range `[0, height)`, the function will return an error.
+    /// - If the grid is not wrapped in the X direction, and `offset_coordinate`'s `x` is out of bounds, i.e., not in the range `[0, width)`, the function will return [`OffsetError::OutOfBoundsX`].
+    /// - Otherwise, if the grid is not wrapped in the Y direction, and `offset_coordinate`'s `y` is out of bounds, i.e., not in the range `[0, height)`, the function will return [`OffsetError::OutOfBoundsY`].
     #[must_use = "this `Result` may be an `Err` variant, which should be handled"]
     fn normalize_offset(
         &self,
         offset_coordinate: OffsetCoordinate,
-    ) -> Result<OffsetCoordinate, String> {
+    ) -> Result<OffsetCoordinate, OffsetError> {
         let mut x = offset_coordinate.0.x;
         let mut y = offset_coordinate.0.y;
 
@@ -312,17 +322,12 @@ pub trait Grid {
 
         let offset_coordinate = OffsetCoordinate::new(x, y);
 
-        if offset_coordinate.0.x >= 0
-            && offset_coordinate.0.x < self.width() as i32
-            && offset_coordinate.0.y >= 0
-            && offset_coordinate.0.y < self.height() as i32
-        {
-            Ok(offset_coordinate)
+        if x < 0 || x >= self.width() as i32 {
+            Err(OffsetError::OutOfBoundsX(offset_coordinate))
+        } else if y < 0 || y >= self.height() as i32 {
+            Err(OffsetError::OutOfBoundsY(offset_coordinate))
         } else {
-            Err(format!(
-                "Offset coordinate out of bounds: {:?}",
-                offset_coordinate
-            ))
+            Ok(offset_coordinate)
         }
     }
 
@@ -421,6 +426,72 @@ pub trait Grid {
     #[must_use]
     fn cells_within_distance(self, center: Cell, distance: u32) -> impl Iterator<Item = Cell>;
 
+    /// Returns the cells at a distance of `radius` from `center`, in clockwise order.
+    ///
+    /// They are the same cells as [`Grid::cells_at_distance`], walked along the ring.
+    /// The walk starts from a fixed corner of the ring, the north-west one on hex grids and the north one on square grids,
+    /// so the order is deterministic. The cells outside the grid are skipped, so the ring has gaps where they are.
+    ///
+    /// When no cell is skipped, e.g. the ring of an interior center on a grid that doesn't wrap:
+    /// - On hex grids, every 2 consecutive cells are neighbors.
+    /// - On square grids, the ring is a diamond walked diagonally, so every 2 consecutive cells touch at a corner.
+    ///
+    /// If `radius` is `0`, the result is empty.
+    #[must_use]
+    fn ring(self, center: Cell, radius: u32) -> Vec<Cell>
+    where
+        Self: Sized,
+    {
+        self.cells_at_distance(center, radius).collect()
+    }
+
+    /// Returns the cells within a distance of `max_radius` from `center`, ordered from the center out.
+    ///
+    /// The first cell is `center`, followed by [`Grid::ring`] of every radius from `1` to `max_radius`.
+    /// See [`Grid::cells_within_distance`] for the limits of `max_radius` on wrapped grids.
+    #[must_use]
+    fn spiral(self, center: Cell, max_radius: u32) -> Vec<Cell>
+    where
+        Self: Sized + Copy,
+    {
+        let mut cells = vec![center];
+        for radius in 1..=max_radius {
+            cells.extend(self.ring(center, radius));
+        }
+        cells
+    }
+
+    /// Returns the minimal bounding rectangle of `cells`, as its origin (the south-west corner) and its size.
+    ///
+    /// On a wrap-X grid, the cells may be enclosed from either side of the seam,
+    /// the representation with the smallest width is chosen, and the same for the height on a wrap-Y grid.
+    /// So the origin is always within the grid bounds, but the rectangle may cross the seam,
+    /// e.g. the cells at `x = width - 1` and `x = 0` are enclosed by a rectangle with the origin `x = width - 1` and the width `2`.
+    /// The result can be passed to [`Rectangle::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cells` is empty.
+    fn bounding_box(&self, cells: &[Cell]) -> (OffsetCoordinate, Size) {
+        assert!(
+            !cells.is_empty(),
+            "Cannot compute the bounding box of no cells"
+        );
+
+        let (xs, ys): (Vec<i32>, Vec<i32>) = cells
+            .iter()
+            .map(|&cell| {
+                let [x, y] = self.cell_to_offset(cell).to_array();
+                (x, y)
+            })
+            .unzip();
+
+        let (x, width) = minimal_extent(xs, self.width(), self.wrap_x());
+        let (y, height) = minimal_extent(ys, self.height(), self.wrap_y());
+
+        (OffsetCoordinate::new(x, y), Size::new(width, height))
+    }
+
     /// Determine the direction of `dest` relative to `start`.
     ///
     /// Returns the primary compass direction from `start` to `dest`.
@@ -448,6 +519,35 @@ pub trait Grid {
     fn estimate_direction(&self, start: Cell, dest: Cell) -> Option<Direction>;
 }
 
+/// Returns the start and the length of the shortest range which contains all the `coordinates` on an axis of length `axis_length`.
+///
+/// When `wrap` is `true`, the range may cross the seam, it starts after the largest gap between the coordinates.
+/// If several gaps are the largest, the range which does not cross the seam is preferred.
+fn minimal_extent(mut coordinates: Vec<i32>, axis_length: u32, wrap: bool) -> (i32, u32) {
+    coordinates.sort_unstable();
+    coordinates.dedup();
+
+    let first = coordinates[0];
+    let last = coordinates[coordinates.len() - 1];
+
+    if !wrap {
+        return (first, (last - first + 1) as u32);
+    }
+
+    // The gap across the seam, from the last coordinate to the first one.
+    let mut largest_gap = first + axis_length as i32 - last;
+    let mut start = first;
+    for pair in coordinates.windows(2) {
+        let gap = pair[1] - pair[0];
+        if gap > largest_gap {
+            largest_gap = gap;
+            start = pair[1];
+        }
+    }
+
+    (start, axis_length + 1 - largest_gap as u32)
+}
+
 /// Represents the dimensions of a grid or map.
 ///
 /// # Structure
@@ -840,11 +940,34 @@ impl Rectangle {
     ///
     /// In debug builds, this function panics if `scale` is not within the range `(0.0, 1.0]`.
     pub fn scaled_center_crop(&self, scale: f64, grid: &impl Grid) -> Rectangle {
+        self.scaled_crop(scale, [0.5, 0.5], grid)
+    }
+
+    /// Returns a new Rectangle that is a crop of the original, scaled by the given factor, and placed at the given anchor.
+    ///
+    /// It has the same size as [`Rectangle::scaled_center_crop`], but it is not always centered within the original rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale`: The scaling factor (0.0 < scale <= 1.0).
+    /// * `anchor`: The position of the crop within the original rectangle along the x and y axes, each in the range `[0.0, 1.0]`.
+    ///   `0.0` puts the crop on the west/south side, `1.0` on the east/north side, and `0.5` centers it.
+    /// * `grid`: The grid context required for the new Rectangle instance.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, this function panics if `scale` is not within the range `(0.0, 1.0]`, or `anchor` is not within the range `[0.0, 1.0]`.
+    pub fn scaled_crop(&self, scale: f64, anchor: [f64; 2], grid: &impl Grid) -> Rectangle {
         debug_assert!(
             scale > 0.0 && scale <= 1.0,
             "Invalid scale factor: {}. Expected a value in range (0.0, 1.0].",
             scale
         );
+        debug_assert!(
+            anchor.iter().all(|value| (0.0..=1.0).contains(value)),
+            "Invalid anchor: {:?}. Expected values in range [0.0, 1.0].",
+            anchor
+        );
 
         let original_width = self.width() as f64;
         let original_height = self.height() as f64;
@@ -853,7 +976,7 @@ impl Rectangle {
         let target_width = original_width * scale;
         let target_height = original_height * scale;
 
-        // Calculate padding to center the crop
+        // Calculate padding on each side as if the crop were centered
         // floor() ensures we don't exceed bounds due to floating point errors
         let pad_x = ((original_width - target_width) / 2.0).floor() as u32;
         let pad_y = ((original_height - target_height) / 2.0).floor() as u32;
@@ -862,9 +985,9 @@ impl Rectangle {
         let final_width = self.width() - (pad_x * 2);
         let final_height = self.height() - (pad_y * 2);
 
-        // Calculate bottom-left (or West/South) offset coordinates for the new rectangle
-        let start_x = self.west_x() + pad_x as i32;
-        let start_y = self.south_y() + pad_y as i32;
+        // Distribute the total padding between the two sides according to the anchor
+        let start_x = self.west_x() + ((pad_x * 2) as f64 * anchor[0]).round() as i32;
+        let start_y = self.south_y() + ((pad_y * 2) as f64 * anchor[1]).round() as i32;
 
         Rectangle::new(
             OffsetCoordinate::new(start_x, start_y),
@@ -63,3 +63,34 @@ impl From<[u32; 2]> for OffsetCoordinate {
         OffsetCoordinate::new(value[0] as i32, value[1] as i32)
     }
 }
+
+/// The error returned when an [`OffsetCoordinate`] is out of the bounds of a grid,
+/// see [`Grid::normalize_offset`](crate::grid::Grid::normalize_offset) and [`Grid::offset_to_cell`](crate::grid::Grid::offset_to_cell).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetError {
+    /// The grid doesn't wrap on the x-axis, and `x` is not in the range `[0, width)`.
+    OutOfBoundsX(OffsetCoordinate),
+    /// The grid doesn't wrap on the y-axis, and `y` is not in the range `[0, height)`.
+    ///
+    /// It is only returned when `x` is within the bounds.
+    OutOfBoundsY(OffsetCoordinate),
+}
+
+impl std::fmt::Display for OffsetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OffsetError::OutOfBoundsX(offset_coordinate) => write!(
+                f,
+                "Offset coordinate out of bounds on the x-axis: {:?}",
+                offset_coordinate
+            ),
+            OffsetError::OutOfBoundsY(offset_coordinate) => write!(
+                f,
+                "Offset coordinate out of bounds on the y-axis: {:?}",
+                offset_coordinate
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OffsetError {}
@@ -3,7 +3,7 @@ use crate::grid::*;
 mod square;
 pub use square::*;
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub struct SquareGrid {
     pub size: Size,
     pub layout: SquareLayout,
@@ -62,6 +62,8 @@ impl Square {
     /// Return a [`Vec<Square>`] containing all [`Square`] which are exactly at a given `distance` from `self`.
     /// If `distance` = 0 the [`Vec<Square>`] will be empty. \
     /// The number of returned squares is equal to `4 * distance`.
+    ///
+    /// The squares are in clockwise order, starting from the square in the north of `self`.
     pub fn squares_at_distance(self, distance: u32) -> Vec<Self> {
         // If distance is 0, return an empty vector
         if distance == 0 {
@@ -81,12 +83,15 @@ impl Square {
         } */
 
         // The following code is equivalent to the commented code above, but it is faster.
-        for x in -radius..=radius {
-            let y1 = radius - x.abs();
-            let y2 = -y1;
-            square_list.push(self + Self::new(x, y1));
-            if y1 != y2 {
-                square_list.push(self + Self::new(x, y2));
+        // Walk along the ring clockwise: north -> east -> south -> west -> north.
+        // Each side of the ring is walked diagonally, e.g. the side from north to east is walked toward south-east.
+        let directions = Self::SQUARE_DIRECTIONS;
+        let mut square = Square(self.0 + directions[3].0 * radius);
+        for i in 0..4 {
+            let step = Square(directions[i].0 + directions[(i + 1) % 4].0);
+            for _ in 0..radius {
+                square_list.push(square);
+                square = square + step;
             }
         }
 
@@ -118,6 +123,37 @@ impl Square {
 
         square_list
     }
+
+    /// Return a [`Vec<Square>`] containing all [`Square`] on the straight line from `self` to `rhs`, including both ends. \
+    /// The number of returned squares is equal to `self.distance_to(rhs) + 1`, and every 2 consecutive squares are neighbors.
+    ///
+    /// Each step moves along the axis which keeps the line closer to the straight line between the square centers.
+    /// When both axes are equally close, i.e. the line passes exactly through a corner, the step moves along the y-axis.
+    pub fn line_to(self, rhs: Self) -> Vec<Self> {
+        let delta = (rhs - self).0;
+        let [num_x_steps, num_y_steps] = delta.abs().to_array();
+        let step_x = Self::new(delta.x.signum(), 0);
+        let step_y = Self::new(0, delta.y.signum());
+
+        let mut square_list = Vec::with_capacity((num_x_steps + num_y_steps + 1) as usize);
+        let mut square = self;
+        square_list.push(square);
+
+        let (mut x_steps, mut y_steps) = (0, 0);
+        while x_steps < num_x_steps || y_steps < num_y_steps {
+            // Compare `(x_steps + 0.5) / num_x_steps` with `(y_steps + 0.5) / num_y_steps` without division.
+            if (2 * x_steps + 1) * num_y_steps < (2 * y_steps + 1) * num_x_steps {
+                square = square + step_x;
+                x_steps += 1;
+            } else {
+                square = square + step_y;
+                y_steps += 1;
+            }
+            square_list.push(square);
+        }
+
+        square_list
+    }
 }
 
 impl Add for Square {
@@ -141,7 +177,7 @@ impl From<[i32; 2]> for Square {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Clone, Copy, Debug)]
 pub struct SquareLayout {
     /// The orientation of the square layout, it can be only `orthogonal` currently.
     pub orientation: SquareOrientation,
@@ -208,7 +244,7 @@ impl SquareLayout {
 }
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
 pub enum SquareOrientation {
     /// 🔳
     Orthogonal,
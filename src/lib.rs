@@ -6,7 +6,8 @@
 //! ## Features
 //!
 //! - **Dual Hex Orientation**: Supports both flat and pointy hex orientations
-//! - **Multiple Map Types**: Fractal and Pangaea generation algorithms
+//! - **Square Grid**: Maps can also be generated on square grids, see [`MapGrid`](grid::MapGrid)
+//! - **Multiple Map Types**: Fractal, Pangaea, Highlands and Lakes generation algorithms
 //! - **Complete Game Elements**: Terrain, resources, rivers, natural wonders, civilizations, city-states
 //! - **Data-Driven Configuration**: JSON-based ruleset system
 //!
@@ -27,6 +28,11 @@
 //! let map = generate_map(&map_parameters);
 //! ```
 //!
+//! ## Cargo Features
+//!
+//! - `parallel`: Enables [`MapParameters::parallel`](map_parameters::MapParameters::parallel),
+//!   which computes the independent per-tile passes in parallel with `rayon`. It is disabled by default.
+//!
 //! ## Adding Custom Map Types
 //!
 //! See [How to add a map type](./src/map_generator/How%20to%20add%20a%20map%20type.MD) for implementation guide.
@@ -40,14 +46,13 @@
 //! The library is organized into several key modules:
 //!
 //! - **`grid`**: Hexagonal and square grid systems with coordinate transformations
-//! - **`map_generator`**: Map generation algorithms (Fractal, Pangaea)
+//! - **`map_generator`**: Map generation algorithms (Fractal, Pangaea, Highlands, Lakes)
 //! - **`ruleset`**: Game rule definitions loaded from JSON files
 //! - **`tile_map`**: Map data structure and generation pipeline
 //!
 //! ## Current Limitations
 //!
-//! - Only fractal and pangaea map algorithms are implemented
-//! - Square grid is not yet supported
+//! - Only fractal, pangaea, highlands and lakes map algorithms are implemented
 //! - Some map parameters are hardcoded; JSON ruleset integration is partial
 //!
 //! ## References
@@ -57,8 +62,12 @@
 //! - [Red Blob Games - Hexagonal Grids](https://www.redblobgames.com/grids/hexagons/)
 
 ////////////////////////////////////////////////////////////////////////////////
-use crate::{map_generator::Generator, map_parameters::MapParameters, tile_map::TileMap};
-use map_generator::{fractal::Fractal, pangaea::Pangaea};
+use crate::{
+    map_generator::Generator,
+    map_parameters::MapParameters,
+    tile_map::{MapStats, TileMap},
+};
+use map_generator::{fractal::Fractal, highlands::Highlands, lakes::Lakes, pangaea::Pangaea};
 use map_parameters::MapType;
 
 pub mod fractal;
@@ -92,26 +101,247 @@ pub fn generate_map(map_parameters: &MapParameters) -> TileMap {
     match map_parameters.map_type {
         MapType::Fractal => Fractal::generate(map_parameters),
         MapType::Pangaea => Pangaea::generate(map_parameters),
+        MapType::Highlands => Highlands::generate(map_parameters),
+        MapType::Lakes => Lakes::generate(map_parameters),
     }
 }
 
+/// Generates a map like [`generate_map`], but checks the ruleset of `map_parameters` at first.
+///
+/// A custom ruleset which misses the items used by the map generator makes [`generate_map`] panic deep inside the generation,
+/// or generate a map with mismatched infos. Use this function instead to get the missing items as an error.
+///
+/// # Errors
+///
+/// Returns the names of the items reported by [`Ruleset::validate_for_generation`](crate::ruleset::Ruleset::validate_for_generation).
+pub fn generate_map_checked(map_parameters: &MapParameters) -> Result<TileMap, Vec<String>> {
+    map_parameters.ruleset.validate_for_generation()?;
+    Ok(generate_map(map_parameters))
+}
+
+/// Generates a map like [`generate_map`], and returns it together with its statistic.
+///
+/// The statistic is computed by [`TileMap::map_stats`] from the generated map,
+/// it helps to tune the map parameters without writing your own counters.
+///
+/// # Panics
+///
+/// The same as [`generate_map`].
+pub fn generate_map_with_stats(map_parameters: &MapParameters) -> (TileMap, MapStats) {
+    let map = generate_map(map_parameters);
+    let map_stats = map.map_stats();
+    (map, map_stats)
+}
+
+/// Generates maps like [`generate_map`] until one satisfies `predicate`, and returns it together with its parameters.
+///
+/// The first attempt uses the seed of `map_parameters`, and each following attempt increments the seed by `1`,
+/// so the seed progression is deterministic:
+/// - If [`MapParameters::seed_bytes`] is `None`, [`MapParameters::seed`] is incremented.
+/// - If [`MapParameters::seed_bytes`] is `Some`, its first 8 bytes are incremented as a little-endian counter.
+///
+/// The returned parameters are a copy of `map_parameters` with the seed of the matching map,
+/// so they can be passed to [`generate_map`] to generate the same map again.
+/// Returns `None` if no map satisfies `predicate` in `max_attempts` attempts.
+///
+/// # Panics
+///
+/// The same as [`generate_map`].
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use civ_map_generator::{generate_map_matching, map_parameters::{MapParametersBuilder, WorldGrid}};
+///
+/// let map_parameters = MapParametersBuilder::new(WorldGrid::default()).build();
+/// if let Some((map, matching_parameters)) = generate_map_matching(&map_parameters, |map| map.map_stats().land_ratio > 0.3, 10) {
+///     println!("Seed {} generates a map with enough land.", matching_parameters.seed);
+/// }
+/// ```
+pub fn generate_map_matching(
+    map_parameters: &MapParameters,
+    predicate: impl Fn(&TileMap) -> bool,
+    max_attempts: u32,
+) -> Option<(TileMap, MapParameters)> {
+    let mut attempt_parameters = map_parameters.clone();
+    for attempt in 0..max_attempts {
+        match map_parameters.seed_bytes {
+            Some(mut seed_bytes) => {
+                let (counter, _) = seed_bytes.split_first_chunk_mut::<8>().unwrap();
+                *counter = u64::from_le_bytes(*counter)
+                    .wrapping_add(attempt as u64)
+                    .to_le_bytes();
+                attempt_parameters.seed_bytes = Some(seed_bytes);
+            }
+            None => attempt_parameters.seed = map_parameters.seed.wrapping_add(attempt as u64),
+        }
+
+        let map = generate_map(&attempt_parameters);
+        if predicate(&map) {
+            return Some((map, attempt_parameters));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test_utils;
+
 #[cfg(test)]
 mod tests {
     use crate::{
         generate_map,
-        map_parameters::{MapParametersBuilder, WorldGrid},
+        test_utils::{TEST_SEED, test_map_parameters},
     };
 
     /// Tests for consistent map generation output when provided with the same random seed.
     #[test]
     fn test_generate_map_deterministic() {
-        let world_grid = WorldGrid::default();
-        let map_parameters = MapParametersBuilder::new(world_grid).seed(12345).build();
+        let map_parameters = test_map_parameters().build();
 
         for _ in 0..10 {
             let map_a = generate_map(&map_parameters);
             let map_b = generate_map(&map_parameters);
             assert_eq!(map_a, map_b, "Maps should be identical with same seed");
         }
+
+        let parallel_map_parameters = test_map_parameters().parallel(true).build();
+        assert_eq!(
+            generate_map(&map_parameters),
+            generate_map(&parallel_map_parameters),
+            "Maps should be identical in parallel and sequential mode"
+        );
+    }
+
+    /// Tests that a small map on a square grid generates without panicking and deterministically,
+    /// and its rivers flow along the edges of the squares.
+    #[test]
+    fn test_generate_map_on_square_grid() {
+        use crate::{
+            grid::{
+                GridSize, SquareGrid, SquareLayout, SquareOrientation, WorldSizeType, WrapFlags,
+            },
+            map_parameters::{MapParametersBuilder, MapType, WorldGrid},
+        };
+
+        let world_size = WorldSizeType::Duel;
+        let grid = SquareGrid::new(
+            SquareGrid::default_size(world_size),
+            SquareLayout::new(SquareOrientation::Orthogonal, [50., 50.], [0., 0.]),
+            WrapFlags::WrapX,
+        );
+
+        for map_type in [MapType::Fractal, MapType::Pangaea] {
+            let map_parameters = MapParametersBuilder::new(WorldGrid::new(grid, world_size))
+                .seed(TEST_SEED)
+                .map_type(map_type)
+                .build();
+
+            let map = generate_map(&map_parameters);
+            assert_eq!(map, generate_map(&map_parameters));
+            assert!(!map.river_list.is_empty());
+            assert_eq!(map.validate_rivers(), vec![]);
+        }
+    }
+
+    /// Tests that [`generate_map_checked`](crate::generate_map_checked) generates the same map as [`generate_map`]
+    /// with the default ruleset, and reports the missing items of a custom ruleset instead of generating a map.
+    #[test]
+    fn test_generate_map_checked() {
+        use crate::{
+            generate_map_checked,
+            ruleset::{Ruleset, enums::Resource},
+        };
+
+        let map_parameters = test_map_parameters().build();
+        assert_eq!(
+            generate_map_checked(&map_parameters),
+            Ok(generate_map(&map_parameters))
+        );
+
+        let mut ruleset = Ruleset::default();
+        ruleset.resources[Resource::Marble].name = "Granite".to_string();
+        let map_parameters = test_map_parameters().ruleset(ruleset).build();
+        assert_eq!(
+            generate_map_checked(&map_parameters),
+            Err(vec!["Marble".to_string()])
+        );
+    }
+
+    /// Tests that the statistic of a generated map agrees with the map.
+    #[test]
+    fn test_generate_map_with_stats() {
+        use crate::generate_map_with_stats;
+
+        let map_parameters = test_map_parameters().build();
+        let (map, map_stats) = generate_map_with_stats(&map_parameters);
+
+        assert_eq!(
+            map_stats.region_count,
+            map_parameters.civilization_list.len() as u32
+        );
+        assert_eq!(map_stats.region_count, map_stats.civilization_count);
+        assert!(map_stats.land_ratio > 0.0 && map_stats.land_ratio < 1.0);
+        assert_eq!(map_stats.river_count, map.river_list.len() as u32);
+        assert_eq!(map_stats.resource_summary, map.resource_summary());
+    }
+
+    /// Tests that [`generate_map_matching`](crate::generate_map_matching) returns the first matching map with its seed,
+    /// and increments the seed by 1 each attempt, either [`MapParameters::seed`] or [`MapParameters::seed_bytes`].
+    ///
+    /// [`MapParameters::seed`]: crate::map_parameters::MapParameters::seed
+    /// [`MapParameters::seed_bytes`]: crate::map_parameters::MapParameters::seed_bytes
+    #[test]
+    fn test_generate_map_matching() {
+        use crate::generate_map_matching;
+        use std::cell::Cell;
+
+        // The predicate fails on the first seed.
+        let map_parameters = test_map_parameters().build();
+        let first_land_ratio = generate_map(&map_parameters).map_stats().land_ratio;
+        let (map, matching_parameters) = generate_map_matching(
+            &map_parameters,
+            |map| map.map_stats().land_ratio != first_land_ratio,
+            5,
+        )
+        .unwrap();
+        assert_eq!(matching_parameters.seed, TEST_SEED + 1);
+        assert_eq!(map_parameters.seed, TEST_SEED);
+
+        // The matching map can be generated again with its parameters.
+        assert_eq!(generate_map(&matching_parameters), map);
+
+        // The seed is incremented by 1 each attempt.
+        let num_attempts = Cell::new(0);
+        let (_, matching_parameters) = generate_map_matching(
+            &map_parameters,
+            |_| {
+                num_attempts.set(num_attempts.get() + 1);
+                num_attempts.get() == 3
+            },
+            5,
+        )
+        .unwrap();
+        assert_eq!(matching_parameters.seed, TEST_SEED + 2);
+
+        // The first 8 bytes of the seed bytes are incremented as a counter.
+        let map_parameters = test_map_parameters().seed_bytes([1; 32]).build();
+        let num_attempts = Cell::new(0);
+        let (map, matching_parameters) = generate_map_matching(
+            &map_parameters,
+            |_| {
+                num_attempts.set(num_attempts.get() + 1);
+                num_attempts.get() == 2
+            },
+            5,
+        )
+        .unwrap();
+        let mut expected_seed_bytes = [1; 32];
+        expected_seed_bytes[0] = 2;
+        assert_eq!(matching_parameters.seed_bytes, Some(expected_seed_bytes));
+        assert_eq!(generate_map(&matching_parameters), map);
+
+        assert!(generate_map_matching(&map_parameters, |_| false, 2).is_none());
     }
 }
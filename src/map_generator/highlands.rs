@@ -0,0 +1,150 @@
+use super::Generator;
+use crate::{
+    fractal::{CvFractalBuilder, FractalFlags},
+    generate_common_methods,
+    grid::WorldSizeType,
+    map_parameters::MapParameters,
+    ruleset::enums::*,
+    tile_map::TileMap,
+};
+use std::cmp::Reverse;
+
+/// A rugged map where hills and mountains make up a large part of the land.
+///
+/// The land is generated like [`Fractal`](super::fractal::Fractal) at first,
+/// then flatland is elevated to hills and mountains along the ridge lines of a fractal,
+/// so that mountain ranges are surrounded by hills, and the valleys between them remain flatland.
+pub struct Highlands(TileMap);
+
+impl Highlands {
+    /// The target percent of land tiles which are hills or mountains.
+    const RUGGED_LAND_PERCENT: u32 = 40;
+
+    /// The max percent of land tiles which are mountains.
+    ///
+    /// Mountains are impassable, so the percent is kept low,
+    /// that ensures every region still has enough passable land to place starting tiles.
+    const MOUNTAIN_PERCENT: u32 = 10;
+}
+
+impl Generator for Highlands {
+    generate_common_methods!();
+
+    fn generate_terrain_types(&mut self, map_parameters: &MapParameters) {
+        let tile_map = self.tile_map_mut();
+
+        // Generate the land as usual.
+        tile_map.generate_terrain_types(map_parameters);
+
+        let grid = tile_map.world_grid.grid;
+
+        let grain = match tile_map.world_grid.world_size_type {
+            WorldSizeType::Duel => 3,
+            WorldSizeType::Tiny => 3,
+            WorldSizeType::Small => 4,
+            WorldSizeType::Standard => 4,
+            WorldSizeType::Large => 5,
+            WorldSizeType::Huge => 5,
+        };
+
+        let num_plates = match tile_map.world_grid.world_size_type {
+            WorldSizeType::Duel => 6,
+            WorldSizeType::Tiny => 9,
+            WorldSizeType::Small => 12,
+            WorldSizeType::Standard => 18,
+            WorldSizeType::Large => 24,
+            WorldSizeType::Huge => 30,
+        };
+
+        let flags = FractalFlags::empty();
+
+        let mut ridge_fractal = CvFractalBuilder::new(grid)
+            .grain(grain)
+            .flags(flags)
+            .build(&mut tile_map.random_number_generator);
+
+        ridge_fractal.ridge_builder(
+            &mut tile_map.random_number_generator,
+            num_plates,
+            flags,
+            6,
+            1,
+        );
+
+        let mut num_land = 0;
+        let mut num_hills = 0;
+        let mut num_mountains = 0;
+        let mut flatland_list = Vec::new();
+
+        for tile in tile_map.all_tiles() {
+            match tile.terrain_type(tile_map) {
+                TerrainType::Water => continue,
+                TerrainType::Flatland => flatland_list.push(tile),
+                TerrainType::Hill => num_hills += 1,
+                TerrainType::Mountain => num_mountains += 1,
+            }
+            num_land += 1;
+        }
+
+        let target_mountains = num_land * Self::MOUNTAIN_PERCENT / 100;
+        let target_rugged_land = num_land * Self::RUGGED_LAND_PERCENT / 100;
+
+        let num_new_mountains = target_mountains.saturating_sub(num_mountains);
+        let num_new_hills =
+            target_rugged_land.saturating_sub(num_hills + num_mountains + num_new_mountains);
+
+        // Sort flatland by ridge height in descending order. The sort is stable,
+        // so tiles with the same height keep their index order, and the result is deterministic.
+        flatland_list.sort_by_key(|tile| {
+            let [x, y] = tile.to_offset(grid).to_array();
+            Reverse(ridge_fractal.height(x as u32, y as u32))
+        });
+
+        // The highest ridges become mountain ranges, and the tiles right below them become hills.
+        // The lowest tiles remain flatland, which forms the valleys that rivers flow through.
+        let mut flatland_iter = flatland_list.into_iter();
+
+        flatland_iter
+            .by_ref()
+            .take(num_new_mountains as usize)
+            .for_each(|tile| tile.set_terrain_type(tile_map, TerrainType::Mountain));
+
+        flatland_iter
+            .take(num_new_hills as usize)
+            .for_each(|tile| tile.set_terrain_type(tile_map, TerrainType::Hill));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map, map_parameters::MapType, ruleset::enums::TerrainType,
+        test_utils::test_map_parameters,
+    };
+
+    /// Tests that the highlands map is deterministic, rugged, and every civilization gets a starting tile.
+    #[test]
+    fn test_generate_highlands_map() {
+        let map_parameters = test_map_parameters().map_type(MapType::Highlands).build();
+
+        let map = generate_map(&map_parameters);
+        assert_eq!(map, generate_map(&map_parameters));
+
+        let land_tiles: Vec<_> = map.all_tiles().filter(|tile| tile.is_land(&map)).collect();
+        let rugged_tiles = land_tiles
+            .iter()
+            .filter(|tile| {
+                matches!(
+                    tile.terrain_type(&map),
+                    TerrainType::Hill | TerrainType::Mountain
+                )
+            })
+            .count();
+        assert!(rugged_tiles * 100 >= land_tiles.len() * 30);
+
+        assert_eq!(
+            map.starting_tile_and_civilization.len(),
+            map_parameters.civilization_list.len()
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use super::Generator;
+use crate::{
+    generate_common_methods,
+    grid::{Grid, WorldSizeType},
+    map_parameters::MapParameters,
+    ruleset::enums::*,
+    tile_map::TileMap,
+};
+use rand::{RngExt, seq::SliceRandom};
+
+/// A mostly-land map dotted with many small lakes instead of oceans.
+///
+/// The land is generated like [`Fractal`](super::fractal::Fractal) at first,
+/// then an ocean rim is kept along the edges of the map, the other water is filled with flatland,
+/// and many small enclosed basins are carved into the land.
+/// The basins are separated from each other and from the rim, so each of them is turned into [`BaseTerrain::Lake`] by [`TileMap::generate_lakes`].
+pub struct Lakes(TileMap);
+
+impl Lakes {
+    /// The max distance from the center of a basin to its tiles.
+    const BASIN_RADIUS: u32 = 1;
+    /// The width of the ocean rim along the edges of the map.
+    const OCEAN_RIM_WIDTH: i32 = 2;
+}
+
+impl Generator for Lakes {
+    generate_common_methods!();
+
+    fn generate_terrain_types(&mut self, map_parameters: &MapParameters) {
+        let tile_map = self.tile_map_mut();
+
+        // Generate the land as usual.
+        tile_map.generate_terrain_types(map_parameters);
+
+        let grid = tile_map.world_grid.grid;
+
+        let num_basins = match tile_map.world_grid.world_size_type {
+            WorldSizeType::Duel => 12,
+            WorldSizeType::Tiny => 18,
+            WorldSizeType::Small => 24,
+            WorldSizeType::Standard => 32,
+            WorldSizeType::Large => 42,
+            WorldSizeType::Huge => 54,
+        };
+
+        // Keep an ocean rim along the edges of the map, so the map still has coasts and naval routes.
+        // Fill the other oceans with flatland, so the map is mostly land.
+        let width = grid.width() as i32;
+        let height = grid.height() as i32;
+        for tile in tile_map.all_tiles() {
+            let [x, y] = tile.to_offset(grid).to_array();
+            let distance_to_edge = x.min(width - 1 - x).min(y).min(height - 1 - y);
+            if distance_to_edge < Self::OCEAN_RIM_WIDTH {
+                tile.set_terrain_type(tile_map, TerrainType::Water);
+            } else if tile.is_water(tile_map) {
+                tile.set_terrain_type(tile_map, TerrainType::Flatland);
+            }
+        }
+
+        let mut candidate_tiles: Vec<_> = tile_map
+            .all_tiles()
+            .filter(|tile| {
+                matches!(
+                    tile.terrain_type(tile_map),
+                    TerrainType::Flatland | TerrainType::Hill
+                )
+            })
+            .collect();
+        candidate_tiles.shuffle(&mut tile_map.random_number_generator);
+
+        let mut num_basins_carved = 0;
+
+        for center_tile in candidate_tiles {
+            if num_basins_carved == num_basins {
+                break;
+            }
+
+            // Keep a ring of land between basins, so that they don't merge into a bigger water body.
+            if center_tile
+                .tiles_in_distance(Self::BASIN_RADIUS * 2, grid)
+                .any(|tile| tile.is_water(tile_map))
+            {
+                continue;
+            }
+
+            // The center of the basin is always carved, the other tiles are carved randomly,
+            // so the lakes have different shapes and sizes.
+            center_tile.set_terrain_type(tile_map, TerrainType::Water);
+
+            let basin_tiles: Vec<_> = center_tile
+                .tiles_in_distance(Self::BASIN_RADIUS, grid)
+                .filter(|tile| {
+                    matches!(
+                        tile.terrain_type(tile_map),
+                        TerrainType::Flatland | TerrainType::Hill
+                    )
+                })
+                .collect();
+
+            for tile in basin_tiles {
+                if tile_map.random_number_generator.random_bool(0.5) {
+                    tile.set_terrain_type(tile_map, TerrainType::Water);
+                }
+            }
+
+            num_basins_carved += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map, map_parameters::MapType, ruleset::enums::BaseTerrain,
+        test_utils::test_map_parameters,
+    };
+
+    /// Tests that the lakes map has more lake tiles than the fractal map with the same size and seed,
+    /// every civilization gets a starting tile, and the ocean rim is kept.
+    #[test]
+    fn test_generate_lakes_map() {
+        let lake_tile_count = |map_type| {
+            let map_parameters = test_map_parameters().map_type(map_type).build();
+            let map = generate_map(&map_parameters);
+            assert_eq!(
+                map.starting_tile_and_civilization.len(),
+                map_parameters.civilization_list.len()
+            );
+            map.all_tiles()
+                .filter(|tile| tile.base_terrain(&map) == BaseTerrain::Lake)
+                .count()
+        };
+
+        assert!(lake_tile_count(MapType::Lakes) > lake_tile_count(MapType::Fractal));
+
+        // The ocean rim is kept.
+        let map = generate_map(&test_map_parameters().map_type(MapType::Lakes).build());
+        assert!(
+            map.all_tiles()
+                .any(|tile| tile.base_terrain(&map) == BaseTerrain::Ocean)
+        );
+    }
+}
@@ -1,8 +1,13 @@
 //! This module defines the [`Generator`] trait for map generation and provides common methods for map generators.
 
-use crate::{map_parameters::MapParameters, tile_map::TileMap};
+use crate::{
+    map_parameters::{MapParameters, WonderResourceOrder},
+    tile_map::TileMap,
+};
 
 pub mod fractal;
+pub mod highlands;
+pub mod lakes;
 pub mod pangaea;
 
 /// A trait that allows for the generation of a tile map.
@@ -19,10 +24,19 @@ pub trait Generator {
         self.tile_map_mut().generate_terrain_types(map_parameters);
     }
 
-    fn shift_terrain_types(&mut self) {
+    fn shift_terrain_types(&mut self, _map_parameters: &MapParameters) {
         self.tile_map_mut().shift_terrain_types();
     }
 
+    fn generate_equatorial_ocean(&mut self, map_parameters: &MapParameters) {
+        self.tile_map_mut()
+            .generate_equatorial_ocean(map_parameters);
+    }
+
+    fn separate_continents(&mut self, map_parameters: &MapParameters) {
+        self.tile_map_mut().separate_continents(map_parameters);
+    }
+
     fn recalculate_areas(&mut self, map_parameters: &MapParameters) {
         self.tile_map_mut().recalculate_areas(map_parameters);
     }
@@ -51,6 +65,19 @@ pub trait Generator {
         self.tile_map_mut().add_features(map_parameters);
     }
 
+    fn apply_symmetry(&mut self, map_parameters: &MapParameters) {
+        self.tile_map_mut().apply_symmetry(map_parameters);
+    }
+
+    fn apply_symmetry_to_placements(&mut self, map_parameters: &MapParameters) {
+        self.tile_map_mut()
+            .apply_symmetry_to_placements(map_parameters);
+    }
+
+    fn remove_tiny_islands(&mut self, map_parameters: &MapParameters) {
+        self.tile_map_mut().remove_tiny_islands(map_parameters);
+    }
+
     fn generate_regions(&mut self, map_parameters: &MapParameters) {
         self.tile_map_mut().generate_regions(map_parameters);
     }
@@ -102,50 +129,123 @@ pub trait Generator {
         self.tile_map_mut().fix_sugar_jungles();
     }
 
-    fn generate(map_parameters: &MapParameters) -> TileMap
-    where
-        Self: Sized,
-    {
-        let mut map = Self::new(map_parameters);
-        // The order of the following methods is important. Do not change it.
-
+    /// Runs Process 1 of the pipeline: generates the terrain types, base terrains, lakes, rivers and features,
+    /// then recalculates the areas, and calls the callbacks of `hooks` between its stages.
+    ///
+    /// It is called by [`Generator::generate_with_hooks`] and [`Generator::generate_terrain_only`].
+    fn generate_terrain(
+        &mut self,
+        map_parameters: &MapParameters,
+        hooks: &mut dyn GenerationObserver,
+    ) {
         /********** Process 1: Generate Terrain Types, Base Terrains, Features and add Rivers **********/
-        map.generate_terrain_types(map_parameters);
+        self.generate_terrain_types(map_parameters);
 
-        map.shift_terrain_types();
+        self.shift_terrain_types(map_parameters);
 
-        map.recalculate_areas(map_parameters);
+        self.generate_equatorial_ocean(map_parameters);
 
-        map.generate_lakes(map_parameters);
+        self.separate_continents(map_parameters);
 
-        map.generate_base_terrains(map_parameters);
+        self.recalculate_areas(map_parameters);
 
-        map.expand_coasts(map_parameters);
+        hooks.after_terrain_types(self.tile_map_mut());
 
-        map.add_rivers();
+        self.generate_lakes(map_parameters);
 
-        map.add_lakes(map_parameters);
+        self.generate_base_terrains(map_parameters);
 
-        map.recalculate_areas(map_parameters);
+        self.expand_coasts(map_parameters);
 
-        map.add_features(map_parameters);
+        hooks.after_base_terrains(self.tile_map_mut());
 
-        map.recalculate_areas(map_parameters);
+        self.tile_map_mut().use_subsystem_rng("rivers");
+        self.add_rivers();
+
+        hooks.after_rivers(self.tile_map_mut());
+
+        self.add_lakes(map_parameters);
+
+        self.recalculate_areas(map_parameters);
+
+        self.tile_map_mut().use_subsystem_rng("features");
+        self.add_features(map_parameters);
+
+        self.apply_symmetry(map_parameters);
+
+        self.recalculate_areas(map_parameters);
+
+        self.remove_tiny_islands(map_parameters);
+
+        hooks.after_features(self.tile_map_mut());
         /********** The End of Process 1 **********/
+    }
+
+    fn generate(map_parameters: &MapParameters) -> TileMap
+    where
+        Self: Sized,
+    {
+        Self::generate_with_hooks(map_parameters, &mut ())
+    }
+
+    /// Generates only the terrain of the map, i.e. Process 1 of [`Generator::generate`].
+    ///
+    /// The regions, starting tiles, natural wonders, city states and resources are not placed,
+    /// so it is much faster than [`Generator::generate`], which is useful for map previews.
+    /// The terrain is the same as the one generated by [`Generator::generate`] with the same `map_parameters` at the end of its Process 1.
+    fn generate_terrain_only(map_parameters: &MapParameters) -> TileMap
+    where
+        Self: Sized,
+    {
+        let mut map = Self::new(map_parameters);
+        map.generate_terrain(map_parameters, &mut ());
+        map.into_inner()
+    }
+
+    /// Generates the map like [`Generator::generate`], and calls the callbacks of `hooks` between the stages of the pipeline.
+    ///
+    /// It is useful to inspect or snapshot the map step by step, e.g. for debugging and visualization.
+    /// See [`GenerationObserver`] for the stages.
+    fn generate_with_hooks(
+        map_parameters: &MapParameters,
+        hooks: &mut dyn GenerationObserver,
+    ) -> TileMap
+    where
+        Self: Sized,
+    {
+        let mut map = Self::new(map_parameters);
+        // The order of the following methods is important. Do not change it.
+        // When `MapParameters::subsystem_rng` is enabled, each subsystem draws from its own namespaced random number generator, see `TileMap::subsystem_rng`.
+
+        map.generate_terrain(map_parameters, hooks);
 
         /********** Process 2: Place Civs, Natural Wonders, City-States and Resources **********/
+        map.tile_map_mut().use_subsystem_rng("regions");
         map.generate_regions(map_parameters);
 
         map.choose_starting_tiles_of_civilization(map_parameters);
 
         map.balance_and_assign_start_locations_of_civilization(map_parameters);
 
-        map.place_natural_wonders(map_parameters);
+        hooks.after_civilization_starts(map.tile_map_mut());
+
+        let wonders_first =
+            map_parameters.wonder_resource_order == WonderResourceOrder::WondersFirst;
+
+        if wonders_first {
+            map.tile_map_mut().use_subsystem_rng("natural_wonders");
+            map.place_natural_wonders(map_parameters);
 
+            hooks.after_natural_wonders(map.tile_map_mut());
+        }
+
+        map.tile_map_mut().use_subsystem_rng("luxury_roles");
         map.assign_luxury_roles(map_parameters);
 
+        map.tile_map_mut().use_subsystem_rng("city_states");
         map.place_city_states(map_parameters);
 
+        map.tile_map_mut().use_subsystem_rng("resources");
         map.place_luxury_resources(map_parameters);
 
         map.place_strategic_resources(map_parameters);
@@ -153,6 +253,19 @@ pub trait Generator {
         map.place_bonus_resources(map_parameters);
 
         map.normalize_start_locations_of_city_state();
+
+        hooks.after_resources(map.tile_map_mut());
+
+        // The natural wonders keep away from the city states and the resources placed above,
+        // see `WonderResourceOrder::ResourcesFirst`.
+        if !wonders_first {
+            map.tile_map_mut().use_subsystem_rng("natural_wonders");
+            map.place_natural_wonders(map_parameters);
+
+            hooks.after_natural_wonders(map.tile_map_mut());
+        }
+
+        map.apply_symmetry_to_placements(map_parameters);
         /********** The End of Process 2 **********/
 
         /********** Process 3: Fix Graphics and Recalculate Areas **********/
@@ -190,3 +303,240 @@ macro_rules! generate_common_methods {
         }
     };
 }
+
+/// Callbacks which are called between the stages of [`Generator::generate_with_hooks`].
+///
+/// Every callback is called exactly once per generation, in the order in which they are declared,
+/// except that [`GenerationObserver::after_natural_wonders`] is called after [`GenerationObserver::after_resources`]
+/// when [`MapParameters::wonder_resource_order`] is [`WonderResourceOrder::ResourcesFirst`].
+/// All of them do nothing by default, so only implement the callbacks of the stages you are interested in.
+pub trait GenerationObserver {
+    /// Called after the terrain types are generated, before the lakes and base terrains are generated.
+    fn after_terrain_types(&mut self, _tile_map: &TileMap) {}
+
+    /// Called after the base terrains are generated and the coasts are expanded.
+    fn after_base_terrains(&mut self, _tile_map: &TileMap) {}
+
+    /// Called after the rivers are added.
+    fn after_rivers(&mut self, _tile_map: &TileMap) {}
+
+    /// Called after the features are added, i.e. at the end of the terrain generation.
+    fn after_features(&mut self, _tile_map: &TileMap) {}
+
+    /// Called after the starting tiles of the civilizations are chosen, balanced and assigned.
+    fn after_civilization_starts(&mut self, _tile_map: &TileMap) {}
+
+    /// Called after the natural wonders are placed.
+    fn after_natural_wonders(&mut self, _tile_map: &TileMap) {}
+
+    /// Called after the city states and all the resources are placed.
+    fn after_resources(&mut self, _tile_map: &TileMap) {}
+}
+
+/// The no-op observer used by [`Generator::generate`].
+impl GenerationObserver for () {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map,
+        map_parameters::{MapParametersBuilder, MapType, WorldGrid},
+        test_utils::{TEST_SEED, test_map_parameters},
+        tile::Tile,
+        tile_map::TileMap,
+    };
+
+    /// Tests that changing the random number generator of the resources leaves the terrain identical when [`MapParameters::subsystem_rng`] is enabled,
+    /// and that the generation keeps drawing from one random number generator when it is disabled.
+    ///
+    /// [`MapParameters::subsystem_rng`]: crate::map_parameters::MapParameters::subsystem_rng
+    #[test]
+    fn test_subsystem_rng() {
+        use crate::{map_generator::Generator, map_parameters::MapParameters};
+
+        /// A generator which places resources with another namespace.
+        struct OtherResourceNamespace(TileMap);
+
+        impl Generator for OtherResourceNamespace {
+            generate_common_methods!();
+
+            fn place_luxury_resources(&mut self, map_parameters: &MapParameters) {
+                self.0.use_subsystem_rng("other_resources");
+                self.0.place_luxury_resources(map_parameters);
+            }
+        }
+
+        // The namespaces are ignored by default, so both generators produce the same map.
+        {
+            let map_parameters = test_map_parameters().map_type(MapType::Fractal).build();
+            assert_eq!(
+                generate_map(&map_parameters).content_hash(),
+                OtherResourceNamespace::generate(&map_parameters).content_hash()
+            );
+        }
+
+        let map_parameters = test_map_parameters()
+            .map_type(MapType::Fractal)
+            .subsystem_rng(true)
+            .build();
+        let map_a = generate_map(&map_parameters);
+        let map_b = OtherResourceNamespace::generate(&map_parameters);
+
+        assert_eq!(map_a.terrain_type_list, map_b.terrain_type_list);
+        assert_eq!(map_a.elevation_list, map_b.elevation_list);
+        assert_eq!(map_a.base_terrain_list, map_b.base_terrain_list);
+        // Features are not compared, because some of them are changed after resources are placed, e.g. by `fix_sugar_jungles`.
+        assert_eq!(map_a.river_list, map_b.river_list);
+        assert_eq!(map_a.natural_wonder_list, map_b.natural_wonder_list);
+        assert_eq!(
+            map_a.starting_tile_and_civilization,
+            map_b.starting_tile_and_civilization
+        );
+        assert_ne!(map_a.resource_list, map_b.resource_list);
+    }
+
+    /// Tests that each hook of `Generator::generate_with_hooks` fires exactly once, in order.
+    #[test]
+    fn test_generate_with_hooks() {
+        use crate::map_generator::{GenerationObserver, Generator, fractal::Fractal};
+
+        #[derive(Default)]
+        struct CountingObserver(Vec<&'static str>);
+
+        impl GenerationObserver for CountingObserver {
+            fn after_terrain_types(&mut self, _tile_map: &TileMap) {
+                self.0.push("terrain_types");
+            }
+
+            fn after_base_terrains(&mut self, _tile_map: &TileMap) {
+                self.0.push("base_terrains");
+            }
+
+            fn after_rivers(&mut self, _tile_map: &TileMap) {
+                self.0.push("rivers");
+            }
+
+            fn after_features(&mut self, _tile_map: &TileMap) {
+                self.0.push("features");
+            }
+
+            fn after_civilization_starts(&mut self, _tile_map: &TileMap) {
+                self.0.push("civilization_starts");
+            }
+
+            fn after_natural_wonders(&mut self, _tile_map: &TileMap) {
+                self.0.push("natural_wonders");
+            }
+
+            fn after_resources(&mut self, _tile_map: &TileMap) {
+                self.0.push("resources");
+            }
+        }
+
+        let map_parameters = test_map_parameters().map_type(MapType::Fractal).build();
+
+        let mut observer = CountingObserver::default();
+        let map = Fractal::generate_with_hooks(&map_parameters, &mut observer);
+
+        assert_eq!(
+            observer.0,
+            [
+                "terrain_types",
+                "base_terrains",
+                "rivers",
+                "features",
+                "civilization_starts",
+                "natural_wonders",
+                "resources",
+            ]
+        );
+        assert_eq!(map, generate_map(&map_parameters));
+    }
+
+    /// Tests that a map without civilizations is generated without panicking, and has no civilization starting tiles.
+    #[test]
+    fn test_zero_civilizations() {
+        use crate::map_parameters::WorldSizeTypeProfile;
+
+        let world_grid = WorldGrid::default();
+        let map_parameters = MapParametersBuilder::new(world_grid)
+            .seed(TEST_SEED)
+            .world_size_type_profile(WorldSizeTypeProfile {
+                num_civilizations: 0,
+                ..WorldSizeTypeProfile::from_world_size_type(world_grid.world_size())
+            })
+            .build();
+        let map = generate_map(&map_parameters);
+
+        assert!(map.starting_tile_and_civilization.is_empty());
+        assert!(map.region_list().is_empty());
+        assert!(!map.starting_tile_and_city_state.is_empty());
+        assert!(map.natural_wonder_list.iter().any(Option::is_some));
+        assert!(map.resource_list.iter().any(Option::is_some));
+    }
+
+    /// Tests that [`Generator::generate_terrain_only`] generates the terrain without any starting tile or resource.
+    ///
+    /// [`Generator::generate_terrain_only`]: crate::map_generator::Generator::generate_terrain_only
+    #[test]
+    fn test_generate_terrain_only() {
+        use crate::map_generator::{Generator, fractal::Fractal};
+
+        let map_parameters = test_map_parameters().build();
+        let map = Fractal::generate_terrain_only(&map_parameters);
+
+        assert!(map.starting_tile_and_civilization.is_empty());
+        assert!(map.resource_list.iter().all(|resource| resource.is_none()));
+        assert!(map.land_tile_count() > 0);
+    }
+
+    /// Tests that both [`WonderResourceOrder`] variants generate maps with different resource layouts,
+    /// that the natural wonders placed after the resources keep away from them, and that all of them are still placed on the default map.
+    ///
+    /// [`WonderResourceOrder`]: crate::map_parameters::WonderResourceOrder
+    #[test]
+    fn test_wonder_resource_order() {
+        use crate::map_parameters::{WonderResourceOrder, WorldSizeTypeProfile};
+        use std::collections::HashSet;
+
+        let generate = |order: WonderResourceOrder| {
+            let map_parameters = test_map_parameters().wonder_resource_order(order).build();
+            generate_map(&map_parameters)
+        };
+
+        // Only the resources are kept, so the two maps are not alive at the same time.
+        let wonders_first_resource_list = generate(WonderResourceOrder::WondersFirst).resource_list;
+        let resources_first_map = generate(WonderResourceOrder::ResourcesFirst);
+
+        assert_ne!(
+            wonders_first_resource_list,
+            resources_first_map.resource_list
+        );
+
+        // Placing the natural wonders last doesn't starve them of tiles on the default map.
+        let target_count =
+            WorldSizeTypeProfile::from_world_size_type(WorldGrid::default().world_size())
+                .num_natural_wonders as usize;
+        let placed_natural_wonders: HashSet<_> = resources_first_map
+            .natural_wonder_list
+            .iter()
+            .flatten()
+            .collect();
+        assert_eq!(placed_natural_wonders.len(), target_count);
+
+        // The natural wonders placed after the resources don't take the tiles of the resources or change their terrain.
+        let grid = resources_first_map.world_grid.grid;
+        let natural_wonder_tiles: Vec<Tile> = resources_first_map
+            .all_tiles()
+            .filter(|tile| tile.natural_wonder(&resources_first_map).is_some())
+            .collect();
+        assert!(!natural_wonder_tiles.is_empty());
+        for tile in natural_wonder_tiles {
+            assert!(
+                std::iter::once(tile)
+                    .chain(tile.neighbor_tiles(grid))
+                    .all(|tile| tile.resource(&resources_first_map).is_none())
+            );
+        }
+    }
+}
@@ -2,7 +2,7 @@ use super::Generator;
 use crate::{
     fractal::{CvFractalBuilder, FractalFlags},
     generate_common_methods,
-    grid::WorldSizeType,
+    grid::{Grid, WorldSizeType},
     map_parameters::*,
     ruleset::enums::*,
     tile_map::TileMap,
@@ -15,6 +15,14 @@ pub struct Pangaea(TileMap);
 impl Generator for Pangaea {
     generate_common_methods!();
 
+    fn shift_terrain_types(&mut self, map_parameters: &MapParameters) {
+        // Shifting moves the most water-heavy part of the map to the map edge,
+        // which would move the biased supercontinent back to the center, so don't shift when it is biased.
+        if map_parameters.pangaea_edge_bias == PangaeaEdgeBias::Center {
+            self.tile_map_mut().shift_terrain_types();
+        }
+    }
+
     fn generate_terrain_types(&mut self, map_parameters: &MapParameters) {
         let tile_map = self.tile_map_mut();
         let world_grid = tile_map.world_grid;
@@ -70,7 +78,7 @@ impl Generator for Pangaea {
             WorldSizeType::Huge => 30,
         };
 
-        let continents_fractal = tile_map.continents_fractal();
+        let continents_fractal = tile_map.continents_fractal(map_parameters);
 
         let flags = FractalFlags::empty();
 
@@ -100,7 +108,7 @@ impl Generator for Pangaea {
             2,
         );
 
-        let [water_threshold] = continents_fractal.height_thresholds_from_percents([water_percent]);
+        let water_threshold = continents_fractal.height_threshold_from_percent(water_percent);
 
         let [
             pass_threshold,
@@ -136,11 +144,22 @@ impl Generator for Pangaea {
             95,
         ]);
 
-        let width = grid.size.width;
-        let height = grid.size.height;
-        let center_position = DVec2::new(width as f64 / 2., height as f64 / 2.);
+        let width = grid.size().width;
+        let height = grid.size().height;
+        let axis = DVec2::new(width as f64 / 2., height as f64 / 2.) * 3. / 5.;
 
-        let axis = center_position * 3. / 5.;
+        // Move the center of the supercontinent toward the biased edge.
+        // The offset is `1/6` of the map size, so the ellipse of the supercontinent
+        // (whose semi-axis is `3/10` of the map size) stays `1/30` of the map size away from the edge.
+        let edge_offset = DVec2::new(width as f64 / 6., height as f64 / 6.);
+        let center_position = DVec2::new(width as f64 / 2., height as f64 / 2.)
+            + match map_parameters.pangaea_edge_bias {
+                PangaeaEdgeBias::Center => DVec2::ZERO,
+                PangaeaEdgeBias::North => DVec2::new(0., edge_offset.y),
+                PangaeaEdgeBias::South => DVec2::new(0., -edge_offset.y),
+                PangaeaEdgeBias::East => DVec2::new(edge_offset.x, 0.),
+                PangaeaEdgeBias::West => DVec2::new(-edge_offset.x, 0.),
+            };
 
         tile_map.all_tiles().for_each(|tile| {
             let [x, y] = tile.to_offset(grid).to_array();
@@ -164,6 +183,8 @@ impl Generator for Pangaea {
 
             let height = ((height as f64 + h + h) * 0.33) as u32;
 
+            tile.set_elevation(tile_map, height.min(u8::MAX as u32) as u8);
+
             if height <= water_threshold {
                 // No hills or mountains here, but check for tectonic islands if that setting is active.
                 if map_parameters.enable_tectonic_islands {
@@ -192,5 +213,47 @@ impl Generator for Pangaea {
                 tile.set_terrain_type(tile_map, TerrainType::Flatland);
             };
         });
+
+        tile_map.apply_hills_percent(map_parameters, &hills_fractal);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map,
+        grid::Grid,
+        map_parameters::{MapType, WorldGrid},
+        test_utils::test_map_parameters,
+    };
+
+    /// Tests that the supercontinent of a West-biased pangaea map is to the west of an East-biased one.
+    #[test]
+    fn test_pangaea_edge_bias() {
+        use crate::map_parameters::PangaeaEdgeBias;
+
+        let land_centroid_x = |bias: PangaeaEdgeBias| {
+            let map_parameters = test_map_parameters()
+                .map_type(MapType::Pangaea)
+                .pangaea_edge_bias(bias)
+                .build();
+            let map = generate_map(&map_parameters);
+            let grid = map.world_grid.grid;
+
+            let land_x_list: Vec<i32> = map
+                .all_tiles()
+                .filter(|tile| tile.is_land(&map))
+                .map(|tile| tile.to_offset(grid).0.x)
+                .collect();
+            land_x_list.iter().sum::<i32>() as f64 / land_x_list.len() as f64
+        };
+
+        let width = WorldGrid::default().grid.size().width as f64;
+        let west_centroid_x = land_centroid_x(PangaeaEdgeBias::West);
+        let east_centroid_x = land_centroid_x(PangaeaEdgeBias::East);
+
+        assert!(west_centroid_x < width / 2.);
+        assert!(east_centroid_x > width / 2.);
+        assert!(east_centroid_x - west_centroid_x > width / 5.);
     }
 }
@@ -1,24 +1,53 @@
 //! This module defines the [MapParameters] struct that contains all the parameters for generating maps.
 
 use crate::{
+    fractal::FractalExp,
     grid::*,
-    ruleset::{enums::Nation, *},
+    ruleset::{
+        enums::{Nation, NaturalWonder, Resource},
+        *,
+    },
 };
 use core::debug_assert;
 use enum_map::Enum;
 use rand::{SeedableRng, rngs::StdRng, seq::IndexedRandom};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    collections::HashMap,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 /// The parameters for generating a map.
+#[derive(Clone)]
 pub struct MapParameters {
     /// The ruleset used to generate the map and play the game.
     ///
     /// The ruleset contains all the rules for the game. e.g. the civilizations, city states, resources, technology, policies and other game elements.
-    pub ruleset: Ruleset,
+    /// It is boxed because it is large, so moving the parameters doesn't copy it on the stack.
+    pub ruleset: Box<Ruleset>,
     /// The seed used to generate the map.
     ///
     /// This seed is used to ensure that the map is reproducible and can be generated again with the same parameters.
     pub seed: u64,
+    /// The 256-bit seed used to generate the map, which is useful to synchronize the generation with an external engine.
+    ///
+    /// - If `Some`, the random number generator is created by [`StdRng::from_seed`] with it, and [`MapParameters::seed`] is ignored.
+    /// - If `None`, the random number generator is created by [`StdRng::seed_from_u64`] with [`MapParameters::seed`]. This is the default.
+    pub seed_bytes: Option<[u8; 32]>,
+    /// Whether each subsystem of the generation draws from its own namespaced random number generator.
+    ///
+    /// - If `true`, rivers, features, regions, natural wonders, luxury roles, city states and resources reseed the random number generator
+    ///   before they run, so changing how many random numbers one subsystem draws doesn't shift the others.
+    ///   The same seed generates a different map from the one generated when it is `false`.
+    /// - If `false`, the whole generation draws from one random number generator seeded by the seed, as original CIV5.
+    ///   This is the default, so the maps generated from existing seeds don't change.
+    pub subsystem_rng: bool,
+    /// Whether to compute the independent per-tile passes in parallel, such as the base terrain classification.
+    ///
+    /// The steps which use the random number generator are always sequential,
+    /// so the generated map is identical to the map generated sequentially.
+    ///
+    /// It has effect only when the `parallel` feature is enabled, otherwise the map is always generated sequentially.
+    pub parallel: bool,
     /// The type of map to generate.
     ///
     /// This can be either [`MapType::Fractal`] or [`MapType::Pangaea`] or other custom map types.
@@ -38,28 +67,153 @@ pub struct MapParameters {
     ///
     /// The water areas with size less than or equal to this value, which are surrounded by land, will be considered as lakes.
     pub max_lake_area_size: u32,
+    /// The max size of a freshwater lake.
+    ///
+    /// A connected body of [`BaseTerrain::Lake`] tiles larger than this value is a salt lake, it becomes [`BaseTerrain::Coast`],
+    /// so it does not provide fresh water to the adjacent land tiles, see [`Tile::is_freshwater`](crate::tile::Tile::is_freshwater).
+    /// The default is [`u32::MAX`], which means every lake is freshwater.
+    pub max_freshwater_lake_size: u32,
     /// Store the chance of each eligible tile to become a coast in each iteration.
     ///
     /// - Its 'length' is the number of iterations. The more iterations, the more coasts will be generated.
     /// - its 'element' is the chance for each eligible tile to become an expansion coast in each iteration. `0.0` means no chance, `1.0` means 100% chance.\
     ///   If it is empty the coast will not expand, and then only the water tiles adjacent to land can become coast.
     pub coast_expand_chance: Vec<f64>,
+    /// The number of rings of ocean around land that always become coast, before the random expansion of [`MapParameters::coast_expand_chance`].
+    ///
+    /// The default value is `1`, that means only the water tiles adjacent to land are always coast.
+    /// Larger values create broader shallow shelves. Lakes are never turned into coast.
+    ///
+    /// This value must be at least `1`.
+    pub coast_width: u32,
     /// The sea level of the map. It affect only terrain type generation.
     pub sea_level: SeaLevel,
     /// The age of the world. It affect only terrain type generation.
     pub world_age: WorldAge,
+    /// The grain of the continents fractal. It affect only terrain type generation.
+    ///
+    /// Higher grain makes the diamond-square algorithm run fewer iterations,
+    /// so the land is broken into more and smaller landmasses.
+    ///
+    /// The default value is `2`, the same as original CIV5. The value must be in the range of **[0, 7]**,
+    /// and it is clamped to the exponents of [`MapParameters::fractal_exp`] when the fractal is built.
+    pub fractal_grain: u8,
+    /// The resolution exponents of the continents fractal. It affect only terrain type generation.
+    ///
+    /// The default value is [`FractalExp::default`], the same as original CIV5.
+    /// Higher exponents give the fractal a finer resolution, which adds detail to the coastlines.
+    pub fractal_exp: FractalExp,
+    /// The fraction of the land tiles which become [`TerrainType::Hill`](crate::ruleset::enums::TerrainType::Hill). It affect only terrain type generation.
+    ///
+    /// - If it is in the range of **[0.0, 1.0]**, after the hill pass, the non-mountain land tiles with the highest hills fractal heights become hills
+    ///   until this fraction of all the land tiles is reached, the others become flatland.
+    ///   The mountains are kept, so the fraction may not be reached when the value is close to `1.0`.
+    /// - If it is [`MapParameters::HILLS_PERCENT_BY_WORLD_AGE`], the hills are decided by [`MapParameters::world_age`] as original CIV5.
+    ///   This is the default, so the default output is the same as before this value is added.
+    ///
+    /// It is applied by [`MapType::Fractal`] and [`MapType::Pangaea`] only.
+    /// [`MapType::Highlands`] and [`MapType::Lakes`] reshape the land after the hill pass,
+    /// so the hills are decided by their own rules there, and this value doesn't take effect.
+    pub hills_percent: f64,
     /// The temperature of the map. It affect only base terrain generation.
     pub temperature: Temperature,
     /// The rainfall of the map. It affect only feature generation.
     pub rainfall: Rainfall,
+    /// The multiplier on the max percent of land tiles covered by forest. It affect only feature generation.
+    ///
+    /// The value is in the range of **[0.0, 2.0]**, default is `1.0`.
+    /// - `0.0` means no forest is placed.
+    /// - `2.0` means up to twice as much forest as the default is placed.
+    pub forest_density: f64,
+    /// The multiplier on the max percent of land tiles covered by jungle. It affect only feature generation.
+    ///
+    /// The value is in the range of **[0.0, 2.0]**, default is `1.0`.
+    /// - `0.0` means no jungle is placed.
+    /// - `2.0` means up to twice as much jungle as the default is placed.
+    ///
+    /// Jungle is still only placed in the warm zone around the equator, whatever the value is.
+    pub jungle_density: f64,
+    /// Whether to place [`Feature::Ice`](crate::ruleset::enums::Feature::Ice) on the water near the poles. It affect only feature generation.
+    ///
+    /// - If true, ice is placed on the high latitude water as usual. This is the default.
+    /// - If false, no ice is placed on the map, which is useful for non-Earthlike maps.
+    pub polar_ice: bool,
+    /// Whether to generate [`BaseTerrain::Snow`](crate::ruleset::enums::BaseTerrain::Snow) near the poles. It affect only base terrain generation.
+    ///
+    /// - If true, snow is generated on the high latitude land as usual. This is the default.
+    /// - If false, [`BaseTerrain::Tundra`](crate::ruleset::enums::BaseTerrain::Tundra) is generated instead of snow,
+    ///   so no tile is snow whatever [`MapParameters::temperature`] is.
+    pub allow_snow: bool,
     /// Controls whether to generate isolated islands in ocean areas based on tectonic plate ridge lines.
     /// When enabled, special height values from the mountains fractal (peaks at 95-100%) will create
     /// land tiles (mountains, hills, or flatlands) even in regions that would otherwise be water.
     /// This simulates real-world volcanic islands and seamounts formed by tectonic activity,
     /// such as Hawaii or Iceland, which appear as isolated peaks rising from the ocean floor.
     pub enable_tectonic_islands: bool,
+    /// Whether to remove the tiny islands which are useless for gameplay.
+    ///
+    /// When enabled, every island (a land [`Landmass`](crate::tile_map::Landmass)) whose size is at most
+    /// [`MapParameters::tiny_island_max_size`] is turned back into water after the features are added,
+    /// before the regions are generated. The default is `false`.
+    pub remove_tiny_islands: bool,
+    /// The max size in tiles of the islands removed by [`MapParameters::remove_tiny_islands`].
+    ///
+    /// The default value is `1`, that means only the single-tile islands are removed. This value must be at least `1`.
+    pub tiny_island_max_size: u32,
+    /// The edge of the map which the supercontinent is biased toward. It affects only [`MapType::Pangaea`].
+    ///
+    /// See [`PangaeaEdgeBias`] for more information.
+    pub pangaea_edge_bias: PangaeaEdgeBias,
+    /// The width in rows of the ocean band forced across the equator.
+    ///
+    /// - `0` means no equatorial ocean is generated. This is the default.
+    /// - Otherwise, all the tiles in the `equatorial_ocean_width` rows centered on the equator become water,
+    ///   which splits the land into a northern and a southern hemisphere.
+    ///
+    /// When the band exists, the climate bands of the base terrain generation are shifted toward the poles,
+    /// so the edges of the band play the role of the equator.
+    pub equatorial_ocean_width: u32,
+    /// The minimum number of water tiles between two major landmasses.
+    ///
+    /// - `0` means the landmasses are not separated. This is the default.
+    /// - Otherwise, after the terrain types are generated, the land tiles closer than `min_continent_gap + 1` tiles to another major landmass
+    ///   are sunk into water, so the continents are separated by channels at least `min_continent_gap` tiles wide.
+    ///   The tiles of the smaller landmass are sunk, so the larger one keeps its shape.
+    ///
+    /// A major landmass is a body of connected land tiles larger than [`MapParameters::tiny_island_max_size`],
+    /// the tiny islands are not separated from the others.
+    /// The later steps, such as [`TileMap::expand_coasts`](crate::tile_map::TileMap::expand_coasts), never turn water into land,
+    /// so the gap is kept in the generated map unless [`MapParameters::symmetry`] mirrors a landmass close to another one.
+    pub min_continent_gap: u32,
+    /// The symmetry of the map, which is useful for competitive play.
+    ///
+    /// When it is not [`Symmetry::None`], after the features are added, one half of the map is mirrored onto the other half,
+    /// including terrain types, elevations, base terrains, features and rivers.
+    /// The regions, starting tiles, natural wonders and resources are placed on the symmetric map afterwards as usual,
+    /// then the starting tiles and resources are mirrored, see [`TileMap::apply_symmetry_to_placements`](crate::tile_map::TileMap::apply_symmetry_to_placements).
+    /// The natural wonders are unique, so they are not mirrored.
+    ///
+    /// See [`Symmetry`] for more information.
+    pub symmetry: Symmetry,
     /// The method used to divide the map into regions.
+    ///
+    /// The default is [`RegionDivideMethod::Continent`], which divides each landmass separately.
+    /// [`RegionDivideMethod::WholeMapRectangle`] and [`RegionDivideMethod::CustomRectangle`] divide a rectangle regardless of the landmasses,
+    /// and then the starting tiles are chosen without regard to the area IDs.
+    ///
+    /// See [`RegionDivideMethod`] for more information.
     pub region_divide_method: RegionDivideMethod,
+    /// The restriction on which continents civilizations can start on.
+    ///
+    /// See [`StartRestriction`] for more information.
+    pub start_continent: StartRestriction,
+    /// Whether hill-dominant regions are classified as [`RegionType::Hill`].
+    ///
+    /// - If true, a region whose hills make up at least 41.5% of its buildable terrain is classified as [`RegionType::Hill`].
+    /// - If false, hills are not considered as a region type, and such regions are classified by their flatland terrain instead.
+    ///
+    /// [`RegionType::Hill`]: crate::ruleset::RegionType::Hill
+    pub hill_as_region_type: bool,
     /// The civilizations in the map, excluding city states and barbarians.
     ///
     /// Its length must be in the range of **[2, [`MapParameters::MAX_CIVILIZATION_COUNT`]]**.
@@ -68,15 +222,133 @@ pub struct MapParameters {
     ///
     /// Its length must be in the range of **[0, [`MapParameters::MAX_CITY_STATE_COUNT`]]**.
     pub city_state_list: Vec<Nation>,
-    /// Whether the civilization starting tile must be coastal land.
+    /// The number of city states to place on the map.
     ///
-    /// - If true, the civilization starting tile only can be coastal land.
+    /// - If `Some`, it overrides [`WorldSizeTypeProfile::num_city_states`], and [`MapParameters::city_state_list`] is selected randomly with this length.
+    /// - If `None`, [`WorldSizeTypeProfile::num_city_states`] is used.
+    ///
+    /// It has no effect when the city states are specified by [`MapParametersBuilder::city_state_list`].
+    /// If there are not enough valid locations, as many city states as possible are placed.
+    pub city_state_count: Option<u32>,
+    /// Whether every civilization starting tile must be coastal land.
+    ///
+    /// - If true, the civilization starting tile is chosen from coastal land tiles.
+    ///   A region which cannot support a coastal start falls back to an inland start, and a warning is logged in debug builds.
     /// - If false, the civilization starting tile can be any hill/flatland tile (including coastal land tiles).
-    pub civ_require_coastal_land_start: bool,
+    pub require_coastal_starts: bool,
+    /// The minimum distance between the starting tiles of any two civilizations.
+    ///
+    /// - If `Some`, in addition to the usual spacing of the starting tiles,
+    ///   a candidate starting tile closer than this distance to an already chosen starting tile is rejected.
+    ///   When no tile in a region is far enough from the other starting tiles, the distance is ignored for that region,
+    ///   and a warning is logged in debug builds.
+    /// - If `None`, only the usual spacing is applied. This is the default.
+    ///
+    /// The distance is the number of tiles between the two starting tiles, see [`Grid::distance_to`].
+    pub min_civ_distance: Option<u32>,
+    /// How the starting tiles of the civilizations are distributed on the map.
+    ///
+    /// It moves the center-bias areas in which the starting tile of each region is searched first,
+    /// so the starting tiles are pulled toward or pushed away from the center of the map.
+    /// It has no effect when the starting tiles are chosen without regard to the area IDs,
+    /// see [`MapParameters::region_divide_method`].
+    ///
+    /// See [`StartDistribution`] for more information.
+    pub start_distribution: StartDistribution,
     /// Whether to disable the start bias of the civilization.
     pub disable_start_bias_of_civ: bool,
+    /// The strength of the civilization start bias, in the range of **[0.0, 1.0]**.
+    ///
+    /// - `0.0` means all start biases are ignored, civilizations are assigned to the remaining regions randomly.
+    /// - Otherwise, the start biases are always tried in their strict form first, e.g. a coastal start for [`StartBias::AlongOcean`],
+    ///   then for each civilization whose strict start bias can't be honored,
+    ///   each of its fallbacks is tried with the probability `start_bias_strength`:
+    ///   - a lake start for [`StartBias::AlongOcean`],
+    ///   - a start near a river for [`StartBias::AlongRiver`],
+    ///   - a river or near river start for [`StartBias::AlongOcean`],
+    ///   - the region with the most preferred terrain for [`StartBias::RegionTypePriority`].
+    ///
+    ///   So `1.0` always tries the fallbacks, which is the behavior of the original CIV5.
+    ///
+    /// The civilizations which are not assigned this way are assigned to the remaining regions randomly.
+    ///
+    /// [`StartBias::AlongOcean`]: crate::ruleset::StartBias::AlongOcean
+    /// [`StartBias::AlongRiver`]: crate::ruleset::StartBias::AlongRiver
+    /// [`StartBias::RegionTypePriority`]: crate::ruleset::StartBias::RegionTypePriority
+    ///
+    /// It has no effect when [`MapParameters::disable_start_bias_of_civ`] is `true`.
+    pub start_bias_strength: f64,
+    /// The number of natural wonders to place on the map.
+    ///
+    /// - If `Some`, it overrides [`WorldSizeTypeProfile::num_natural_wonders`].
+    /// - If `None`, [`WorldSizeTypeProfile::num_natural_wonders`] is used.
+    ///
+    /// If there are not enough eligible locations, as many natural wonders as possible are placed.
+    pub natural_wonder_count: Option<u32>,
+    /// The natural wonders which can be placed on the map.
+    ///
+    /// - If `Some`, only the natural wonders in the list can be placed, e.g. to exclude some natural wonders in a scenario.
+    /// - If `None`, all the natural wonders in the ruleset can be placed. This is the default.
+    ///
+    /// The eligibility and spacing of the natural wonders are not changed,
+    /// so fewer natural wonders than [`MapParameters::natural_wonder_count`] may be placed when the list is short.
+    pub allowed_natural_wonders: Option<Vec<NaturalWonder>>,
+    /// Whether natural wonders avoid the map border.
+    ///
+    /// - If true, natural wonders are not placed on the outermost ring of the map along the axes that do not wrap.
+    /// - If false, natural wonders can be placed on any eligible tile.
+    pub natural_wonders_avoid_border: bool,
+    /// Whether the natural wonders are placed before or after the city states and the resources.
+    ///
+    /// See [`WonderResourceOrder`] for more information.
+    pub wonder_resource_order: WonderResourceOrder,
     /// The resource setting of the map.
     pub resource_setting: ResourceSetting,
+    /// The variety of the luxury resources on the map. It affects how many luxury types are disabled.
+    ///
+    /// See [`LuxuryVariety`] for more information.
+    pub luxury_variety: LuxuryVariety,
+    /// The number of Marble deposits on the map.
+    ///
+    /// - If `Some`, it overrides the number of Marble deposits derived from the number of civilizations and [`MapParameters::resource_setting`].
+    /// - If `None`, that derived number is used, like original CIV5.
+    ///
+    /// The Marble deposits keep their usual spacing, so if there are not enough eligible tiles, as many Marble deposits as possible are placed.
+    pub marble_count: Option<u32>,
+    /// How the ripples of the resources on [`Layer::Strategic`], [`Layer::Luxury`], [`Layer::Bonus`] and [`Layer::Fish`] add up where they overlap.
+    ///
+    /// It tunes how densely the resources can cluster. The default is the same as original CIV5.
+    /// See [`RippleConfig`] for more information.
+    ///
+    /// [`Layer::Strategic`]: crate::tile_map::Layer::Strategic
+    /// [`Layer::Luxury`]: crate::tile_map::Layer::Luxury
+    /// [`Layer::Bonus`]: crate::tile_map::Layer::Bonus
+    /// [`Layer::Fish`]: crate::tile_map::Layer::Fish
+    pub ripple_config: RippleConfig,
+    /// The minimum number of deposits of each strategic resource on the map.
+    ///
+    /// A deposit is a tile with the resource. After the strategic resources are placed as usual,
+    /// every strategic resource with fewer deposits than its minimum is topped up with additional deposits,
+    /// see [`TileMap::place_strategic_resources`](crate::tile_map::TileMap::place_strategic_resources).
+    /// If there are not enough eligible tiles, as many deposits as possible are placed.
+    ///
+    /// The default is empty, that means no minimum. Every resource must be a strategic resource in [`MapParameters::ruleset`].
+    pub min_strategic_deposits: HashMap<Resource, u32>,
+    /// The name of the resource placed on or adjacent to every civilization starting tile, e.g. `"Wheat"`.
+    ///
+    /// - If `Some`, during the normalization of the civilization starting tiles,
+    ///   the resource is placed on a random tile within radius 1 of each starting tile whose terrain allows it,
+    ///   unless there is already such a resource there.
+    /// - If `None`, no extra resource is placed. This is the default.
+    ///
+    /// It is usually a bonus resource. The name must be one of the resources in [`MapParameters::ruleset`].
+    pub starting_bonus_resource: Option<String>,
+    /// Whether the civilization starting tiles are kept free of resources.
+    ///
+    /// - If true, any resource on a civilization starting tile is removed during the normalization of the starting tile,
+    ///   and [`MapParameters::starting_bonus_resource`] is only placed on the tiles adjacent to the starting tile.
+    /// - If false, a resource may be placed on the starting tile. This is the default.
+    pub clear_resource_on_start: bool,
 }
 
 impl MapParameters {
@@ -86,6 +358,9 @@ impl MapParameters {
     /// The maximum number of city states that can be placed on the map.
     pub const MAX_CITY_STATE_COUNT: u32 = 41;
 
+    /// The default value of [`MapParameters::hills_percent`], which means the hills are decided by [`MapParameters::world_age`].
+    pub const HILLS_PERCENT_BY_WORLD_AGE: f64 = -1.0;
+
     /// The maximum number of regions that can share a regional-exclusive luxury resource type.
     ///
     /// All the regional exclusive luxury resources are in the [`LuxuryResourceRole::luxury_assigned_to_regions`](crate::tile_map::LuxuryResourceRole::regions_exclusive).
@@ -110,6 +385,15 @@ impl MapParameters {
     ///
     /// In original CIV5, this value is 3.
     pub const NUM_MAX_ALLOWED_LUXURY_TYPES_FOR_CITY_STATES: usize = 3;
+
+    /// Returns a new random number generator seeded by [`MapParameters::seed_bytes`] if it is `Some`,
+    /// otherwise by [`MapParameters::seed`].
+    pub(crate) fn seeded_rng(&self) -> StdRng {
+        match self.seed_bytes {
+            Some(seed_bytes) => StdRng::from_seed(seed_bytes),
+            None => StdRng::seed_from_u64(self.seed),
+        }
+    }
 }
 
 /// A builder for constructing [`MapParameters`].
@@ -118,25 +402,59 @@ impl MapParameters {
 /// It separates the construction process from the final object representation,
 /// allowing for more granular control over the map parameters.
 pub struct MapParametersBuilder {
-    ruleset: Ruleset,
+    ruleset: Box<Ruleset>,
     seed: u64,
+    seed_bytes: Option<[u8; 32]>,
+    subsystem_rng: bool,
+    parallel: bool,
     world_grid: WorldGrid,
     map_type: MapType,
     world_size_type_profile: WorldSizeTypeProfile,
     num_large_lakes: u32,
     max_lake_area_size: u32,
+    max_freshwater_lake_size: u32,
     coast_expand_chance: Vec<f64>,
+    coast_width: u32,
     sea_level: SeaLevel,
     world_age: WorldAge,
+    fractal_grain: u8,
+    fractal_exp: FractalExp,
+    hills_percent: f64,
     temperature: Temperature,
     rainfall: Rainfall,
+    forest_density: f64,
+    jungle_density: f64,
+    polar_ice: bool,
+    allow_snow: bool,
     enable_tectonic_islands: bool,
+    remove_tiny_islands: bool,
+    tiny_island_max_size: u32,
+    pangaea_edge_bias: PangaeaEdgeBias,
+    equatorial_ocean_width: u32,
+    min_continent_gap: u32,
+    symmetry: Symmetry,
     region_divide_method: RegionDivideMethod,
+    start_continent: StartRestriction,
+    hill_as_region_type: bool,
     civilization_list: Vec<Nation>,
     city_state_list: Vec<Nation>,
-    civ_require_coastal_land_start: bool,
+    city_state_count: Option<u32>,
+    require_coastal_starts: bool,
+    min_civ_distance: Option<u32>,
+    start_distribution: StartDistribution,
     disable_start_bias_of_civ: bool,
+    start_bias_strength: f64,
+    natural_wonder_count: Option<u32>,
+    allowed_natural_wonders: Option<Vec<NaturalWonder>>,
+    natural_wonders_avoid_border: bool,
+    wonder_resource_order: WonderResourceOrder,
     resource_setting: ResourceSetting,
+    luxury_variety: LuxuryVariety,
+    marble_count: Option<u32>,
+    ripple_config: RippleConfig,
+    min_strategic_deposits: HashMap<Resource, u32>,
+    starting_bonus_resource: Option<String>,
+    clear_resource_on_start: bool,
 }
 
 impl MapParametersBuilder {
@@ -158,7 +476,7 @@ impl MapParametersBuilder {
     ///
     /// **Practical Application**: To avoid edge cases where the same tile appears on both sides of the screen simultaneously, it is recommended to maintain a **sufficient margin** between the grid dimensions and the screen dimensions (e.g., ensuring the grid is significantly larger than the viewport) for both Wrap X and Wrap Y scenarios.
     pub fn new(world_grid: WorldGrid) -> Self {
-        let ruleset = Ruleset::default();
+        let ruleset = Box::new(Ruleset::default());
 
         let seed = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -168,6 +486,9 @@ impl MapParametersBuilder {
         Self {
             ruleset,
             seed,
+            seed_bytes: None,
+            subsystem_rng: false,
+            parallel: false,
             world_grid,
             map_type: Default::default(),
             world_size_type_profile: WorldSizeTypeProfile::from_world_size_type(
@@ -175,18 +496,49 @@ impl MapParametersBuilder {
             ),
             num_large_lakes: 2,
             max_lake_area_size: 9,
+            max_freshwater_lake_size: u32::MAX,
             coast_expand_chance: vec![0.25, 0.25], // Default to two iterations with 25% chance each.
+            coast_width: 1,
             sea_level: SeaLevel::Normal,
             world_age: WorldAge::Normal,
+            fractal_grain: 2,
+            fractal_exp: FractalExp::default(),
+            hills_percent: MapParameters::HILLS_PERCENT_BY_WORLD_AGE,
             temperature: Temperature::Normal,
             rainfall: Rainfall::Normal,
+            forest_density: 1.0,
+            jungle_density: 1.0,
+            polar_ice: true,
+            allow_snow: true,
             enable_tectonic_islands: false,
+            remove_tiny_islands: false,
+            tiny_island_max_size: 1,
+            pangaea_edge_bias: PangaeaEdgeBias::Center,
+            equatorial_ocean_width: 0,
+            min_continent_gap: 0,
+            symmetry: Symmetry::None,
             region_divide_method: RegionDivideMethod::Continent,
+            start_continent: StartRestriction::Anywhere,
+            hill_as_region_type: true,
             civilization_list: vec![], // That will be filled in later by `MapParameters::build()`.
             city_state_list: vec![],   // That will be filled in later by `MapParameters::build()`.
-            civ_require_coastal_land_start: false,
+            city_state_count: None,
+            require_coastal_starts: false,
+            min_civ_distance: None,
+            start_distribution: StartDistribution::Default,
             disable_start_bias_of_civ: false,
+            start_bias_strength: 1.0,
+            natural_wonder_count: None,
+            allowed_natural_wonders: None,
+            natural_wonders_avoid_border: true,
+            wonder_resource_order: WonderResourceOrder::WondersFirst,
             resource_setting: ResourceSetting::Standard,
+            luxury_variety: LuxuryVariety::Normal,
+            marble_count: None,
+            ripple_config: RippleConfig::default(),
+            min_strategic_deposits: HashMap::new(),
+            starting_bonus_resource: None,
+            clear_resource_on_start: false,
         }
     }
 
@@ -194,7 +546,7 @@ impl MapParametersBuilder {
 
     /// Set the ruleset to use for the map generation and game rules.
     pub fn ruleset(mut self, ruleset: Ruleset) -> Self {
-        self.ruleset = ruleset;
+        self.ruleset = Box::new(ruleset);
         self
     }
 
@@ -204,12 +556,62 @@ impl MapParametersBuilder {
         self
     }
 
+    /// Sets the 256-bit seed for the map generation. When it is set, the `u64` seed set by [`Self::seed`] is ignored.
+    pub fn seed_bytes(mut self, seed_bytes: [u8; 32]) -> Self {
+        self.seed_bytes = Some(seed_bytes);
+        self
+    }
+
+    /// Sets whether each subsystem of the generation draws from its own namespaced random number generator.
+    ///
+    /// See [`MapParameters::subsystem_rng`] for more information.
+    pub fn subsystem_rng(mut self, subsystem_rng: bool) -> Self {
+        self.subsystem_rng = subsystem_rng;
+        self
+    }
+
+    /// Sets whether to compute the independent per-tile passes in parallel.
+    ///
+    /// It has effect only when the `parallel` feature is enabled.
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
     /// Sets the type of map to generate (e.g., Fractal, Pangaea).
     pub fn map_type(mut self, map_type: MapType) -> Self {
         self.map_type = map_type;
         self
     }
 
+    /// Sets an exact size of the world grid, which does not need to match any [`WorldSizeType`] preset.
+    ///
+    /// The layout, offset and wrap flags of the world grid are kept.
+    /// The world size type is derived from the size by [`GridSize::world_size_type`],
+    /// and the profile related to the world size type is reset to the default profile of the derived world size type.
+    /// So call [`Self::world_size_type_profile`] after this method if you want a custom profile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the world grid is a hex grid and the size breaks the wrap constraints checked by [`HexGrid::new`],
+    /// e.g. the height is odd for pointy hexes which wrap on the y-axis.
+    pub fn size(mut self, size: Size) -> Self {
+        self.world_grid = match self.world_grid.grid {
+            MapGrid::Hex(grid) => WorldGrid::from_grid(HexGrid::new(
+                size,
+                grid.layout,
+                grid.offset,
+                grid.wrap_flags,
+            )),
+            MapGrid::Square(grid) => {
+                WorldGrid::from_grid(SquareGrid::new(size, grid.layout, grid.wrap_flags))
+            }
+        };
+        self.world_size_type_profile =
+            WorldSizeTypeProfile::from_world_size_type(self.world_grid.world_size());
+        self
+    }
+
     /// Sets the profile related to the world size type.
     ///
     /// # Notes
@@ -237,12 +639,33 @@ impl MapParametersBuilder {
         self
     }
 
+    /// Sets the max size of a freshwater lake.
+    ///
+    /// See [`MapParameters::max_freshwater_lake_size`] for more information.
+    pub fn max_freshwater_lake_size(mut self, size: u32) -> Self {
+        self.max_freshwater_lake_size = size;
+        self
+    }
+
     /// Sets the probability vector for coast expansion in each iteration.
     pub fn coast_expand_chance(mut self, chances: Vec<f64>) -> Self {
         self.coast_expand_chance = chances;
         self
     }
 
+    /// Sets the number of rings of ocean around land that always become coast.
+    ///
+    /// See [`MapParameters::coast_width`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is `0`.
+    pub fn coast_width(mut self, width: u32) -> Self {
+        assert!(width >= 1, "The coast width must be at least 1.");
+        self.coast_width = width;
+        self
+    }
+
     /// Sets the sea level configuration. It affect only terrain type generation.
     pub fn sea_level(mut self, sea_level: SeaLevel) -> Self {
         self.sea_level = sea_level;
@@ -255,6 +678,39 @@ impl MapParametersBuilder {
         self
     }
 
+    /// Sets the grain of the continents fractal.
+    ///
+    /// See [`MapParameters::fractal_grain`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `grain` is greater than `7`.
+    pub fn fractal_grain(mut self, grain: u8) -> Self {
+        assert!(
+            grain <= 7,
+            "The fractal grain must be in the range of [0, 7]."
+        );
+        self.fractal_grain = grain;
+        self
+    }
+
+    /// Sets the resolution exponents of the continents fractal.
+    ///
+    /// See [`MapParameters::fractal_exp`] for more information.
+    pub fn fractal_exp(mut self, fractal_exp: FractalExp) -> Self {
+        self.fractal_exp = fractal_exp;
+        self
+    }
+
+    /// Sets the fraction of the land tiles which become hills.
+    ///
+    /// The value is clamped to the range of **[0.0, 1.0]**.
+    /// See [`MapParameters::hills_percent`] for more information.
+    pub fn hills_percent(mut self, percent: f64) -> Self {
+        self.hills_percent = percent.clamp(0.0, 1.0);
+        self
+    }
+
     /// Sets the temperature configuration. It affect only base terrain generation.
     pub fn temperature(mut self, temperature: Temperature) -> Self {
         self.temperature = temperature;
@@ -267,6 +723,38 @@ impl MapParametersBuilder {
         self
     }
 
+    /// Sets the multiplier on the max percent of land tiles covered by forest.
+    ///
+    /// The value is clamped to the range of **[0.0, 2.0]**.
+    /// See [`MapParameters::forest_density`] for more information.
+    pub fn forest_density(mut self, density: f64) -> Self {
+        self.forest_density = density.clamp(0.0, 2.0);
+        self
+    }
+
+    /// Sets the multiplier on the max percent of land tiles covered by jungle.
+    ///
+    /// The value is clamped to the range of **[0.0, 2.0]**.
+    /// See [`MapParameters::jungle_density`] for more information.
+    pub fn jungle_density(mut self, density: f64) -> Self {
+        self.jungle_density = density.clamp(0.0, 2.0);
+        self
+    }
+
+    /// Sets whether to place ice on the water near the poles.
+    pub fn polar_ice(mut self, polar_ice: bool) -> Self {
+        self.polar_ice = polar_ice;
+        self
+    }
+
+    /// Sets whether to generate snow near the poles.
+    ///
+    /// See [`MapParameters::allow_snow`] for more information.
+    pub fn allow_snow(mut self, allow: bool) -> Self {
+        self.allow_snow = allow;
+        self
+    }
+
     /// Sets whether to enable tectonic islands.
     ///
     /// Controls whether to generate isolated islands in ocean areas based on tectonic plate ridge lines.
@@ -279,12 +767,78 @@ impl MapParametersBuilder {
         self
     }
 
+    /// Sets whether to remove the tiny islands.
+    ///
+    /// See [`MapParameters::remove_tiny_islands`] for more information.
+    pub fn remove_tiny_islands(mut self, remove: bool) -> Self {
+        self.remove_tiny_islands = remove;
+        self
+    }
+
+    /// Sets the max size in tiles of the islands removed by [`MapParameters::remove_tiny_islands`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is `0`.
+    pub fn tiny_island_max_size(mut self, size: u32) -> Self {
+        assert!(size >= 1, "The tiny island max size must be at least 1.");
+        self.tiny_island_max_size = size;
+        self
+    }
+
+    /// Sets the edge of the map which the supercontinent is biased toward. It affects only [`MapType::Pangaea`].
+    pub fn pangaea_edge_bias(mut self, bias: PangaeaEdgeBias) -> Self {
+        self.pangaea_edge_bias = bias;
+        self
+    }
+
+    /// Sets the width in rows of the ocean band forced across the equator. `0` disables it.
+    ///
+    /// See [`MapParameters::equatorial_ocean_width`] for more information.
+    pub fn equatorial_ocean_width(mut self, width: u32) -> Self {
+        self.equatorial_ocean_width = width;
+        self
+    }
+
+    /// Sets the minimum number of water tiles between two major landmasses. `0` disables it.
+    ///
+    /// It is checked against the width of the map in [`MapParametersBuilder::build`].
+    /// See [`MapParameters::min_continent_gap`] for more information.
+    pub fn min_continent_gap(mut self, gap: u32) -> Self {
+        self.min_continent_gap = gap;
+        self
+    }
+
+    /// Sets the symmetry of the map.
+    ///
+    /// See [`MapParameters::symmetry`] for more information.
+    pub fn symmetry(mut self, symmetry: Symmetry) -> Self {
+        self.symmetry = symmetry;
+        self
+    }
+
     /// Sets the method used to divide the map into regions.
     pub fn region_divide_method(mut self, method: RegionDivideMethod) -> Self {
         self.region_divide_method = method;
         self
     }
 
+    /// Sets the restriction on which continents civilizations can start on.
+    ///
+    /// See [`StartRestriction`] for more information.
+    pub fn start_continent(mut self, start_continent: StartRestriction) -> Self {
+        self.start_continent = start_continent;
+        self
+    }
+
+    /// Sets whether hill-dominant regions are classified as [`RegionType::Hill`].
+    ///
+    /// [`RegionType::Hill`]: crate::ruleset::RegionType::Hill
+    pub fn hill_as_region_type(mut self, hill_as_region_type: bool) -> Self {
+        self.hill_as_region_type = hill_as_region_type;
+        self
+    }
+
     /// Sets the list of civilizations which will be placed on the map, excluding city states and barbarians.
     ///
     /// # Arguments
@@ -345,9 +899,41 @@ impl MapParametersBuilder {
         self
     }
 
-    /// Sets whether the civilization starting tile is required to be coastal land.
-    pub fn civ_require_coastal_land_start(mut self, require: bool) -> Self {
-        self.civ_require_coastal_land_start = require;
+    /// Sets the number of city states to place on the map, overriding the default count derived from the world size.
+    ///
+    /// The city states are selected randomly. Use `0` to disable city states.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is greater than [`MapParameters::MAX_CITY_STATE_COUNT`].
+    pub fn city_state_count(mut self, count: u32) -> Self {
+        assert!(count <= MapParameters::MAX_CITY_STATE_COUNT);
+
+        self.city_state_count = Some(count);
+        self
+    }
+
+    /// Sets whether every civilization starting tile is required to be coastal land.
+    ///
+    /// See [`MapParameters::require_coastal_starts`] for more information.
+    pub fn require_coastal_starts(mut self, require: bool) -> Self {
+        self.require_coastal_starts = require;
+        self
+    }
+
+    /// Sets the minimum distance between the starting tiles of any two civilizations.
+    ///
+    /// See [`MapParameters::min_civ_distance`] for more information.
+    pub fn min_civ_distance(mut self, distance: u32) -> Self {
+        self.min_civ_distance = Some(distance);
+        self
+    }
+
+    /// Sets how the starting tiles of the civilizations are distributed on the map.
+    ///
+    /// See [`MapParameters::start_distribution`] for more information.
+    pub fn start_distribution(mut self, distribution: StartDistribution) -> Self {
+        self.start_distribution = distribution;
         self
     }
 
@@ -357,93 +943,263 @@ impl MapParametersBuilder {
         self
     }
 
+    /// Sets the strength of the civilization start bias.
+    ///
+    /// The value is clamped to the range of **[0.0, 1.0]**.
+    /// See [`MapParameters::start_bias_strength`] for more information.
+    pub fn start_bias_strength(mut self, strength: f64) -> Self {
+        self.start_bias_strength = strength.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the number of natural wonders to place on the map, overriding the default count derived from the world size.
+    pub fn natural_wonder_count(mut self, count: u32) -> Self {
+        self.natural_wonder_count = Some(count);
+        self
+    }
+
+    /// Sets the natural wonders which can be placed on the map.
+    ///
+    /// See [`MapParameters::allowed_natural_wonders`] for more information.
+    pub fn allowed_natural_wonders(mut self, natural_wonders: Vec<NaturalWonder>) -> Self {
+        self.allowed_natural_wonders = Some(natural_wonders);
+        self
+    }
+
+    /// Sets whether natural wonders avoid the outermost ring of the map along the axes that do not wrap.
+    pub fn natural_wonders_avoid_border(mut self, avoid: bool) -> Self {
+        self.natural_wonders_avoid_border = avoid;
+        self
+    }
+
+    /// Sets whether the natural wonders are placed before or after the city states and the resources.
+    ///
+    /// See [`MapParameters::wonder_resource_order`] for more information.
+    pub fn wonder_resource_order(mut self, order: WonderResourceOrder) -> Self {
+        self.wonder_resource_order = order;
+        self
+    }
+
     /// Sets the resource generation settings.
     pub fn resource_setting(mut self, setting: ResourceSetting) -> Self {
         self.resource_setting = setting;
         self
     }
 
+    /// Sets the variety of the luxury resources on the map.
+    ///
+    /// See [`MapParameters::luxury_variety`] for more information.
+    pub fn luxury_variety(mut self, variety: LuxuryVariety) -> Self {
+        self.luxury_variety = variety;
+        self
+    }
+
+    /// Sets the number of Marble deposits on the map.
+    ///
+    /// See [`MapParameters::marble_count`] for more information.
+    pub fn marble_count(mut self, count: u32) -> Self {
+        self.marble_count = Some(count);
+        self
+    }
+
+    /// Sets how the ripples of the resources add up where they overlap.
+    ///
+    /// See [`MapParameters::ripple_config`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// [`Self::build`] panics if [`RippleConfig::overlap_cap`] is not less than `98`.
+    pub fn ripple_config(mut self, ripple_config: RippleConfig) -> Self {
+        self.ripple_config = ripple_config;
+        self
+    }
+
+    /// Sets the minimum number of deposits of each strategic resource on the map.
+    ///
+    /// See [`MapParameters::min_strategic_deposits`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// [`Self::build`] panics if any resource is not a strategic resource in the ruleset.
+    pub fn min_strategic_deposits(mut self, min_deposits: HashMap<Resource, u32>) -> Self {
+        self.min_strategic_deposits = min_deposits;
+        self
+    }
+
+    /// Sets the name of the resource placed on or adjacent to every civilization starting tile, e.g. `"Wheat"`.
+    ///
+    /// See [`MapParameters::starting_bonus_resource`] for more information.
+    ///
+    /// # Panics
+    ///
+    /// [`Self::build`] panics if the resource does not exist in the ruleset.
+    pub fn starting_bonus_resource(mut self, resource: String) -> Self {
+        self.starting_bonus_resource = Some(resource);
+        self
+    }
+
+    /// Sets whether the civilization starting tiles are kept free of resources.
+    pub fn clear_resource_on_start(mut self, clear: bool) -> Self {
+        self.clear_resource_on_start = clear;
+        self
+    }
+
     /// Finalizes the construction and returns the `MapParameters` instance.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the starting bonus resource does not exist in the ruleset.
+    /// - Panics if any resource of the minimum strategic deposits is not a strategic resource in the ruleset.
+    /// - Panics if the overlap cap of the ripple config is not less than `98`,
+    ///   because the values from `98` block the placement of the resources.
+    /// - Panics if the start continent is [`StartRestriction::OldWorldOnly`],
+    ///   but the region divide method is neither [`RegionDivideMethod::Pangaea`] nor [`RegionDivideMethod::Continent`].
+    /// - Panics if the minimum continent gap is not less than half of the map width,
+    ///   because two continents could not fit on the map.
     pub fn build(self) -> MapParameters {
-        let mut rng = StdRng::seed_from_u64(self.seed);
+        assert!(
+            self.start_continent == StartRestriction::Anywhere
+                || matches!(
+                    self.region_divide_method,
+                    RegionDivideMethod::Pangaea | RegionDivideMethod::Continent
+                ),
+            "`StartRestriction::OldWorldOnly` requires the region divide method to be `Pangaea` or `Continent`."
+        );
 
-        let num_civilizations;
-        let civilization_list;
+        assert!(
+            self.min_continent_gap < self.world_grid.size().width / 2,
+            "The minimum continent gap {} must be less than half of the map width {}.",
+            self.min_continent_gap,
+            self.world_grid.size().width
+        );
 
-        if !self.civilization_list.is_empty() {
-            num_civilizations = self.civilization_list.len() as u32;
-            civilization_list = self.civilization_list;
-        } else {
-            num_civilizations = self.world_size_type_profile.num_civilizations;
+        if let Some(resource) = &self.starting_bonus_resource {
+            assert!(
+                self.ruleset
+                    .resources
+                    .values()
+                    .any(|resource_info| &resource_info.name == resource),
+                "The starting bonus resource `{resource}` does not exist in the ruleset."
+            );
+        }
+
+        for resource in self.min_strategic_deposits.keys() {
+            assert!(
+                resource.is_strategic(),
+                "The resource `{resource:?}` of the minimum strategic deposits is not a strategic resource."
+            );
+        }
+
+        assert!(
+            self.ripple_config.overlap_cap < 98,
+            "The overlap cap of the ripple config must be less than 98, got {}.",
+            self.ripple_config.overlap_cap
+        );
 
+        let mut map_parameters = MapParameters {
+            ruleset: self.ruleset,
+            map_type: self.map_type,
+            world_grid: self.world_grid,
+            seed: self.seed,
+            seed_bytes: self.seed_bytes,
+            subsystem_rng: self.subsystem_rng,
+            parallel: self.parallel,
+            world_size_type_profile: self.world_size_type_profile,
+            num_large_lakes: self.num_large_lakes,
+            max_lake_area_size: self.max_lake_area_size,
+            max_freshwater_lake_size: self.max_freshwater_lake_size,
+            coast_expand_chance: self.coast_expand_chance,
+            coast_width: self.coast_width,
+            sea_level: self.sea_level,
+            world_age: self.world_age,
+            fractal_grain: self.fractal_grain,
+            fractal_exp: self.fractal_exp,
+            hills_percent: self.hills_percent,
+            temperature: self.temperature,
+            rainfall: self.rainfall,
+            forest_density: self.forest_density,
+            jungle_density: self.jungle_density,
+            polar_ice: self.polar_ice,
+            allow_snow: self.allow_snow,
+            enable_tectonic_islands: self.enable_tectonic_islands,
+            remove_tiny_islands: self.remove_tiny_islands,
+            tiny_island_max_size: self.tiny_island_max_size,
+            pangaea_edge_bias: self.pangaea_edge_bias,
+            equatorial_ocean_width: self.equatorial_ocean_width,
+            min_continent_gap: self.min_continent_gap,
+            symmetry: self.symmetry,
+            region_divide_method: self.region_divide_method,
+            start_continent: self.start_continent,
+            hill_as_region_type: self.hill_as_region_type,
+            civilization_list: self.civilization_list,
+            city_state_list: self.city_state_list,
+            city_state_count: self.city_state_count,
+            require_coastal_starts: self.require_coastal_starts,
+            min_civ_distance: self.min_civ_distance,
+            start_distribution: self.start_distribution,
+            disable_start_bias_of_civ: self.disable_start_bias_of_civ,
+            start_bias_strength: self.start_bias_strength,
+            natural_wonder_count: self.natural_wonder_count,
+            allowed_natural_wonders: self.allowed_natural_wonders,
+            natural_wonders_avoid_border: self.natural_wonders_avoid_border,
+            wonder_resource_order: self.wonder_resource_order,
+            resource_setting: self.resource_setting,
+            luxury_variety: self.luxury_variety,
+            marble_count: self.marble_count,
+            ripple_config: self.ripple_config,
+            min_strategic_deposits: self.min_strategic_deposits,
+            starting_bonus_resource: self.starting_bonus_resource,
+            clear_resource_on_start: self.clear_resource_on_start,
+        };
+
+        let mut rng = map_parameters.seeded_rng();
+
+        let world_size_type_profile = &mut map_parameters.world_size_type_profile;
+
+        if !map_parameters.civilization_list.is_empty() {
+            world_size_type_profile.num_civilizations =
+                map_parameters.civilization_list.len() as u32;
+        } else {
             let all_civilizations = (0..Nation::LENGTH)
                 .map(Nation::from_usize)
                 .filter(|&nation| {
                     matches!(
-                        self.ruleset.nations[nation].nation_type,
+                        map_parameters.ruleset.nations[nation].nation_type,
                         NationType::Civilization
                     )
                 })
                 .collect::<Vec<_>>();
 
-            civilization_list = all_civilizations
-                .sample(&mut rng, num_civilizations as usize)
+            map_parameters.civilization_list = all_civilizations
+                .sample(&mut rng, world_size_type_profile.num_civilizations as usize)
                 .copied()
                 .collect();
-        };
-
-        let num_city_states;
-        let city_state_list;
+        }
 
-        if !self.city_state_list.is_empty() {
-            num_city_states = self.city_state_list.len() as u32;
-            city_state_list = self.city_state_list;
+        if !map_parameters.city_state_list.is_empty() {
+            world_size_type_profile.num_city_states = map_parameters.city_state_list.len() as u32;
         } else {
-            num_city_states = self.world_size_type_profile.num_city_states;
+            world_size_type_profile.num_city_states = map_parameters
+                .city_state_count
+                .unwrap_or(world_size_type_profile.num_city_states);
 
             let all_city_states = (0..Nation::LENGTH)
                 .map(Nation::from_usize)
                 .filter(|&nation| {
                     matches!(
-                        self.ruleset.nations[nation].nation_type,
+                        map_parameters.ruleset.nations[nation].nation_type,
                         NationType::CityState(_)
                     )
                 })
                 .collect::<Vec<_>>();
 
-            city_state_list = all_city_states
-                .sample(&mut rng, num_city_states as usize)
+            map_parameters.city_state_list = all_city_states
+                .sample(&mut rng, world_size_type_profile.num_city_states as usize)
                 .copied()
                 .collect();
         }
 
-        let world_size_type_profile = WorldSizeTypeProfile {
-            num_civilizations,
-            num_city_states,
-            ..self.world_size_type_profile
-        };
-
-        MapParameters {
-            ruleset: self.ruleset,
-            map_type: self.map_type,
-            world_grid: self.world_grid,
-            seed: self.seed,
-            world_size_type_profile,
-            num_large_lakes: self.num_large_lakes,
-            max_lake_area_size: self.max_lake_area_size,
-            coast_expand_chance: self.coast_expand_chance,
-            sea_level: self.sea_level,
-            world_age: self.world_age,
-            temperature: self.temperature,
-            rainfall: self.rainfall,
-            enable_tectonic_islands: self.enable_tectonic_islands,
-            region_divide_method: self.region_divide_method,
-            civilization_list,
-            city_state_list,
-            civ_require_coastal_land_start: self.civ_require_coastal_land_start,
-            disable_start_bias_of_civ: self.disable_start_bias_of_civ,
-            resource_setting: self.resource_setting,
-        }
+        map_parameters
     }
 }
 
@@ -496,9 +1252,25 @@ impl MapParametersBuilder {
 ///
 /// let world_grid = WorldGrid::new(grid, world_size_type);
 /// ```
+///
+/// Both methods accept a [`HexGrid`] or a [`SquareGrid`], the kind of the grid decides the kind of the generated map:
+/// ```rust
+/// use civ_map_generator::grid::*;
+/// use civ_map_generator::map_parameters::*;
+///
+/// let grid = SquareGrid::new(
+///     SquareGrid::default_size(WorldSizeType::Duel),
+///     SquareLayout::new(SquareOrientation::Orthogonal, [8., 8.], [0., 0.]),
+///     WrapFlags::WrapX,
+/// );
+///
+/// let world_grid = WorldGrid::from_grid(grid);
+/// assert!(matches!(world_grid.grid, MapGrid::Square(_)));
+/// ```
 #[derive(PartialEq, Clone, Copy, Debug)]
 pub struct WorldGrid {
-    pub grid: HexGrid,
+    /// The grid of the map. Its variant selects whether the map is generated on a [`HexGrid`] or a [`SquareGrid`].
+    pub grid: MapGrid,
     pub world_size_type: WorldSizeType,
 }
 
@@ -534,7 +1306,8 @@ impl WorldGrid {
     /// let world_grid = WorldGrid::new(grid, world_size_type);
     /// ```
     ///
-    pub fn new(grid: HexGrid, world_size: WorldSizeType) -> Self {
+    pub fn new(grid: impl Into<MapGrid>, world_size: WorldSizeType) -> Self {
+        let grid = grid.into();
         debug_assert!(
             grid.world_size_type() == world_size,
             "Grid size does not match the specified world size"
@@ -545,7 +1318,8 @@ impl WorldGrid {
         }
     }
 
-    pub fn from_grid(grid: HexGrid) -> Self {
+    pub fn from_grid(grid: impl Into<MapGrid>) -> Self {
+        let grid = grid.into();
         let world_size = grid.world_size_type();
         Self {
             grid,
@@ -555,7 +1329,7 @@ impl WorldGrid {
 
     /// Get the size of the grid.
     pub fn size(&self) -> Size {
-        self.grid.size
+        self.grid.size()
     }
 
     /// Get the world size of the grid.
@@ -566,35 +1340,47 @@ impl WorldGrid {
 
 impl Default for WorldGrid {
     fn default() -> Self {
-        let world_size = WorldSizeType::Standard;
-        let grid = HexGrid {
-            size: HexGrid::default_size(world_size),
-            layout: HexLayout {
-                orientation: HexOrientation::Pointy,
-                size: [50., 50.],
-                origin: [0., 0.],
-            },
-            wrap_flags: WrapFlags::WrapX,
-            offset: Offset::Odd,
-        };
         Self {
-            grid,
-            world_size_type: world_size,
+            grid: MapGrid::Hex(HexGrid::default()),
+            world_size_type: WorldSizeType::Standard,
         }
     }
 }
 
 /// The type of map to generate.
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 pub enum MapType {
     #[default]
     Fractal,
     Pangaea,
+    /// A rugged map where hills and mountains make up about 40% of the land.
+    Highlands,
+    /// A mostly-land map dotted with many small lakes instead of oceans.
+    Lakes,
+}
+
+/// The edge of the map which the supercontinent of [`MapType::Pangaea`] is biased toward.
+///
+/// When the supercontinent is biased toward an edge, the ocean dominates the opposite side of the map.
+/// The supercontinent is kept slightly away from the biased edge, so it is not split by the seam when the map wraps.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum PangaeaEdgeBias {
+    /// The supercontinent is centered on the map.
+    #[default]
+    Center,
+    /// The supercontinent is biased toward the north edge.
+    North,
+    /// The supercontinent is biased toward the south edge.
+    South,
+    /// The supercontinent is biased toward the east edge.
+    East,
+    /// The supercontinent is biased toward the west edge.
+    West,
 }
 
 /// The sea level of the map. It affect only terrain type generation.
 /// The higher the sea level, the more water tiles will be generated on the map.
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 pub enum SeaLevel {
     /// Fewer water tiles will be generated on the map than [`SeaLevel::Normal`].
     Low,
@@ -615,7 +1401,7 @@ pub enum SeaLevel {
 ///   The older the world, the less active the plates are.
 /// - The number of mountains and hills on the map.
 ///   The older the world, the fewer mountains and hills on the map.
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 pub enum WorldAge {
     /// 5 Billion Years
     ///
@@ -636,7 +1422,7 @@ pub enum WorldAge {
 }
 
 /// The temperature of the map. It affect only base terrain generation.
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 pub enum Temperature {
     /// More tundra and snow, less desert.
     Cool,
@@ -648,7 +1434,7 @@ pub enum Temperature {
 }
 
 /// The rainfall of the map. It affect only feature generation.
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 pub enum Rainfall {
     /// Less forest, jungle, and marsh.
     Arid,
@@ -662,7 +1448,7 @@ pub enum Rainfall {
 }
 
 /// Defines the method used to divide regions for civilizations in the game. This enum is used to determine how civilizations are assigned to different regions on the map.
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 pub enum RegionDivideMethod {
     /// All civilizations start on the biggest landmass.
     ///
@@ -683,6 +1469,94 @@ pub enum RegionDivideMethod {
     CustomRectangle(Rectangle),
 }
 
+/// The symmetry of the map. See [`MapParameters::symmetry`].
+///
+/// The map is exactly symmetric only when the mirror image of every tile is also on the map.
+/// It is always true for the default [`WorldGrid`], which is pointy and wraps on the x-axis.
+/// On other grids, the tiles whose mirror image is outside the map are left as generated.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum Symmetry {
+    /// The map is not mirrored. This is the default.
+    #[default]
+    None,
+    /// The west half of the map is mirrored onto the east half, about the vertical centerline.
+    Horizontal,
+    /// The south half of the map is rotated by 180 degrees onto the north half, about the center of the map.
+    Rotational,
+}
+
+impl Symmetry {
+    /// Returns the direction that `direction` becomes after being mirrored by this symmetry.
+    pub const fn mirror_direction(self, direction: Direction) -> Direction {
+        use Direction::*;
+
+        match self {
+            Symmetry::None => direction,
+            Symmetry::Horizontal => match direction {
+                North => North,
+                NorthEast => NorthWest,
+                East => West,
+                SouthEast => SouthWest,
+                South => South,
+                SouthWest => SouthEast,
+                West => East,
+                NorthWest => NorthEast,
+            },
+            Symmetry::Rotational => direction.opposite(),
+        }
+    }
+}
+
+/// Defines which continents civilizations can start on.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum StartRestriction {
+    /// Civilizations can start on any continent, according to [`MapParameters::region_divide_method`].
+    #[default]
+    Anywhere,
+    /// All civilizations start on the biggest landmass, the so-called "Old World",
+    /// and the other landmasses are left without starting tiles for exploration, like the Terra map in Civ 5.
+    ///
+    /// The other landmasses still get resources.
+    /// This requires [`MapParameters::region_divide_method`] to be [`RegionDivideMethod::Pangaea`] or [`RegionDivideMethod::Continent`].
+    OldWorldOnly,
+}
+
+/// How the starting tiles of the civilizations are distributed on the map. See [`MapParameters::start_distribution`].
+///
+/// In each region, the starting tile is searched first in a center-bias area, then in a middle-bias area, and at last in the rest of the region.
+/// This enum decides where those areas are in the region.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum StartDistribution {
+    /// The areas are in the middle of the region, like original CIV5. This is the default.
+    #[default]
+    Default,
+    /// The areas are moved to the side of the region away from the map center, so the starting tiles are spread out.
+    Spread,
+    /// The areas are moved to the side of the region toward the map center, so the starting tiles are clustered.
+    Clustered,
+}
+
+/// The order in which the natural wonders and the resources are placed. See [`MapParameters::wonder_resource_order`].
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum WonderResourceOrder {
+    /// The natural wonders are placed right after the civilization starting tiles,
+    /// the city states and the resources keep away from them. This is the default, like original CIV5.
+    #[default]
+    WondersFirst,
+    /// The natural wonders are placed after the city states and the resources,
+    /// so they don't take the spots of the resources.
+    ///
+    /// The impacts of the natural wonders on the resource and city state layers are placed after those layers are used,
+    /// so they reserve nothing. Instead, a natural wonder is not placed where it would occupy or change the terrain of a tile with a resource,
+    /// or on or next to a city state. The impact on [`Layer::NaturalWonder`](crate::tile_map::Layer::NaturalWonder)
+    /// still keeps the natural wonders away from each other.
+    ///
+    /// Fewer eligible tiles are left for the natural wonders, so fewer natural wonders than
+    /// [`MapParameters::natural_wonder_count`] may be placed on a small or crowded map.
+    /// On the default map, the same number of natural wonders is placed as [`WonderResourceOrder::WondersFirst`].
+    ResourcesFirst,
+}
+
 /// The resource setting of the map.
 #[derive(Clone, Copy, PartialEq, Eq, Default)]
 pub enum ResourceSetting {
@@ -697,13 +1571,108 @@ pub enum ResourceSetting {
     LegendaryStart,
     /// Every civilization will begin with a starting tile containing approximately the same amount of strategic resources.
     StrategicBalance,
+    /// Scales each category of resources independently, relative to [`ResourceSetting::Standard`].
+    ///
+    /// A multiplier of `1.0` is the same as [`ResourceSetting::Standard`], `2.0` places about twice as many resources,
+    /// and `0.0` places as few resources as possible.
+    Custom {
+        /// Scales the quantity of strategic deposits and the number of small strategic deposits.
+        strategic_multiplier: ResourceMultiplier,
+        /// Scales the number of luxuries placed in regions and randomly across the world, and the number of marbles.
+        luxury_multiplier: ResourceMultiplier,
+        /// Scales the number of bonus resources.
+        bonus_multiplier: ResourceMultiplier,
+    },
+}
+
+/// A multiplier of [`ResourceSetting::Custom`].
+///
+/// It is stored in per mille, so [`ResourceSetting`] can be compared exactly,
+/// and the multiplier is always finite and non-negative.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ResourceMultiplier(u32);
+
+impl ResourceMultiplier {
+    /// The multiplier `1.0`, which is the same as [`ResourceSetting::Standard`].
+    pub const ONE: Self = Self(1000);
+
+    /// Creates a multiplier from its value in per mille, e.g. `1500` is `1.5`.
+    pub const fn from_per_mille(per_mille: u32) -> Self {
+        Self(per_mille)
+    }
+
+    /// Creates a multiplier from `multiplier`, rounded to the nearest per mille.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `multiplier` is negative or not finite.
+    pub fn from_f64(multiplier: f64) -> Self {
+        assert!(
+            multiplier.is_finite() && multiplier >= 0.0,
+            "The multiplier must be finite and non-negative."
+        );
+        Self((multiplier * 1000.0).round() as u32)
+    }
+
+    /// Returns the value of the multiplier in per mille.
+    pub const fn per_mille(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the value of the multiplier.
+    pub fn as_f64(self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
+}
+
+/// The variety of the luxury resources on the map.
+///
+/// Some luxury types are disabled and never placed on the map, the number of them depends on the world size,
+/// so a smaller map has fewer luxury types. The variety adjusts that number.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum LuxuryVariety {
+    /// Two more luxury types are disabled than [`LuxuryVariety::Normal`].
+    Low,
+    /// The number of disabled luxury types is the same as original CIV5.
+    #[default]
+    Normal,
+    /// Two fewer luxury types are disabled than [`LuxuryVariety::Normal`], but never less than zero.
+    High,
+}
+
+/// How the ripples of the resources add up where they overlap. See [`MapParameters::ripple_config`].
+///
+/// When a resource is placed, the tiles around it get ripple values, the closer the tile, the higher the value.
+/// Where the ripple overlaps an existing ripple, the tile gets the greater of the two values plus [`RippleConfig::overlap_increment`],
+/// but no more than [`RippleConfig::overlap_cap`].
+/// When no tile without a ripple is left, the resources are placed on the tiles with the lowest values,
+/// so a smaller increment or cap makes the overlapping ripples weaker, and the resources cluster more densely.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RippleConfig {
+    /// The value added to the stronger ripple where two ripples overlap. The default is `2`.
+    pub overlap_increment: u32,
+    /// The max value of the overlapping ripples. It must be less than `98`. The default is `50`.
+    pub overlap_cap: u32,
+}
+
+impl Default for RippleConfig {
+    fn default() -> Self {
+        Self {
+            overlap_increment: 2,
+            overlap_cap: 50,
+        }
+    }
 }
 
 /// Stores the profile related to the world size type of the map.
+#[derive(Clone, Copy)]
 pub struct WorldSizeTypeProfile {
     /// The number of civilizations, excluding city states.
     ///
-    /// This value must be in the range of **[2, [`MapParameters::MAX_CIVILIZATION_COUNT`]]**.
+    /// This value must be `0` or in the range of **[2, [`MapParameters::MAX_CIVILIZATION_COUNT`]]**.
+    /// When it is `0`, the map has no regions and no civilization starting tiles,
+    /// and the natural wonders, city states and resources are placed over the whole map.
+    /// It is useful to generate a neutral backdrop map.
     pub num_civilizations: u32,
     /// The number of city states.
     ///
@@ -772,3 +1741,42 @@ impl WorldSizeTypeProfile {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map,
+        grid::WorldSizeType,
+        map_parameters::{MapParametersBuilder, WorldGrid},
+        test_utils::test_map_parameters,
+    };
+
+    /// Tests that a starting bonus resource which does not exist in the ruleset is rejected.
+    #[test]
+    #[should_panic]
+    fn test_invalid_starting_bonus_resource() {
+        MapParametersBuilder::new(WorldGrid::default())
+            .starting_bonus_resource("Unobtainium".to_string())
+            .build();
+    }
+
+    /// Tests that a map with a custom size is generated with that size, and classified by its area.
+    #[test]
+    fn test_custom_size() {
+        use crate::grid::Size;
+
+        let map_parameters = test_map_parameters().size(Size::new(100, 70)).build();
+        assert_eq!(
+            map_parameters.world_grid.world_size_type,
+            WorldSizeType::Large
+        );
+
+        let map = generate_map(&map_parameters);
+        assert_eq!(map.world_grid.size(), Size::new(100, 70));
+        assert_eq!(map.all_tiles().count(), 100 * 70);
+        assert_eq!(
+            map.starting_tile_and_civilization.len() as u32,
+            map_parameters.world_size_type_profile.num_civilizations
+        );
+    }
+}
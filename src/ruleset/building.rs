@@ -5,7 +5,7 @@ use super::{
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BuildingInfo {
     pub name: String,
@@ -50,7 +50,7 @@ pub struct BuildingInfo {
     pub quote: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum RequiredBuilding {
     /// Required building in a city.
@@ -3,7 +3,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::sync::LazyLock;
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Yields {
     #[serde(default)]
     pub food: i32,
@@ -21,7 +21,23 @@ pub struct Yields {
     pub happiness: i32,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl std::ops::Add for Yields {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            food: self.food + rhs.food,
+            production: self.production + rhs.production,
+            science: self.science + rhs.science,
+            gold: self.gold + rhs.gold,
+            culture: self.culture + rhs.culture,
+            faith: self.faith + rhs.faith,
+            happiness: self.happiness + rhs.happiness,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RequiredTerrain {
     #[serde(default = "default_terrain_type")]
@@ -75,7 +91,7 @@ fn default_base_terrain() -> Vec<BaseTerrain> {
     ]
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Unique {
     pub placeholder_text: String,
     pub params: Vec<String>,
@@ -43,3 +43,22 @@ impl EnumStr for BaseTerrain {
         }
     }
 }
+
+impl BaseTerrain {
+    /// Returns the default RGB color to render it, shared by the exporters and the UIs.
+    ///
+    /// The colors are generated from the 'RGB' field in the JSON file of the default ruleset,
+    /// so they don't follow a custom ruleset.
+    pub const fn display_color(&self) -> [u8; 3] {
+        match self {
+            BaseTerrain::Ocean => [45, 108, 145],
+            BaseTerrain::Coast => [107, 167, 193],
+            BaseTerrain::Grassland => [97, 171, 58],
+            BaseTerrain::Plain => [168, 185, 102],
+            BaseTerrain::Tundra => [189, 204, 191],
+            BaseTerrain::Desert => [230, 230, 113],
+            BaseTerrain::Lake => [123, 202, 226],
+            BaseTerrain::Snow => [231, 242, 249],
+        }
+    }
+}
@@ -1,9 +1,12 @@
 // Auto-generated by build.rs, DO NOT EDIT
 use super::EnumStr;
+use crate::ruleset::ResourceCategory;
 use enum_map::Enum;
 use serde::{Deserialize, Serialize};
 
-#[derive(Enum, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize, Debug)]
+#[derive(
+    Enum, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Serialize, Deserialize, Debug,
+)]
 pub enum Resource {
     Cattle,
     Sheep,
@@ -130,3 +133,48 @@ impl EnumStr for Resource {
         }
     }
 }
+
+impl Resource {
+    /// Returns the category of the resource.
+    pub const fn category(&self) -> ResourceCategory {
+        match self {
+            Resource::Cattle => ResourceCategory::Bonus,
+            Resource::Sheep => ResourceCategory::Bonus,
+            Resource::Deer => ResourceCategory::Bonus,
+            Resource::Bananas => ResourceCategory::Bonus,
+            Resource::Wheat => ResourceCategory::Bonus,
+            Resource::Stone => ResourceCategory::Bonus,
+            Resource::Fish => ResourceCategory::Bonus,
+            Resource::Bison => ResourceCategory::Bonus,
+            Resource::Horses => ResourceCategory::Strategic,
+            Resource::Iron => ResourceCategory::Strategic,
+            Resource::Coal => ResourceCategory::Strategic,
+            Resource::Oil => ResourceCategory::Strategic,
+            Resource::Aluminum => ResourceCategory::Strategic,
+            Resource::Uranium => ResourceCategory::Strategic,
+            Resource::Furs => ResourceCategory::Luxury,
+            Resource::Cotton => ResourceCategory::Luxury,
+            Resource::Dyes => ResourceCategory::Luxury,
+            Resource::Gems => ResourceCategory::Luxury,
+            Resource::GoldOre => ResourceCategory::Luxury,
+            Resource::Silver => ResourceCategory::Luxury,
+            Resource::Incense => ResourceCategory::Luxury,
+            Resource::Ivory => ResourceCategory::Luxury,
+            Resource::Silk => ResourceCategory::Luxury,
+            Resource::Spices => ResourceCategory::Luxury,
+            Resource::Wine => ResourceCategory::Luxury,
+            Resource::Sugar => ResourceCategory::Luxury,
+            Resource::Marble => ResourceCategory::Luxury,
+            Resource::Whales => ResourceCategory::Luxury,
+            Resource::Pearls => ResourceCategory::Luxury,
+            Resource::Jewelry => ResourceCategory::Luxury,
+            Resource::Porcelain => ResourceCategory::Luxury,
+            Resource::Citrus => ResourceCategory::Luxury,
+            Resource::Copper => ResourceCategory::Luxury,
+            Resource::Cocoa => ResourceCategory::Luxury,
+            Resource::Crab => ResourceCategory::Luxury,
+            Resource::Salt => ResourceCategory::Luxury,
+            Resource::Truffles => ResourceCategory::Luxury,
+        }
+    }
+}
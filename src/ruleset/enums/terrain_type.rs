@@ -31,3 +31,18 @@ impl EnumStr for TerrainType {
         }
     }
 }
+
+impl TerrainType {
+    /// Returns the default RGB color to render it, shared by the exporters and the UIs.
+    ///
+    /// The colors are generated from the 'RGB' field in the JSON file of the default ruleset,
+    /// so they don't follow a custom ruleset.
+    pub const fn display_color(&self) -> [u8; 3] {
+        match self {
+            TerrainType::Water => [45, 108, 145],
+            TerrainType::Flatland => [97, 171, 58],
+            TerrainType::Hill => [105, 125, 72],
+            TerrainType::Mountain => [120, 120, 120],
+        }
+    }
+}
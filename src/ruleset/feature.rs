@@ -1,8 +1,11 @@
-use super::common::{RequiredTerrain, Yields};
+use super::{
+    common::{RequiredTerrain, Yields},
+    enums::Feature,
+};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FeatureInfo {
     pub name: String,
@@ -25,3 +28,21 @@ pub struct FeatureInfo {
     #[serde(default)]
     pub civilopedia_text: Vec<HashMap<String, String>>,
 }
+
+impl Feature {
+    /// Returns the default RGB color to render the feature, shared by the exporters and the UIs.
+    ///
+    /// The ruleset doesn't define the colors of the features, so these colors are chosen to be drawn over the base terrains.
+    pub const fn display_color(&self) -> [u8; 3] {
+        match self {
+            Feature::Forest => [34, 100, 34],
+            Feature::Jungle => [46, 139, 87],
+            Feature::Marsh => [94, 120, 90],
+            Feature::Fallout => [140, 150, 40],
+            Feature::Oasis => [64, 196, 160],
+            Feature::Floodplain => [150, 190, 80],
+            Feature::Ice => [215, 235, 245],
+            Feature::Atoll => [220, 200, 140],
+        }
+    }
+}
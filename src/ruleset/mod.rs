@@ -7,13 +7,15 @@
 //! # Error Handling
 //!
 //! The [`Ruleset::new`] method will panic if any JSON file cannot be loaded or parsed.
+//! The [`Ruleset::try_new`] method checks the items used by the map generator before loading,
+//! and returns the missing items as an error.
 //! For production use, consider implementing proper error handling with `Result` types.
 
 use crate::ruleset::enums::*;
 use enum_map::{Enum, EnumArray, EnumMap};
 use serde::de::DeserializeOwned;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fs,
     path::{Path, PathBuf},
 };
@@ -70,7 +72,33 @@ where
     EnumMap::from_fn(|_| items_iter.next().expect("Not enough items in JSON file"))
 }
 
-#[derive(Debug)]
+/// Creates an [`EnumMap`] from a JSON file like [`create_enum_map_from_json_file`],
+/// but looks up the item of each variant by its `name` field instead of its position in the file.
+///
+/// It is used for the items referred to by the map generator, so the items can be in any order.
+fn create_enum_map_from_json_file_by_name<M, T>(path: PathBuf) -> EnumMap<M, T>
+where
+    M: EnumStr + EnumArray<T>,
+    T: DeserializeOwned,
+{
+    let json_string_without_comment = load_json_file_and_strip_json_comments(path);
+    let items: Vec<serde_json::Value> =
+        serde_json::from_str(&json_string_without_comment).expect("Failed to parse JSON file");
+
+    let mut name_and_item: HashMap<String, serde_json::Value> = items
+        .into_iter()
+        .map(|item| (item["name"].as_str().unwrap_or_default().to_string(), item))
+        .collect();
+
+    EnumMap::from_fn(|key: M| {
+        let item = name_and_item
+            .remove(key.as_str())
+            .unwrap_or_else(|| panic!("{} is missing from JSON file", key.as_str()));
+        serde_json::from_value(item).expect("Failed to parse JSON file")
+    })
+}
+
+#[derive(Debug, Clone)]
 pub struct Ruleset {
     // The structs related to terrains
     pub terrain_types: EnumMap<TerrainType, TerrainTypeInfo>,
@@ -132,19 +160,19 @@ impl Ruleset {
         /* **********Loading standard ruleset JSON file********** */
 
         let terrain_types: EnumMap<_, _> =
-            create_enum_map_from_json_file(ruleset_json_folder.join("TerrainType.json"));
+            create_enum_map_from_json_file_by_name(ruleset_json_folder.join("TerrainType.json"));
 
         let base_terrains: EnumMap<_, _> =
-            create_enum_map_from_json_file(ruleset_json_folder.join("BaseTerrain.json"));
+            create_enum_map_from_json_file_by_name(ruleset_json_folder.join("BaseTerrain.json"));
 
         let features: EnumMap<_, _> =
-            create_enum_map_from_json_file(ruleset_json_folder.join("Feature.json"));
+            create_enum_map_from_json_file_by_name(ruleset_json_folder.join("Feature.json"));
 
         let natural_wonders: EnumMap<_, _> =
-            create_enum_map_from_json_file(ruleset_json_folder.join("NaturalWonder.json"));
+            create_enum_map_from_json_file_by_name(ruleset_json_folder.join("NaturalWonder.json"));
 
         let resources: EnumMap<_, _> =
-            create_enum_map_from_json_file(ruleset_json_folder.join("Resource.json"));
+            create_enum_map_from_json_file_by_name(ruleset_json_folder.join("Resource.json"));
 
         let ruins: EnumMap<_, _> =
             create_enum_map_from_json_file(ruleset_json_folder.join("Ruin.json"));
@@ -317,6 +345,121 @@ impl Ruleset {
             global_uniques,
         }
     }
+
+    /// Creates a new Ruleset from a folder containing json files, like [`Ruleset::new`],
+    /// but checks the folder with [`Ruleset::validate_folder_for_generation`] at first.
+    ///
+    /// Use it to load a custom ruleset, so that a ruleset which can't be used by the map generator is reported,
+    /// instead of generating a map with mismatched infos or panicking deep inside the generation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the names of the items reported by [`Ruleset::validate_folder_for_generation`].
+    pub fn try_new(ruleset_json_folder: PathBuf) -> Result<Self, Vec<String>> {
+        Self::validate_folder_for_generation(&ruleset_json_folder)?;
+        Ok(Self::new(ruleset_json_folder))
+    }
+
+    /// Checks that the terrain types, base terrains, features, natural wonders and resources used by the map generator
+    /// are present in the ruleset.
+    ///
+    /// The map generator refers to these items by their enum variants, such as [`Resource::Marble`],
+    /// and [`Ruleset::new`] loads the info of each variant from the item with the same name in the JSON file.
+    /// So the info of each variant must have the same name as the variant, e.g. after the ruleset is modified in code.
+    /// [`generate_map_checked`](crate::generate_map_checked) calls it before generating the map.
+    ///
+    /// # Errors
+    ///
+    /// Returns the names of the variants whose infos have another name.
+    pub fn validate_for_generation(&self) -> Result<(), Vec<String>> {
+        fn missing_names<K: EnumStr + EnumArray<T>, T>(
+            infos: &EnumMap<K, T>,
+            name: impl Fn(&T) -> &str,
+        ) -> Vec<String> {
+            infos
+                .iter()
+                .filter(|(key, info)| name(info) != key.as_str())
+                .map(|(key, _)| key.as_str().to_string())
+                .collect()
+        }
+
+        let missing: Vec<String> = [
+            missing_names(&self.terrain_types, |info| info.name.as_str()),
+            missing_names(&self.base_terrains, |info| info.name.as_str()),
+            missing_names(&self.features, |info| info.name.as_str()),
+            missing_names(&self.natural_wonders, |info| info.name.as_str()),
+            missing_names(&self.resources, |info| info.name.as_str()),
+        ]
+        .concat();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
+    /// Checks the ruleset JSON files of the folder like [`Ruleset::validate_for_generation`], before loading them.
+    ///
+    /// Unlike [`Ruleset::validate_for_generation`], it reports the items missing from the JSON files,
+    /// which make [`Ruleset::new`] panic. The items can be in any order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the names of the items which are missing from their JSON files.
+    /// A JSON file which can't be read or parsed is regarded as having no item.
+    pub fn validate_folder_for_generation(ruleset_json_folder: &Path) -> Result<(), Vec<String>> {
+        fn missing_names<K: EnumStr + Enum>(json_path: PathBuf) -> impl Iterator<Item = String> {
+            let names: HashSet<String> = load_item_names(json_path).into_iter().collect();
+
+            (0..K::LENGTH)
+                .map(K::from_usize)
+                .filter(move |key| !names.contains(key.as_str()))
+                .map(|key| key.as_str().to_string())
+        }
+
+        let missing: Vec<String> =
+            missing_names::<TerrainType>(ruleset_json_folder.join("TerrainType.json"))
+                .chain(missing_names::<BaseTerrain>(
+                    ruleset_json_folder.join("BaseTerrain.json"),
+                ))
+                .chain(missing_names::<Feature>(
+                    ruleset_json_folder.join("Feature.json"),
+                ))
+                .chain(missing_names::<NaturalWonder>(
+                    ruleset_json_folder.join("NaturalWonder.json"),
+                ))
+                .chain(missing_names::<Resource>(
+                    ruleset_json_folder.join("Resource.json"),
+                ))
+                .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+}
+
+/// Loads the `name` field of every item in a JSON file.
+///
+/// Returns an empty list if the file can't be read or parsed.
+fn load_item_names(path: PathBuf) -> Vec<String> {
+    let Ok(json_string_with_comment) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let json_string_without_comment = strip_json_comments(&json_string_with_comment, true);
+
+    let Ok(items) = serde_json::from_str::<Vec<serde_json::Value>>(&json_string_without_comment)
+    else {
+        return Vec::new();
+    };
+
+    items
+        .iter()
+        .map(|item| item["name"].as_str().unwrap_or_default().to_string())
+        .collect()
 }
 
 fn load_json_file_and_strip_json_comments(path: PathBuf) -> String {
@@ -400,3 +543,100 @@ pub fn strip_json_comments(json_with_comments: &str, preserve_locations: bool) -
 
     json_without_comments
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ruleset::enums::TerrainType;
+
+    /// Tests that [`Ruleset::validate_for_generation`] and [`Ruleset::validate_folder_for_generation`]
+    /// report the items missing from a custom ruleset.
+    ///
+    /// [`Ruleset::validate_for_generation`]: crate::ruleset::Ruleset::validate_for_generation
+    /// [`Ruleset::validate_folder_for_generation`]: crate::ruleset::Ruleset::validate_folder_for_generation
+    #[test]
+    fn test_validate_ruleset_for_generation() {
+        use crate::{
+            ruleset::{Ruleset, enums::Resource},
+            test_utils::{TempRulesetFolder, default_ruleset_folder},
+        };
+        use std::fs;
+
+        let default_folder = default_ruleset_folder();
+        assert_eq!(
+            Ruleset::validate_folder_for_generation(&default_folder),
+            Ok(())
+        );
+        assert_eq!(Ruleset::default().validate_for_generation(), Ok(()));
+
+        // Create a custom ruleset which deletes Marble.
+        let custom_folder = TempRulesetFolder::new("validate_ruleset");
+        custom_folder.remove_item("Resource.json", "Marble");
+
+        let expected = Err(vec!["Marble".to_string()]);
+        assert_eq!(
+            Ruleset::validate_folder_for_generation(custom_folder.path()),
+            expected
+        );
+        assert_eq!(
+            Ruleset::try_new(custom_folder.path().to_path_buf()).err(),
+            expected.clone().err()
+        );
+
+        // A ruleset modified in code reports the info which doesn't have the name of its variant.
+        let mut custom_ruleset = Ruleset::default();
+        custom_ruleset.resources[Resource::Marble].name = "Granite".to_string();
+        assert_eq!(custom_ruleset.validate_for_generation(), expected);
+
+        // A missing JSON file reports all of its items.
+        fs::remove_file(custom_folder.path().join("TerrainType.json")).unwrap();
+        let missing = Ruleset::validate_folder_for_generation(custom_folder.path()).unwrap_err();
+        assert!(missing.starts_with(&["Water".to_string(), "Flatland".to_string()]));
+    }
+
+    /// Tests that [`Resource::category`] agrees with the resource types in the default ruleset.
+    ///
+    /// [`Resource::category`]: crate::ruleset::enums::Resource::category
+    #[test]
+    fn test_resource_category() {
+        use crate::ruleset::{ResourceCategory, Ruleset, enums::Resource};
+
+        assert_eq!(Resource::Iron.category(), ResourceCategory::Strategic);
+        assert_eq!(Resource::Gems.category(), ResourceCategory::Luxury);
+        assert!(Resource::Wheat.is_bonus());
+
+        // The categories agree with the resource types in the default ruleset.
+        let ruleset = Ruleset::default();
+        for (resource, resource_info) in ruleset.resources.iter() {
+            assert_eq!(
+                format!("{:?}", resource.category()),
+                resource_info.resource_type
+            );
+        }
+    }
+
+    /// Tests that the display colors of the base terrains and features are all distinct,
+    /// and that water shares the color of ocean.
+    #[test]
+    fn test_display_color() {
+        use crate::ruleset::enums::{BaseTerrain, Feature};
+        use enum_map::Enum;
+        use std::collections::HashSet;
+
+        let base_terrain_colors: HashSet<[u8; 3]> = (0..BaseTerrain::LENGTH)
+            .map(|index| BaseTerrain::from_usize(index).display_color())
+            .collect();
+        assert_eq!(base_terrain_colors.len(), BaseTerrain::LENGTH);
+
+        let feature_colors: HashSet<[u8; 3]> = (0..Feature::LENGTH)
+            .map(|index| Feature::from_usize(index).display_color())
+            .collect();
+        assert_eq!(feature_colors.len(), Feature::LENGTH);
+
+        assert_eq!(BaseTerrain::Ocean.display_color(), [45, 108, 145]);
+        assert_eq!(
+            TerrainType::Water.display_color(),
+            BaseTerrain::Ocean.display_color()
+        );
+        assert_eq!(TerrainType::Mountain.display_color(), [120, 120, 120]);
+    }
+}
@@ -1,7 +1,7 @@
 use super::enums::VictoryType;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NationInfo {
     pub name: String,
@@ -46,7 +46,7 @@ pub struct NationInfo {
     pub nation_type: NationType,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StartBias {
     AlongOcean,
     AlongRiver,
@@ -75,7 +75,7 @@ pub enum RegionType {
     Undefined,
 }
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub enum NationType {
     #[default]
     Civilization,
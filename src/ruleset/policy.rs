@@ -2,7 +2,7 @@ use super::enums::VictoryType;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyBranchInfo {
     pub name: String,
     pub era: String,
@@ -1,8 +1,12 @@
-use super::common::{RequiredTerrain, Yields};
+use super::{
+    common::{RequiredTerrain, Yields},
+    enums::Resource,
+};
+use enum_map::Enum;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ResourceInfo {
     pub name: String,
@@ -24,3 +28,40 @@ pub struct ResourceInfo {
     #[serde(default)]
     pub minor_deposit_amount: HashMap<String, i8>,
 }
+
+/// The category of a resource, which is [`ResourceInfo::resource_type`] of the resource in the default ruleset.
+///
+/// Use it instead of comparing [`ResourceInfo::resource_type`] with strings like `"Luxury"`.
+/// [`Resource::category`] is generated by `build.rs` from the `resourceType` field in `Resource.json`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, Debug)]
+pub enum ResourceCategory {
+    Bonus,
+    Luxury,
+    Strategic,
+}
+
+impl ResourceCategory {
+    /// Returns all the resources in this category, in the order of [`Resource`].
+    pub fn resources(self) -> impl Iterator<Item = Resource> {
+        (0..Resource::LENGTH)
+            .map(Resource::from_usize)
+            .filter(move |resource| resource.category() == self)
+    }
+}
+
+impl Resource {
+    /// Returns `true` if the resource is a [`ResourceCategory::Bonus`] resource.
+    pub const fn is_bonus(&self) -> bool {
+        matches!(self.category(), ResourceCategory::Bonus)
+    }
+
+    /// Returns `true` if the resource is a [`ResourceCategory::Luxury`] resource.
+    pub const fn is_luxury(&self) -> bool {
+        matches!(self.category(), ResourceCategory::Luxury)
+    }
+
+    /// Returns `true` if the resource is a [`ResourceCategory::Strategic`] resource.
+    pub const fn is_strategic(&self) -> bool {
+        matches!(self.category(), ResourceCategory::Strategic)
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TechColumn {
     pub column_number: u8,
@@ -11,7 +11,7 @@ pub struct TechColumn {
     pub techs: Vec<TechnologyInfo>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TechnologyInfo {
     pub name: String,
     #[serde(default)]
@@ -2,7 +2,7 @@ use super::common::{RequiredTerrain, Yields};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TileImprovementInfo {
     pub name: String,
@@ -0,0 +1,78 @@
+//! Fixtures shared by the unit tests of the crate.
+
+use crate::{
+    generate_map,
+    map_parameters::{MapParametersBuilder, WorldGrid},
+    ruleset::strip_json_comments,
+    tile_map::TileMap,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The seed used by the unit tests which don't need to try several seeds.
+pub(crate) const TEST_SEED: u64 = 12345;
+
+/// Returns a [`MapParametersBuilder`] on the default world grid, seeded with [`TEST_SEED`].
+pub(crate) fn test_map_parameters() -> MapParametersBuilder {
+    MapParametersBuilder::new(WorldGrid::default()).seed(TEST_SEED)
+}
+
+/// Generates a map with the default parameters of [`test_map_parameters`].
+pub(crate) fn generate_test_map() -> TileMap {
+    generate_map(&test_map_parameters().build())
+}
+
+/// The folder of the default ruleset.
+pub(crate) fn default_ruleset_folder() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src/jsons/Civ V - Gods & Kings")
+}
+
+/// A copy of the default ruleset folder in the temporary directory.
+///
+/// The folder is removed when it is dropped, so it doesn't leak even if the test fails.
+pub(crate) struct TempRulesetFolder(PathBuf);
+
+impl TempRulesetFolder {
+    /// Copies the default ruleset folder to a new folder in the temporary directory.
+    ///
+    /// `name` must be unique among the tests, because the tests run in parallel.
+    pub(crate) fn new(name: &str) -> Self {
+        let folder =
+            std::env::temp_dir().join(format!("civ_map_generator_{name}_{}", std::process::id()));
+        fs::create_dir_all(&folder).unwrap();
+        // Create the guard before copying, so the folder is removed if copying fails.
+        let temp_folder = Self(folder);
+        for entry in fs::read_dir(default_ruleset_folder()).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_file() {
+                fs::copy(&path, temp_folder.path().join(path.file_name().unwrap())).unwrap();
+            }
+        }
+        temp_folder
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.0
+    }
+
+    /// Removes the item whose `name` field is `name` from the JSON file named `file_name` of the folder.
+    ///
+    /// The comments of the file are removed too.
+    pub(crate) fn remove_item(&self, file_name: &str, name: &str) {
+        let path = self.0.join(file_name);
+        let json = strip_json_comments(&fs::read_to_string(&path).unwrap(), false);
+        let mut items: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        let num_items = items.len();
+        items.retain(|item| item["name"] != name);
+        assert_eq!(items.len() + 1, num_items, "`{name}` is not in {file_name}");
+        fs::write(path, serde_json::to_string(&items).unwrap()).unwrap();
+    }
+}
+
+impl Drop for TempRulesetFolder {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
@@ -4,10 +4,11 @@
 
 use crate::{
     grid::*,
-    map_parameters::MapParameters,
-    ruleset::{Ruleset, enums::*},
+    ruleset::{RequiredTerrain, Ruleset, enums::*},
     tile_map::*,
 };
+use arrayvec::ArrayVec;
+use std::collections::HashSet;
 
 /// The maximum distance a `Settler` can move in one turn, without considering technologies, eras, improvements, etc.
 ///
@@ -26,15 +27,31 @@ impl Tile {
         Self(index)
     }
 
-    /// Creates a `Tile` from an `OffsetCoordinate` according to the specified `HexGrid`.
+    /// Creates a `Tile` from an `OffsetCoordinate` according to the specified `MapGrid`.
     ///
-    pub fn from_offset(offset_coordinate: OffsetCoordinate, grid: HexGrid) -> Self {
+    pub fn from_offset(offset_coordinate: OffsetCoordinate, grid: MapGrid) -> Self {
         let cell = grid
             .offset_to_cell(offset_coordinate)
             .expect("Offset coordinate is out of bounds for the grid size");
         Self::from_cell(cell)
     }
 
+    /// Creates a `Tile` from an `OffsetCoordinate` on the grid of the given [`TileMap`].
+    ///
+    /// The coordinate is wrapped if the map wraps in that direction.
+    /// Returns `None` if the coordinate is out of bounds of the map, see [`Grid::offset_to_cell`].
+    pub fn from_offset_coordinate(
+        tile_map: &TileMap,
+        offset_coordinate: OffsetCoordinate,
+    ) -> Option<Self> {
+        tile_map
+            .world_grid
+            .grid
+            .offset_to_cell(offset_coordinate)
+            .ok()
+            .map(Self::from_cell)
+    }
+
     /// Creates a `Tile` from a `Cell`.
     ///
     #[inline(always)]
@@ -59,17 +76,24 @@ impl Tile {
     ///
     /// # Arguments
     ///
-    /// - `grid`: A `HexGrid` that contains the map size information.
+    /// - `grid`: A `MapGrid` that contains the map size information.
     ///
     /// # Returns
     /// Returns an `OffsetCoordinate` that corresponds to the provided tile, calculated based on the grid parameters.
     /// This coordinate represents the position of the tile within the map grid.
     ///
-    pub fn to_offset(&self, grid: HexGrid) -> OffsetCoordinate {
+    pub fn to_offset(&self, grid: MapGrid) -> OffsetCoordinate {
         grid.cell_to_offset(self.to_cell())
     }
 
-    /// Converts the current tile to a hexagonal coordinate based on the map parameters.
+    /// Converts the current tile to the corresponding offset coordinate on the grid of the given [`TileMap`].
+    ///
+    /// See [`Tile::to_offset`] for more information.
+    pub fn to_offset_coordinate(&self, tile_map: &TileMap) -> OffsetCoordinate {
+        self.to_offset(tile_map.world_grid.grid)
+    }
+
+    /// Converts the current tile to a hexagonal coordinate on the given `HexGrid`.
     ///
     /// # Returns
     /// Returns a `Hex` coordinate that corresponds to the provided map position, calculated based on the map grid parameters.
@@ -78,7 +102,7 @@ impl Tile {
     /// # Panics
     /// This method will panic if the tile is out of bounds for the given map size.
     pub fn to_hex(&self, grid: HexGrid) -> Hex {
-        let offset_coordinate = self.to_offset(grid);
+        let offset_coordinate = grid.cell_to_offset(self.to_cell());
         Hex::from_offset(offset_coordinate, grid.layout.orientation, grid.offset)
     }
 
@@ -93,7 +117,7 @@ impl Tile {
     ///
     /// # Arguments
     ///
-    /// - `grid`: A `HexGrid` that contains the map size information.
+    /// - `grid`: A `MapGrid` that contains the map size information.
     ///
     /// # Returns
     ///
@@ -102,18 +126,41 @@ impl Tile {
     /// # Panics
     ///
     /// This method will panic if the tile is out of bounds for the given map size.
-    pub fn latitude(&self, grid: HexGrid) -> f64 {
+    pub fn latitude(&self, grid: MapGrid) -> f64 {
         let y = self.to_offset(grid).0.y;
         let half_height = grid.height() as f64 / 2.0;
         (1.0 - y as f64 / half_height).abs()
     }
 
+    /// Returns the signed latitude of the tile, in the range of **[-1.0, 1.0]**.
+    ///
+    /// The northernmost row is `1.0`, the southernmost row is `-1.0`, and the value changes linearly in between,
+    /// so the rows at the vertical center of the map are around `0.0` (the equator).
+    /// Unlike [`Tile::latitude`], it keeps the hemisphere of the tile.
+    ///
+    /// # Notes
+    ///
+    /// Poles are only meaningful when the map doesn't wrap on the y-axis.
+    /// When the map wraps on the y-axis, the value is computed in the same way, but the northernmost and southernmost rows are adjacent.
+    pub fn signed_latitude(&self, tile_map: &TileMap) -> f64 {
+        let grid = tile_map.world_grid.grid;
+        let y = self.to_offset(grid).0.y;
+        let max_y = grid.height().saturating_sub(1).max(1) as f64;
+        2.0 * y as f64 / max_y - 1.0
+    }
+
     /// Returns the terrain type of the tile at the given index.
     #[inline]
     pub fn terrain_type(&self, tile_map: &TileMap) -> TerrainType {
         tile_map.terrain_type_list[self.0]
     }
 
+    /// Returns the elevation of the tile at the given index.
+    #[inline]
+    pub fn elevation(&self, tile_map: &TileMap) -> u8 {
+        tile_map.elevation_list[*self]
+    }
+
     /// Returns the base terrain of the tile at the given index.
     #[inline]
     pub fn base_terrain(&self, tile_map: &TileMap) -> BaseTerrain {
@@ -156,6 +203,12 @@ impl Tile {
         tile_map.terrain_type_list[self.0] = terrain_type;
     }
 
+    /// Sets the elevation of the tile at the given index.
+    #[inline]
+    pub fn set_elevation(&self, tile_map: &mut TileMap, elevation: u8) {
+        tile_map.elevation_list.set(*self, elevation);
+    }
+
     /// Sets the base terrain of the tile at the given index.
     #[inline]
     pub fn set_base_terrain(&self, tile_map: &mut TileMap, base_terrain: BaseTerrain) {
@@ -212,7 +265,7 @@ impl Tile {
 
     /// Returns an iterator over the neighboring tiles of the current tile.
     #[must_use = "iterators are lazy and do nothing unless consumed"]
-    pub fn neighbor_tiles(&self, grid: HexGrid) -> impl Iterator<Item = Self> + use<> {
+    pub fn neighbor_tiles(&self, grid: MapGrid) -> impl Iterator<Item = Self> + use<> {
         self.tiles_at_distance(1, grid)
     }
 
@@ -231,17 +284,38 @@ impl Tile {
     /// # Panics
     ///
     /// This method will panic if the current tile is out of bounds for the given map size.
-    pub fn neighbor_tile(&self, direction: Direction, grid: HexGrid) -> Option<Self> {
+    pub fn neighbor_tile(&self, direction: Direction, grid: MapGrid) -> Option<Self> {
         grid.neighbor(self.to_cell(), direction)
             .map(Self::from_cell)
     }
 
+    /// Returns all the existing neighboring tiles of the current tile in the given [`TileMap`].
+    ///
+    /// The tiles are ordered by the grid's edge direction array.
+    /// Neighbors which are off the map (on the edges of a non-wrapping map) are skipped,
+    /// so the returned list may contain fewer than 6 tiles.
+    pub fn neighbors(&self, tile_map: &TileMap) -> ArrayVec<Self, 6> {
+        let grid = tile_map.world_grid.grid;
+        grid.edge_direction_array()
+            .into_iter()
+            .filter_map(|direction| self.neighbor_tile(direction, grid))
+            .collect()
+    }
+
+    /// Retrieves the neighboring tile from the current tile in the specified direction in the given [`TileMap`].
+    ///
+    /// Returns `None` if the neighboring tile is off the map.
+    /// See [`Tile::neighbor_tile`] for more information.
+    pub fn neighbor(&self, tile_map: &TileMap, direction: Direction) -> Option<Self> {
+        self.neighbor_tile(direction, tile_map.world_grid.grid)
+    }
+
     /// Returns an iterator over the tiles at the given distance from the current tile.
     #[must_use = "iterators are lazy and do nothing unless consumed"]
     pub fn tiles_at_distance(
         &self,
         distance: u32,
-        grid: HexGrid,
+        grid: MapGrid,
     ) -> impl Iterator<Item = Self> + use<> {
         grid.cells_at_distance(self.to_cell(), distance)
             .map(Self::from_cell)
@@ -249,11 +323,56 @@ impl Tile {
 
     /// Returns an iterator over the tiles within the given distance from the current tile, including the current tile.
     #[must_use = "iterators are lazy and do nothing unless consumed"]
-    pub fn tiles_in_distance(&self, distance: u32, grid: HexGrid) -> impl Iterator<Item = Self> {
+    pub fn tiles_in_distance(&self, distance: u32, grid: MapGrid) -> impl Iterator<Item = Self> {
         grid.cells_within_distance(self.to_cell(), distance)
             .map(Self::from_cell)
     }
 
+    /// Returns an iterator over the tiles within the given distance from the current tile in the given [`TileMap`], including the current tile.
+    ///
+    /// See [`Tile::tiles_in_distance`] for more information.
+    #[must_use = "iterators are lazy and do nothing unless consumed"]
+    pub fn tiles_within_distance(
+        &self,
+        distance: u32,
+        tile_map: &TileMap,
+    ) -> impl Iterator<Item = Self> + use<> {
+        tile_map
+            .world_grid
+            .grid
+            .cells_within_distance(self.to_cell(), distance)
+            .map(Self::from_cell)
+    }
+
+    /// Returns the distance between the current tile and `other` in the given [`TileMap`].
+    ///
+    /// The distance is the number of steps between neighboring tiles, and it respects the wrapping of the map.
+    pub fn distance_to(&self, other: Tile, tile_map: &TileMap) -> u32 {
+        tile_map
+            .world_grid
+            .grid
+            .distance_to(self.to_cell(), other.to_cell()) as u32
+    }
+
+    /// Returns all the tiles which can be seen from the current tile within `sight_range`, including the current tile.
+    ///
+    /// A tile is visible if no tile on the straight line between the current tile and it (see [`MapGrid::line`]) is
+    /// [`TerrainType::Hill`] or [`TerrainType::Mountain`]. The hills and mountains themselves are visible,
+    /// only the tiles behind them are hidden. The terrain of the current tile doesn't matter.
+    ///
+    /// This is a simpler line of sight than [`TileMap::initial_visible_tiles`], which also takes the features into account.
+    pub fn visible_tiles(&self, tile_map: &TileMap, sight_range: u32) -> HashSet<Tile> {
+        tile_map
+            .line_of_sight_tiles(*self, sight_range, |tile| {
+                matches!(
+                    tile.terrain_type(tile_map),
+                    TerrainType::Hill | TerrainType::Mountain
+                )
+            })
+            .into_iter()
+            .collect()
+    }
+
     /// Checks if there is a river on the current tile.
     ///
     /// # Arguments
@@ -283,19 +402,22 @@ impl Tile {
     pub fn has_river_in_direction(&self, direction: Direction, tile_map: &TileMap) -> bool {
         let grid = tile_map.world_grid.grid;
         // Get the edge index for the specified direction.
-        let edge_index = grid.layout.orientation.edge_index(direction);
+        let edge_index = grid.edge_index(direction);
 
         // Determine the tile and edge direction to check based on the edge index.
-        let (check_tile, check_edge_direction) = if edge_index < 3 {
-            // If the edge index is less than 3, use the current tile and the given direction.
-            (*self, direction)
-        } else {
-            // Otherwise, check the neighboring tile and the opposite direction.
-            match self.neighbor_tile(direction, grid) {
-                Some(neighbor_tile) => (neighbor_tile, direction.opposite()),
-                None => return false,
-            }
-        };
+        // The river edges are stored in the tile whose edge is in the first half of the edge direction array,
+        // i.e. the edge indices `0..3` on hex grids and `0..2` on square grids.
+        let (check_tile, check_edge_direction) =
+            if edge_index < grid.edge_direction_array().len() / 2 {
+                // If the edge index is in the first half, use the current tile and the given direction.
+                (*self, direction)
+            } else {
+                // Otherwise, check the neighboring tile and the opposite direction.
+                match self.neighbor_tile(direction, grid) {
+                    Some(neighbor_tile) => (neighbor_tile, direction.opposite()),
+                    None => return false,
+                }
+            };
 
         tile_map.river_list.iter().flatten().any(|river_edge| {
             river_edge.tile == check_tile // 1. Check whether there is a river in the current tile.
@@ -311,7 +433,25 @@ impl Tile {
         self.terrain_type(tile_map) == TerrainType::Water
     }
 
+    /// Checks if the tile is land.
+    ///
+    /// When tile's terrain type is [`TerrainType::Flatland`], [`TerrainType::Hill`] or [`TerrainType::Mountain`], it is considered land.
+    /// It is always the opposite of [`Tile::is_water`].
+    pub fn is_land(&self, tile_map: &TileMap) -> bool {
+        self.terrain_type(tile_map) != TerrainType::Water
+    }
+
+    /// Checks if the tile is passable.
+    ///
+    /// It is always the opposite of [`Tile::is_impassable`].
+    pub fn is_passable(&self, tile_map: &TileMap, ruleset: &Ruleset) -> bool {
+        !self.is_impassable(tile_map, ruleset)
+    }
+
     /// Checks if the tile is impassable.
+    ///
+    /// A tile is impassable when its terrain type, feature or natural wonder is impassable in the ruleset,
+    /// e.g. [`TerrainType::Mountain`] and [`Feature::Ice`] in the default ruleset.
     pub fn is_impassable(&self, tile_map: &TileMap, ruleset: &Ruleset) -> bool {
         ruleset.terrain_types[self.terrain_type(tile_map)].impassable
             || self
@@ -331,35 +471,73 @@ impl Tile {
     /// We should check all base terrains and features of the neighboring tiles with `"Provide fresh water to adjacent land tiles"` unique,
     /// not just `Lake` and `Oasis`, there we don't use `ruleset` as argument to check the unique because of performance.
     /// Maybe in the future we will add a `ruleset` argument to `is_freshwater` function.
+    ///
+    /// A salt lake is [`BaseTerrain::Coast`] rather than [`BaseTerrain::Lake`], so it does not provide fresh water,
+    /// see [`MapParameters::max_freshwater_lake_size`](crate::map_parameters::MapParameters::max_freshwater_lake_size).
     pub fn is_freshwater(&self, tile_map: &TileMap) -> bool {
         let grid = tile_map.world_grid.grid;
-        self.terrain_type(tile_map) != TerrainType::Water
+        self.is_land(tile_map)
             && (self.neighbor_tiles(grid).any(|tile| {
                 tile.base_terrain(tile_map) == BaseTerrain::Lake
                     || tile.feature(tile_map) == Some(Feature::Oasis)
             }) || self.has_river(tile_map))
     }
 
+    /// Checks if the tile meets `required_terrain`, e.g. one of the required terrains of a resource.
+    ///
+    /// The terrain type and base terrain of the tile must be in the lists of `required_terrain`.
+    /// The feature and freshwater are only checked when they are specified,
+    /// an empty feature list means the tile must have no feature.
+    pub fn meets_required_terrain(
+        &self,
+        tile_map: &TileMap,
+        required_terrain: &RequiredTerrain,
+    ) -> bool {
+        required_terrain
+            .terrain_type
+            .contains(&self.terrain_type(tile_map))
+            && required_terrain
+                .base_terrain
+                .contains(&self.base_terrain(tile_map))
+            && required_terrain
+                .feature
+                .as_ref()
+                .is_none_or(|feature_list| match self.feature(tile_map) {
+                    Some(feature) => feature_list.contains(&feature),
+                    None => feature_list.is_empty(),
+                })
+            && required_terrain
+                .freshwater
+                .is_none_or(|freshwater| self.is_freshwater(tile_map) == freshwater)
+    }
+
     /// Check if the tile is coastal land.
     ///
-    /// A tile is considered `coastal land` if it is not `Water` and has at least one neighboring tile that is `Coast`.
+    /// A tile is considered `coastal land` if it is not `Water` and has at least one neighboring tile that is `Coast` or `Ocean`.
+    /// In a generated map, land is always surrounded by `Coast`, `Ocean` only matters for a map edited by hand.
     ///
     /// # Notes
     ///
-    /// If the tile is not `Water` and has at least one neighboring tile that is `Lake`, but it has no neighboring tile that is `Coast`, it is not `coastal land`.
+    /// If the tile is not `Water` and has at least one neighboring tile that is `Lake`, but it has no neighboring tile that is `Coast` or `Ocean`, it is not `coastal land`.
     pub fn is_coastal_land(&self, tile_map: &TileMap) -> bool {
         let grid = tile_map.world_grid.grid;
-        self.terrain_type(tile_map) != TerrainType::Water
-            && self
-                .neighbor_tiles(grid)
-                .any(|tile| tile.base_terrain(tile_map) == BaseTerrain::Coast)
+        self.is_land(tile_map)
+            && self.neighbor_tiles(grid).any(|tile| {
+                matches!(
+                    tile.base_terrain(tile_map),
+                    BaseTerrain::Coast | BaseTerrain::Ocean
+                )
+            })
     }
 
     /// Checks if a tile can be a starting tile of civilization.
     ///
     /// A tile is considered a starting tile if it is either `Flatland` or `Hill`, and then it must meet one of the following conditions:
     /// 1. The tile is a coastal land.
-    /// 2. If `civ_require_coastal_land_start` is `false`, An inland tile (whose distance to `Coast` is greater than 2) can be a starting tile as well.
+    /// 2. The tile is an inland tile whose distance to `Coast` is greater than 2.
+    ///
+    /// When [`MapParameters::require_coastal_starts`](crate::map_parameters::MapParameters::require_coastal_starts) is `true`, the inland tiles are only used
+    /// if the region cannot support a coastal start, see [`TileMap::choose_starting_tiles_of_civilization`].
     ///
     /// **Why Inland Tiles with Distance 2 from Coast are Excluded**
     ///
@@ -373,19 +551,14 @@ impl Tile {
     /// That's like in original CIV5.
     /// City state starting tile is the same as well.
     /// In CIV6, we should check the nature wonder in this function.
-    pub fn can_be_civilization_starting_tile(
-        &self,
-        tile_map: &TileMap,
-        map_parameters: &MapParameters,
-    ) -> bool {
+    pub fn can_be_civilization_starting_tile(&self, tile_map: &TileMap) -> bool {
         matches!(
             self.terrain_type(tile_map),
             TerrainType::Flatland | TerrainType::Hill
         ) && (self.is_coastal_land(tile_map)
-            || (!map_parameters.civ_require_coastal_land_start
-                && self
-                    .tiles_in_distance(SETTLER_MOVEMENT_RANGE, tile_map.world_grid.grid)
-                    .all(|tile| tile.base_terrain(tile_map) != BaseTerrain::Coast)))
+            || self
+                .tiles_in_distance(SETTLER_MOVEMENT_RANGE, tile_map.world_grid.grid)
+                .all(|tile| tile.base_terrain(tile_map) != BaseTerrain::Coast))
     }
 
     /// Checks if a tile can be a starting tile of city state.
@@ -438,4 +611,414 @@ impl Tile {
 
         true
     }
+
+    /// The weights used by [`Tile::fertility`].
+    pub const FERTILITY_WEIGHTS: FertilityWeights = FertilityWeights {
+        mountain: -2,
+        snow: -1,
+        oasis: 4,
+        floodplain: 5,
+        hill: 1,
+        grassland: 3,
+        plain: 4,
+        coast: 2,
+        lake: 2,
+        tundra: 2,
+        desert: 1,
+        forest: 0,
+        jungle: -1,
+        ice: -1,
+        marsh: -2,
+        river: 1,
+        freshwater: 1,
+        coastal_land: 2,
+    };
+
+    /// Returns the fertility of the tile, which shows how good the tile is for a city to work.
+    ///
+    /// It uses the same weighting as the region generation, see [`Tile::FERTILITY_WEIGHTS`] for the weights.
+    /// Resources are not taken into account, because the regions are generated before the resources are placed.
+    pub fn fertility(&self, tile_map: &TileMap) -> i32 {
+        self.start_placement_fertility(tile_map, true)
+    }
+
+    // function AssignStartingPlots:MeasureStartPlacementFertilityOfPlot
+    /// Returns the fertility of the tile for starting placement.
+    ///
+    /// When `check_for_coastal_land` is true, [`FertilityWeights::coastal_land`] is added if the tile is coastal land.
+    pub(crate) fn start_placement_fertility(
+        &self,
+        tile_map: &TileMap,
+        check_for_coastal_land: bool,
+    ) -> i32 {
+        let weights = &Self::FERTILITY_WEIGHTS;
+
+        let mut tile_fertility = 0;
+
+        // Measure Fertility -- Any cases absent from the process have a 0 value.
+        match self.terrain_type(tile_map) {
+            TerrainType::Mountain => {
+                // Note, mountains cannot belong to a landmass AreaID, so they usually go unmeasured.
+                return weights.mountain;
+            }
+            TerrainType::Hill => tile_fertility += weights.hill,
+            _ => {}
+        }
+
+        match self.base_terrain(tile_map) {
+            BaseTerrain::Snow => return weights.snow,
+            BaseTerrain::Grassland => tile_fertility += weights.grassland,
+            BaseTerrain::Plain => tile_fertility += weights.plain,
+            BaseTerrain::Coast => tile_fertility += weights.coast,
+            BaseTerrain::Lake => tile_fertility += weights.lake,
+            BaseTerrain::Tundra => tile_fertility += weights.tundra,
+            BaseTerrain::Desert => tile_fertility += weights.desert,
+            BaseTerrain::Ocean => {}
+        }
+
+        if let Some(feature) = self.feature(tile_map) {
+            match feature {
+                Feature::Oasis => return weights.oasis,
+                Feature::Floodplain => return weights.floodplain,
+                Feature::Forest => tile_fertility += weights.forest,
+                Feature::Jungle => tile_fertility += weights.jungle,
+                Feature::Ice => tile_fertility += weights.ice,
+                Feature::Marsh => tile_fertility += weights.marsh,
+                _ => {}
+            }
+        }
+
+        if self.has_river(tile_map) {
+            tile_fertility += weights.river;
+        }
+
+        if self.is_freshwater(tile_map) {
+            tile_fertility += weights.freshwater;
+        }
+
+        if check_for_coastal_land && self.is_coastal_land(tile_map) {
+            tile_fertility += weights.coastal_land;
+        }
+
+        tile_fertility
+    }
+}
+
+/// The weights used to compute the fertility of a tile, see [`Tile::fertility`].
+///
+/// The fertility of a tile is the sum of the weights of its terrain type, base terrain, feature,
+/// river, freshwater and coastal land, except that a tile which is mountain, snow, oasis or floodplain
+/// has the fixed fertility of the matching weight.
+#[derive(Clone, Copy, Debug)]
+pub struct FertilityWeights {
+    /// The fixed fertility of a mountain tile.
+    pub mountain: i32,
+    /// The fixed fertility of a snow tile.
+    pub snow: i32,
+    /// The fixed fertility of a tile with oasis.
+    pub oasis: i32,
+    /// The fixed fertility of a tile with floodplain.
+    pub floodplain: i32,
+    /// The fertility added by hill.
+    pub hill: i32,
+    /// The fertility added by grassland.
+    pub grassland: i32,
+    /// The fertility added by plain.
+    pub plain: i32,
+    /// The fertility added by coast.
+    pub coast: i32,
+    /// The fertility added by lake.
+    pub lake: i32,
+    /// The fertility added by tundra.
+    pub tundra: i32,
+    /// The fertility added by desert.
+    pub desert: i32,
+    /// The fertility added by forest.
+    pub forest: i32,
+    /// The fertility added by jungle.
+    pub jungle: i32,
+    /// The fertility added by ice.
+    pub ice: i32,
+    /// The fertility added by marsh.
+    pub marsh: i32,
+    /// The fertility added when the tile has a river.
+    pub river: i32,
+    /// The fertility added when the tile is freshwater.
+    pub freshwater: i32,
+    /// The fertility added when the tile is coastal land.
+    pub coastal_land: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grid::{Grid, GridSize, HexGrid, MapGrid, WorldSizeType},
+        map_parameters::{MapParametersBuilder, WorldGrid},
+        ruleset::enums::{BaseTerrain, TerrainType},
+        test_utils::{TEST_SEED, test_map_parameters},
+        tile::Tile,
+        tile_map::TileMap,
+    };
+
+    /// Tests that a corner tile on a non-wrapping map has fewer than 6 neighbors.
+    #[test]
+    fn test_corner_tile_neighbors() {
+        use crate::grid::{Grid, WrapFlags};
+
+        let world_size = WorldSizeType::Duel;
+        let grid = MapGrid::Hex(HexGrid {
+            size: HexGrid::default_size(world_size),
+            wrap_flags: WrapFlags::empty(),
+            ..HexGrid::default()
+        });
+        let map_parameters = MapParametersBuilder::new(WorldGrid::new(grid, world_size))
+            .seed(TEST_SEED)
+            .build();
+        let map = TileMap::new(&map_parameters);
+
+        let corner_tile = Tile::new(0);
+        let neighbors = corner_tile.neighbors(&map);
+        assert!(neighbors.len() < 6);
+        assert_eq!(neighbors.len(), corner_tile.neighbor_tiles(grid).count());
+        assert!(
+            grid.edge_direction_array()
+                .into_iter()
+                .filter_map(|direction| corner_tile.neighbor(&map, direction))
+                .eq(neighbors)
+        );
+    }
+
+    /// Tests that a desert tile with floodplain is more fertile than a flatland snow tile.
+    #[test]
+    fn test_tile_fertility() {
+        use crate::ruleset::enums::Feature;
+
+        let map_parameters = test_map_parameters().build();
+        let mut map = TileMap::new(&map_parameters);
+
+        let floodplain_tile = Tile::new(0);
+        floodplain_tile.set_terrain_type(&mut map, TerrainType::Flatland);
+        floodplain_tile.set_base_terrain(&mut map, BaseTerrain::Desert);
+        floodplain_tile.set_feature(&mut map, Feature::Floodplain);
+
+        let snow_tile = Tile::new(map.all_tiles().count() / 2);
+        snow_tile.set_terrain_type(&mut map, TerrainType::Flatland);
+        snow_tile.set_base_terrain(&mut map, BaseTerrain::Snow);
+
+        assert!(floodplain_tile.fertility(&map) > snow_tile.fertility(&map));
+        assert_eq!(
+            floodplain_tile.fertility(&map),
+            Tile::FERTILITY_WEIGHTS.floodplain
+        );
+        assert_eq!(snow_tile.fertility(&map), Tile::FERTILITY_WEIGHTS.snow);
+    }
+
+    /// Tests `Tile::is_land`, `Tile::is_water` and `Tile::is_passable` on each terrain type,
+    /// and that `Tile::is_passable` is the opposite of `Tile::is_impassable` on ice and an impassable natural wonder.
+    #[test]
+    fn test_tile_land_water_passable() {
+        use crate::ruleset::enums::{Feature, NaturalWonder};
+
+        let map_parameters = test_map_parameters().build();
+        let ruleset = &map_parameters.ruleset;
+        let mut map = TileMap::new(&map_parameters);
+
+        let cases = [
+            (TerrainType::Water, false, true, true),
+            (TerrainType::Flatland, true, false, true),
+            (TerrainType::Hill, true, false, true),
+            (TerrainType::Mountain, true, false, false),
+        ];
+        for (index, (terrain_type, is_land, is_water, is_passable)) in cases.into_iter().enumerate()
+        {
+            let tile = Tile::new(index);
+            tile.set_terrain_type(&mut map, terrain_type);
+            assert_eq!(tile.is_land(&map), is_land);
+            assert_eq!(tile.is_water(&map), is_water);
+            assert_eq!(tile.is_passable(&map, ruleset), is_passable);
+            assert_ne!(
+                tile.is_passable(&map, ruleset),
+                tile.is_impassable(&map, ruleset)
+            );
+        }
+
+        let ice_tile = Tile::new(cases.len());
+        ice_tile.set_terrain_type(&mut map, TerrainType::Water);
+        ice_tile.set_feature(&mut map, Feature::Ice);
+        assert!(ice_tile.is_water(&map));
+        assert!(!ice_tile.is_passable(&map, ruleset));
+        assert!(ice_tile.is_impassable(&map, ruleset));
+
+        let natural_wonder_tile = Tile::new(cases.len() + 1);
+        natural_wonder_tile.set_terrain_type(&mut map, TerrainType::Flatland);
+        assert!(natural_wonder_tile.is_passable(&map, ruleset));
+        natural_wonder_tile.set_natural_wonder(&mut map, NaturalWonder::MountFuji);
+        assert!(ruleset.natural_wonders[NaturalWonder::MountFuji].impassable);
+        assert!(!natural_wonder_tile.is_passable(&map, ruleset));
+        assert!(natural_wonder_tile.is_impassable(&map, ruleset));
+    }
+
+    /// Tests that the signed latitude is `1.0` at the northernmost row, `-1.0` at the southernmost row, and about `0.0` at the middle row.
+    #[test]
+    fn test_signed_latitude() {
+        use crate::grid::OffsetCoordinate;
+
+        let map_parameters = test_map_parameters().build();
+        let map = TileMap::new(&map_parameters);
+        let grid = map.world_grid.grid;
+        let height = grid.size().height as i32;
+
+        let signed_latitude =
+            |y| Tile::from_offset(OffsetCoordinate::new(0, y), grid).signed_latitude(&map);
+
+        assert_eq!(signed_latitude(height - 1), 1.0);
+        assert_eq!(signed_latitude(0), -1.0);
+        assert!(signed_latitude(height / 2).abs() < 0.05);
+    }
+
+    /// Tests [`Tile::distance_to`] and [`Tile::tiles_within_distance`].
+    #[test]
+    fn test_tile_distance_to() {
+        let map_parameters = test_map_parameters().build();
+        let map = TileMap::new(&map_parameters);
+
+        let tile = Tile::new(map.world_grid.grid.size().width as usize * 10 + 10);
+
+        assert_eq!(tile.distance_to(tile, &map), 0);
+        for neighbor_tile in tile.neighbors(&map) {
+            assert_eq!(tile.distance_to(neighbor_tile, &map), 1);
+            assert_eq!(neighbor_tile.distance_to(tile, &map), 1);
+        }
+
+        // 1 + 6 + 12 tiles within distance 2.
+        let tiles_within_distance: Vec<_> = tile.tiles_within_distance(2, &map).collect();
+        assert_eq!(tiles_within_distance.len(), 19);
+        assert!(
+            tiles_within_distance
+                .iter()
+                .all(|&other| tile.distance_to(other, &map) <= 2)
+        );
+    }
+
+    /// Tests that a hill or a mountain adjacent to the origin hides the tiles behind it, but not itself.
+    #[test]
+    fn test_visible_tiles() {
+        use crate::grid::{Direction, OffsetCoordinate};
+
+        let map_parameters = test_map_parameters().build();
+        let mut map = TileMap::new(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        let start = Tile::from_offset(OffsetCoordinate::new(20, 20), grid);
+        for tile in start.tiles_in_distance(3, grid) {
+            tile.set_terrain_type(&mut map, TerrainType::Flatland);
+        }
+
+        let direction = Direction::East;
+        let near_tile = start.neighbor_tile(direction, grid).unwrap();
+        let far_tile = near_tile.neighbor_tile(direction, grid).unwrap();
+
+        assert_eq!(
+            start.visible_tiles(&map, 3).len(),
+            start.tiles_in_distance(3, grid).count()
+        );
+
+        for terrain_type in [TerrainType::Hill, TerrainType::Mountain] {
+            near_tile.set_terrain_type(&mut map, terrain_type);
+
+            let visible_tiles = start.visible_tiles(&map, 3);
+            assert!(visible_tiles.contains(&start));
+            assert!(visible_tiles.contains(&near_tile));
+            assert!(!visible_tiles.contains(&far_tile));
+        }
+    }
+
+    /// Tests the round trip between `Tile` and `OffsetCoordinate` on the interior and the edges of the map,
+    /// and that a coordinate out of bounds of a non-wrapping direction has no tile.
+    #[test]
+    fn test_offset_coordinate_round_trip() {
+        use crate::grid::{Grid, OffsetCoordinate};
+
+        let map_parameters = test_map_parameters().build();
+        let map = TileMap::new(&map_parameters);
+        let grid = map.world_grid.grid;
+        let width = grid.width() as i32;
+        let height = grid.height() as i32;
+
+        for [x, y] in [
+            [width / 2, height / 2],
+            [0, 0],
+            [width - 1, 0],
+            [0, height - 1],
+            [width - 1, height - 1],
+        ] {
+            let offset_coordinate = OffsetCoordinate::new(x, y);
+            let tile = Tile::from_offset_coordinate(&map, offset_coordinate).unwrap();
+            assert_eq!(tile.to_offset_coordinate(&map), offset_coordinate);
+        }
+
+        assert!(map.all_tiles().all(|tile| {
+            Tile::from_offset_coordinate(&map, tile.to_offset_coordinate(&map)) == Some(tile)
+        }));
+
+        assert_eq!(
+            Tile::from_offset_coordinate(&map, OffsetCoordinate::new(0, height)),
+            None
+        );
+    }
+
+    /// Tests that only the land tiles next to coast or ocean are coastal land, the land tiles next to a lake are not.
+    #[test]
+    fn test_is_coastal_land() {
+        use crate::grid::{OffsetCoordinate, Size, WrapFlags};
+
+        let world_size = WorldSizeType::Duel;
+        let grid = MapGrid::Hex(HexGrid {
+            size: Size::new(8, 8),
+            wrap_flags: WrapFlags::empty(),
+            ..HexGrid::default()
+        });
+        let map_parameters = MapParametersBuilder::new(WorldGrid::new(grid, world_size))
+            .seed(TEST_SEED)
+            .build();
+        let mut map = TileMap::new(&map_parameters);
+
+        for tile in map.all_tiles() {
+            tile.set_terrain_type(&mut map, TerrainType::Flatland);
+            tile.set_base_terrain(&mut map, BaseTerrain::Grassland);
+        }
+
+        let tile_at = |x, y| Tile::from_offset(OffsetCoordinate::new(x, y), grid);
+
+        let coast_tile = tile_at(1, 1);
+        coast_tile.set_terrain_type(&mut map, TerrainType::Water);
+        coast_tile.set_base_terrain(&mut map, BaseTerrain::Coast);
+
+        let ocean_tile = tile_at(1, 6);
+        ocean_tile.set_terrain_type(&mut map, TerrainType::Water);
+        ocean_tile.set_base_terrain(&mut map, BaseTerrain::Ocean);
+
+        let lake_tile = tile_at(6, 6);
+        lake_tile.set_terrain_type(&mut map, TerrainType::Water);
+        lake_tile.set_base_terrain(&mut map, BaseTerrain::Lake);
+
+        let coastal_land_tiles: Vec<Tile> = map
+            .all_tiles()
+            .filter(|tile| tile.is_coastal_land(&map))
+            .collect();
+        let mut sea_neighbor_tiles: Vec<Tile> = coast_tile
+            .neighbor_tiles(grid)
+            .chain(ocean_tile.neighbor_tiles(grid))
+            .collect();
+        sea_neighbor_tiles.sort();
+        assert_eq!(coastal_land_tiles, sea_neighbor_tiles);
+
+        assert!(!coast_tile.is_coastal_land(&map));
+        assert!(!ocean_tile.is_coastal_land(&map));
+        assert!(
+            lake_tile
+                .neighbor_tiles(grid)
+                .all(|tile| !tile.is_coastal_land(&map))
+        );
+    }
 }
@@ -0,0 +1,195 @@
+//! Export the tile map to formats that can be used by other tools.
+//!
+//! This module is only available with the `export` feature.
+
+use crate::{grid::*, tile::Tile, tile_map::TileMap};
+use serde_json::{Value, json};
+
+/// Exports the tile map as a GeoJSON `FeatureCollection` string, which can be used for web visualization.
+///
+/// Every tile is exported as a `Feature` with a `Polygon` geometry, in the order of the tile index.
+/// The polygon is the hexagon or square of the tile computed from [`MapGrid::corners`], so the coordinates are pixel coordinates rather than longitude and latitude.
+/// As required by GeoJSON, the ring is counterclockwise and closed.
+///
+/// The properties of each feature are:
+///
+/// - `index`: the tile index.
+/// - `x`, `y`: the offset coordinate of the tile.
+/// - `terrain_type`, `base_terrain`: the terrain of the tile.
+/// - `feature`, `natural_wonder`: the feature and natural wonder of the tile, or `null`.
+/// - `resource`: an object with `name` and `quantity` of the resource of the tile, or `null`.
+/// - `river_edges`: the names of the [`Direction`]s of the tile edges which have a river, see [`Tile::has_river_in_direction`](crate::tile::Tile::has_river_in_direction).
+pub fn to_geojson(tile_map: &TileMap) -> String {
+    let grid = tile_map.world_grid.grid;
+
+    let features: Vec<Value> = tile_map
+        .all_tiles()
+        .map(|tile| {
+            let [x, y] = tile.to_offset(grid).to_array();
+
+            let mut ring: Vec<[f32; 2]> = grid
+                .corners(tile.to_offset(grid))
+                .into_iter()
+                .map(|corner| corner.to_array())
+                .collect();
+            // The corners are ordered clockwise, but GeoJSON requires the exterior ring to be counterclockwise.
+            ring.reverse();
+            ring.push(ring[0]);
+
+            json!({
+                "type": "Feature",
+                "geometry": {
+                    "type": "Polygon",
+                    "coordinates": [ring],
+                },
+                "properties": {
+                    "index": tile.index(),
+                    "x": x,
+                    "y": y,
+                    "terrain_type": tile.terrain_type(tile_map),
+                    "base_terrain": tile.base_terrain(tile_map),
+                    "feature": tile.feature(tile_map),
+                    "natural_wonder": tile.natural_wonder(tile_map),
+                    "resource": resource_json(tile, tile_map),
+                    "river_edges": river_edges_json(tile, tile_map),
+                },
+            })
+        })
+        .collect();
+
+    json!({
+        "type": "FeatureCollection",
+        "features": features,
+    })
+    .to_string()
+}
+
+impl TileMap {
+    /// Exports the tiles as a JSON array string, which can be used by game engines.
+    ///
+    /// The array is row-major, i.e. in the order of the tile index, so the tile at the offset coordinate `(x, y)` is at the index `x + y * width`.
+    /// Unlike [`to_geojson`], the tiles have no geometry. Every tile is an object with:
+    ///
+    /// - `x`, `y`: the offset coordinate of the tile.
+    /// - `terrain`, `base`: the terrain type and base terrain of the tile.
+    /// - `feature`, `natural_wonder`: the feature and natural wonder of the tile, or `null`.
+    /// - `resource`: an object with `name` and `quantity` of the resource of the tile, or `null`.
+    /// - `river_edges`: the names of the [`Direction`]s of the tile edges which have a river, see [`Tile::has_river_in_direction`].
+    ///
+    /// This method is only available with the `export` feature.
+    pub fn to_tile_json(&self) -> String {
+        let grid = self.world_grid.grid;
+
+        let tiles: Vec<Value> = self
+            .all_tiles()
+            .map(|tile| {
+                let [x, y] = tile.to_offset(grid).to_array();
+
+                json!({
+                    "x": x,
+                    "y": y,
+                    "terrain": tile.terrain_type(self),
+                    "base": tile.base_terrain(self),
+                    "feature": tile.feature(self),
+                    "resource": resource_json(tile, self),
+                    "natural_wonder": tile.natural_wonder(self),
+                    "river_edges": river_edges_json(tile, self),
+                })
+            })
+            .collect();
+
+        Value::Array(tiles).to_string()
+    }
+}
+
+/// Returns the resource of `tile` as an object with `name` and `quantity`, or `null` if it has no resource.
+fn resource_json(tile: Tile, tile_map: &TileMap) -> Value {
+    match tile.resource(tile_map) {
+        Some((resource, quantity)) => json!({
+            "name": resource,
+            "quantity": quantity,
+        }),
+        None => Value::Null,
+    }
+}
+
+/// Returns the names of the [`Direction`]s of the edges of `tile` which have a river.
+fn river_edges_json(tile: Tile, tile_map: &TileMap) -> Vec<String> {
+    let grid = tile_map.world_grid.grid;
+
+    grid.edge_direction_array()
+        .into_iter()
+        .filter(|&direction| tile.has_river_in_direction(direction, tile_map))
+        .map(|direction| format!("{direction:?}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::generate_test_map, tile::Tile};
+
+    /// Tests that the GeoJSON export can be parsed back, and it has one counterclockwise closed polygon per tile.
+    #[test]
+    fn test_to_geojson() {
+        use crate::tile_map::export::to_geojson;
+
+        let map = generate_test_map();
+
+        let geojson: serde_json::Value = serde_json::from_str(&to_geojson(&map)).unwrap();
+        assert_eq!(geojson["type"], "FeatureCollection");
+
+        let features = geojson["features"].as_array().unwrap();
+        assert_eq!(features.len(), map.all_tiles().count());
+
+        for (tile, feature) in map.all_tiles().zip(features) {
+            let properties = &feature["properties"];
+            assert_eq!(properties["index"], tile.index());
+            assert_eq!(
+                properties["terrain_type"],
+                serde_json::to_value(tile.terrain_type(&map)).unwrap()
+            );
+
+            let ring: Vec<[f64; 2]> =
+                serde_json::from_value(feature["geometry"]["coordinates"][0].clone()).unwrap();
+            assert_eq!(ring.len(), 7);
+            assert_eq!(ring[0], ring[6]);
+            // Shoelace formula, the signed area is positive when the ring is counterclockwise.
+            let signed_area: f64 = ring
+                .windows(2)
+                .map(|pair| pair[0][0] * pair[1][1] - pair[1][0] * pair[0][1])
+                .sum();
+            assert!(signed_area > 0.0);
+        }
+
+        assert!(features.iter().any(|feature| {
+            !feature["properties"]["river_edges"]
+                .as_array()
+                .unwrap()
+                .is_empty()
+        }));
+    }
+
+    /// Tests that [`TileMap::to_tile_json`] has one tile per map tile in row-major order.
+    #[test]
+    fn test_to_tile_json() {
+        use crate::grid::Grid;
+
+        let map = generate_test_map();
+        let width = map.world_grid.grid.width() as usize;
+        let height = map.world_grid.grid.height() as usize;
+
+        let tiles: Vec<serde_json::Value> = serde_json::from_str(&map.to_tile_json()).unwrap();
+        assert_eq!(tiles.len(), width * height);
+
+        for (index, tile) in tiles.iter().enumerate() {
+            assert_eq!(tile["x"], index % width);
+            assert_eq!(tile["y"], index / width);
+        }
+
+        let tile = Tile::new(width + 1);
+        assert_eq!(
+            tiles[tile.index()]["terrain"],
+            serde_json::to_value(tile.terrain_type(&map)).unwrap()
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! This module defines the [`Field`] type, which stores a scalar value for each tile of the map.
+
+use std::ops::{Index, IndexMut};
+
+use crate::tile::Tile;
+
+/// A per-tile data of the map, such as elevation.
+///
+/// It wraps a `Vec<T>` whose element at index `i` is the value of the tile `Tile::new(i)`,
+/// so it is the same as the parallel arrays in [`TileMap`](crate::tile_map::TileMap),
+/// but it is indexed by [`Tile`] instead of `usize`.
+///
+/// - [`Field::get`] and [`Field::get_mut`] return `None` when the tile is out of range.
+/// - [`Field::set`] and the `[]` operator panic when the tile is out of range.
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct Field<T>(Vec<T>);
+
+impl<T> Field<T> {
+    /// Creates a new `Field` with `size` tiles, and every tile's value is `value`.
+    pub fn new(size: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self(vec![value; size])
+    }
+
+    /// Returns the number of tiles in the field.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the field has no tile.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns a reference to the value of the tile, or `None` if the tile is out of range.
+    pub fn get(&self, tile: Tile) -> Option<&T> {
+        self.0.get(tile.index())
+    }
+
+    /// Returns a mutable reference to the value of the tile, or `None` if the tile is out of range.
+    pub fn get_mut(&mut self, tile: Tile) -> Option<&mut T> {
+        self.0.get_mut(tile.index())
+    }
+
+    /// Sets the value of the tile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tile is out of range.
+    pub fn set(&mut self, tile: Tile, value: T) {
+        self[tile] = value;
+    }
+
+    /// Returns an iterator over all tiles and their values, in the order of the tile index.
+    pub fn iter(&self) -> impl Iterator<Item = (Tile, &T)> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (Tile::new(index), value))
+    }
+
+    /// Returns an iterator over the values of all tiles, in the order of the tile index.
+    pub fn values(&self) -> std::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    /// Consumes the field and returns the inner `Vec<T>`.
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> From<Vec<T>> for Field<T> {
+    fn from(value_list: Vec<T>) -> Self {
+        Self(value_list)
+    }
+}
+
+impl<T> FromIterator<T> for Field<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<T> Index<Tile> for Field<T> {
+    type Output = T;
+
+    fn index(&self, tile: Tile) -> &Self::Output {
+        &self.0[tile.index()]
+    }
+}
+
+impl<T> IndexMut<Tile> for Field<T> {
+    fn index_mut(&mut self, tile: Tile) -> &mut Self::Output {
+        &mut self.0[tile.index()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Field;
+    use crate::tile::Tile;
+
+    #[test]
+    fn test_get_and_set() {
+        let mut field = Field::new(4, 0u8);
+        assert_eq!(field.len(), 4);
+
+        field.set(Tile::new(2), 7);
+        *field.get_mut(Tile::new(3)).unwrap() += 1;
+
+        assert_eq!(field.get(Tile::new(2)), Some(&7));
+        assert_eq!(field[Tile::new(3)], 1);
+        assert_eq!(
+            field.values().copied().collect::<Vec<_>>(),
+            vec![0, 0, 7, 1]
+        );
+        assert_eq!(field.iter().nth(2), Some((Tile::new(2), &7)));
+    }
+
+    #[test]
+    fn test_out_of_range_get() {
+        let mut field: Field<u8> = vec![1, 2, 3].into();
+        assert_eq!(field.get(Tile::new(3)), None);
+        assert_eq!(field.get_mut(Tile::new(100)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_out_of_range_set() {
+        let mut field = Field::new(3, 0u8);
+        field.set(Tile::new(3), 1);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    grid::WorldSizeType,
+    grid::{Grid, WorldSizeType},
     map_parameters::Rainfall,
     ruleset::{Ruleset, enums::*},
     tile_map::{AreaFlags, MapParameters, TileMap},
@@ -43,10 +43,13 @@ impl TileMap {
 
         // By default, the equator is at the vertical center of the map.
         // Use `equator_adjustment` to offset it.
-        let equator = grid.size.height as i32 / 2 + equator_adjustment;
+        let equator = grid.size().height as i32 / 2 + equator_adjustment;
 
-        let jungle_max_percent = jungle_percent as u32;
-        let forest_max_percent = forest_percent as u32;
+        // Scale the max percent of jungle and forest by the density.
+        // The jungle band below is still computed from the unscaled `jungle_percent`,
+        // so jungle is kept in the warm zone around the equator.
+        let jungle_max_percent = (jungle_percent as f64 * map_parameters.jungle_density) as u32;
+        let forest_max_percent = (forest_percent as f64 * map_parameters.forest_density) as u32;
         let marsh_max_percent = marsh_percent as u32;
         let oasis_max_percent = oasis_percent as u32;
 
@@ -70,8 +73,9 @@ impl TileMap {
                 continue;
             }
 
-            if tile.terrain_type(self) == TerrainType::Water {
-                if !tile.has_river(self)
+            if tile.is_water(self) {
+                if map_parameters.polar_ice
+                    && !tile.has_river(self)
                     && ice_required_terrain
                         .terrain_type
                         .contains(&tile.terrain_type(self))
@@ -82,10 +86,7 @@ impl TileMap {
                 {
                     let mut score = self.random_number_generator.random_range(0..100);
                     score += (latitude * 100.) as i32;
-                    if tile
-                        .neighbor_tiles(grid)
-                        .any(|tile| tile.terrain_type(self) != TerrainType::Water)
-                    {
+                    if tile.neighbor_tiles(grid).any(|tile| tile.is_land(self)) {
                         score /= 2;
                     }
                     let a = tile
@@ -170,9 +171,10 @@ impl TileMap {
                 let [_, y] = tile.to_offset(grid).to_array();
                 let jungle_required_terrain = &ruleset.features[Feature::Jungle].required_terrain;
 
-                if jungle_required_terrain
-                    .terrain_type
-                    .contains(&tile.terrain_type(self))
+                if jungle_max_percent > 0
+                    && jungle_required_terrain
+                        .terrain_type
+                        .contains(&tile.terrain_type(self))
                     && jungle_required_terrain
                         .base_terrain
                         .contains(&tile.base_terrain(self))
@@ -205,9 +207,10 @@ impl TileMap {
                 /* **********start to add forest********** */
                 let forest_required_terrain = &ruleset.features[Feature::Forest].required_terrain;
 
-                if forest_required_terrain
-                    .terrain_type
-                    .contains(&tile.terrain_type(self))
+                if forest_max_percent > 0
+                    && forest_required_terrain
+                        .terrain_type
+                        .contains(&tile.terrain_type(self))
                     && forest_required_terrain
                         .base_terrain
                         .contains(&tile.base_terrain(self))
@@ -250,7 +253,7 @@ impl TileMap {
         let num_tiles = self.area_list[biggest_water_area_id].size;
 
         // If the biggest water area is too small, we can't place any atolls.
-        if num_tiles <= grid.size.area() / 4 {
+        if num_tiles <= grid.size().area() / 4 {
             return;
         }
 
@@ -425,3 +428,40 @@ impl TileMap {
             .id
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_map, test_utils::test_map_parameters};
+
+    /// Tests that `forest_density` controls the amount of forest on the map.
+    #[test]
+    fn test_forest_density() {
+        use crate::ruleset::enums::Feature;
+
+        let forest_count = |forest_density: f64| {
+            let map_parameters = test_map_parameters().forest_density(forest_density).build();
+            let map = generate_map(&map_parameters);
+            map.all_tiles()
+                .filter(|tile| tile.feature(&map) == Some(Feature::Forest))
+                .count()
+        };
+
+        assert_eq!(forest_count(0.0), 0);
+        assert!(forest_count(2.0) > forest_count(1.0));
+    }
+
+    /// Tests that no ice is placed when `polar_ice` is false.
+    #[test]
+    fn test_polar_ice_disabled() {
+        use crate::ruleset::enums::Feature;
+
+        let map_parameters = test_map_parameters().polar_ice(false).build();
+        let map = generate_map(&map_parameters);
+
+        assert!(
+            map.feature_list
+                .iter()
+                .all(|&feature| feature != Some(Feature::Ice))
+        );
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
     tile::Tile,
     tile_map::{River, RiverEdge, TileMap},
 };
+use arrayvec::ArrayVec;
 use rand::{Rng, RngExt, seq::IndexedRandom};
 
 const RIVER_SOURCE_RANGE_DEFAULT: u32 = 4;
@@ -32,7 +33,7 @@ impl TileMap {
             .all_tiles()
             .filter_map(|tile| {
                 let inland_corner_list = self.get_inland_corner_list(tile);
-                if tile.terrain_type(self) != TerrainType::Water
+                if tile.is_land(self)
                     && tile.natural_wonder(self).is_none()
                     && !tile
                         .neighbor_tiles(grid)
@@ -101,7 +102,7 @@ impl TileMap {
                             .any(|tile| tile.is_freshwater(self))
                         && !anchor_tile
                             .tiles_in_distance(sea_water_range, grid)
-                            .any(|tile| tile.terrain_type(self) == TerrainType::Water)
+                            .any(|tile| tile.is_water(self))
                     {
                         // choose an inland corner tile as the start tile randomly
                         if let Some(&start_tile) =
@@ -126,7 +127,7 @@ impl TileMap {
     /// - `original_flow_direction`: The original flow direction at the start of the river.
     ///   - `None`: Algorithm automatically determines initial flow direction (default)
     ///   - `Some(Direction)`: Forces specific starting flow direction (must be a valid direction
-    ///     from [`Grid::corner_direction_array()`])
+    ///     from [`river_flow_directions`])
     fn do_river(&mut self, start_tile: Tile, original_flow_direction: Option<Direction>) {
         let grid = self.world_grid.grid;
         // This array contains the list of tuples.
@@ -134,22 +135,31 @@ impl TileMap {
         // 1. The first element indicates the next possible flow direction of the river.
         // 2. The second element represents the direction of a neighboring tile relative to the current tile.
         //    We evaluate the weight value of these neighboring tiles using a certain algorithm and select the minimum one to determine the next flow direction of the river.
-        let flow_direction_and_neighbor_tile_direction = match grid.layout.orientation {
-            HexOrientation::Pointy => [
-                (Direction::North, Direction::NorthWest),
-                (Direction::NorthEast, Direction::NorthEast),
-                (Direction::SouthEast, Direction::East),
-                (Direction::South, Direction::SouthWest),
-                (Direction::SouthWest, Direction::West),
-                (Direction::NorthWest, Direction::NorthWest),
-            ],
-            HexOrientation::Flat => [
-                (Direction::East, Direction::NorthEast),
-                (Direction::SouthEast, Direction::South),
-                (Direction::SouthWest, Direction::SouthWest),
-                (Direction::West, Direction::NorthWest),
-                (Direction::NorthWest, Direction::NorthWest),
-                (Direction::NorthEast, Direction::North),
+        let flow_direction_and_neighbor_tile_direction: &[(Direction, Direction)] = match grid {
+            MapGrid::Hex(hex_grid) => match hex_grid.layout.orientation {
+                HexOrientation::Pointy => &[
+                    (Direction::North, Direction::NorthWest),
+                    (Direction::NorthEast, Direction::NorthEast),
+                    (Direction::SouthEast, Direction::East),
+                    (Direction::South, Direction::SouthWest),
+                    (Direction::SouthWest, Direction::West),
+                    (Direction::NorthWest, Direction::NorthWest),
+                ],
+                HexOrientation::Flat => &[
+                    (Direction::East, Direction::NorthEast),
+                    (Direction::SouthEast, Direction::South),
+                    (Direction::SouthWest, Direction::SouthWest),
+                    (Direction::West, Direction::NorthWest),
+                    (Direction::NorthWest, Direction::NorthWest),
+                    (Direction::NorthEast, Direction::North),
+                ],
+            },
+            // On square grids, the river moves towards the neighbor in its flow direction.
+            MapGrid::Square(_) => &[
+                (Direction::North, Direction::North),
+                (Direction::East, Direction::East),
+                (Direction::South, Direction::South),
+                (Direction::West, Direction::West),
             ],
         };
 
@@ -173,19 +183,27 @@ impl TileMap {
         let mut original_flow_direction = original_flow_direction;
         let mut this_flow_direction = original_flow_direction;
 
+        // Square grids have no hex orientation, their rivers are extended by `extend_river_on_square_grid`.
+        let hex_orientation = match grid {
+            MapGrid::Hex(hex_grid) => Some(hex_grid.layout.orientation),
+            MapGrid::Square(_) => None,
+        };
+
         loop {
             let mut river_tile;
             if let Some(this_flow_direction) = this_flow_direction {
-                match (grid.layout.orientation, this_flow_direction) {
+                match (hex_orientation, this_flow_direction) {
                     /********** Pointy Hex Orientation And Flow Direction **********/
-                    (HexOrientation::Pointy, Direction::East | Direction::West) => unreachable!(),
-                    (HexOrientation::Pointy, Direction::North) => {
+                    (Some(HexOrientation::Pointy), Direction::East | Direction::West) => {
+                        unreachable!()
+                    }
+                    (Some(HexOrientation::Pointy), Direction::North) => {
                         river_tile = start_tile;
                         river.push(RiverEdge::new(river_tile, this_flow_direction));
                         if let Some(neighbor_tile) =
                             river_tile.neighbor_tile(Direction::NorthEast, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || neighbor_tile.has_river_in_direction(Direction::SouthEast, self)
                                 || neighbor_tile.has_river_in_direction(Direction::SouthWest, self)
                             {
@@ -197,12 +215,12 @@ impl TileMap {
                             break;
                         }
                     }
-                    (HexOrientation::Pointy, Direction::NorthEast) => {
+                    (Some(HexOrientation::Pointy), Direction::NorthEast) => {
                         river_tile = start_tile;
                         river.push(RiverEdge::new(river_tile, this_flow_direction));
                         if let Some(neighbor_tile) = river_tile.neighbor_tile(Direction::East, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || river_tile.has_river_in_direction(Direction::East, self)
                                 || neighbor_tile.has_river_in_direction(Direction::SouthWest, self)
                             {
@@ -212,7 +230,7 @@ impl TileMap {
                             break;
                         }
                     }
-                    (HexOrientation::Pointy, Direction::SouthEast) => {
+                    (Some(HexOrientation::Pointy), Direction::SouthEast) => {
                         if let Some(neighbor_tile) = start_tile.neighbor_tile(Direction::East, grid)
                         {
                             river_tile = neighbor_tile
@@ -223,7 +241,7 @@ impl TileMap {
                         if let Some(neighbor_tile) =
                             river_tile.neighbor_tile(Direction::SouthEast, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || river_tile.has_river_in_direction(Direction::SouthEast, self)
                             {
                                 break;
@@ -234,7 +252,7 @@ impl TileMap {
                         if let Some(neighbor_tile2) =
                             river_tile.neighbor_tile(Direction::SouthWest, grid)
                         {
-                            if neighbor_tile2.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile2.is_water(self)
                                 || neighbor_tile2.has_river_in_direction(Direction::East, self)
                             {
                                 break;
@@ -243,7 +261,7 @@ impl TileMap {
                             break;
                         }
                     }
-                    (HexOrientation::Pointy, Direction::South) => {
+                    (Some(HexOrientation::Pointy), Direction::South) => {
                         if let Some(neighbor_tile) =
                             start_tile.neighbor_tile(Direction::SouthWest, grid)
                         {
@@ -255,7 +273,7 @@ impl TileMap {
                         if let Some(neighbor_tile) =
                             river_tile.neighbor_tile(Direction::SouthEast, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || river_tile.has_river_in_direction(Direction::SouthEast, self)
                             {
                                 break;
@@ -273,13 +291,13 @@ impl TileMap {
                             break;
                         }
                     }
-                    (HexOrientation::Pointy, Direction::SouthWest) => {
+                    (Some(HexOrientation::Pointy), Direction::SouthWest) => {
                         river_tile = start_tile;
                         river.push(RiverEdge::new(river_tile, this_flow_direction));
                         if let Some(neighbor_tile) =
                             river_tile.neighbor_tile(Direction::SouthWest, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || neighbor_tile.has_river_in_direction(Direction::East, self)
                                 || river_tile.has_river_in_direction(Direction::SouthWest, self)
                             {
@@ -289,12 +307,12 @@ impl TileMap {
                             break;
                         }
                     }
-                    (HexOrientation::Pointy, Direction::NorthWest) => {
+                    (Some(HexOrientation::Pointy), Direction::NorthWest) => {
                         river_tile = start_tile;
                         river.push(RiverEdge::new(river_tile, this_flow_direction));
                         if let Some(neighbor_tile) = river_tile.neighbor_tile(Direction::West, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || neighbor_tile.has_river_in_direction(Direction::East, self)
                                 || neighbor_tile.has_river_in_direction(Direction::SouthEast, self)
                             {
@@ -308,14 +326,16 @@ impl TileMap {
                     }
                     /********** End Pointy Hex Orientation And Flow Direction **********/
                     /********** Flat Hex Orientation And Flow Direction **********/
-                    (HexOrientation::Flat, Direction::North | Direction::South) => unreachable!(),
-                    (HexOrientation::Flat, Direction::NorthEast) => {
+                    (Some(HexOrientation::Flat), Direction::North | Direction::South) => {
+                        unreachable!()
+                    }
+                    (Some(HexOrientation::Flat), Direction::NorthEast) => {
                         river_tile = start_tile;
                         river.push(RiverEdge::new(river_tile, this_flow_direction));
                         if let Some(neighbor_tile) =
                             river_tile.neighbor_tile(Direction::NorthEast, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || river_tile.has_river_in_direction(Direction::NorthEast, self)
                                 || neighbor_tile.has_river_in_direction(Direction::South, self)
                             {
@@ -325,7 +345,7 @@ impl TileMap {
                             break;
                         }
                     }
-                    (HexOrientation::Flat, Direction::East) => {
+                    (Some(HexOrientation::Flat), Direction::East) => {
                         if let Some(neighbor_tile) =
                             start_tile.neighbor_tile(Direction::NorthEast, grid)
                         {
@@ -337,7 +357,7 @@ impl TileMap {
                         if let Some(neighbor_tile) =
                             river_tile.neighbor_tile(Direction::SouthEast, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || river_tile.has_river_in_direction(Direction::SouthEast, self)
                             {
                                 break;
@@ -348,7 +368,7 @@ impl TileMap {
                         if let Some(neighbor_tile2) =
                             river_tile.neighbor_tile(Direction::South, grid)
                         {
-                            if neighbor_tile2.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile2.is_water(self)
                                 || neighbor_tile2.has_river_in_direction(Direction::NorthEast, self)
                             {
                                 break;
@@ -357,7 +377,7 @@ impl TileMap {
                             break;
                         }
                     }
-                    (HexOrientation::Flat, Direction::SouthEast) => {
+                    (Some(HexOrientation::Flat), Direction::SouthEast) => {
                         if let Some(neighbor_tile) =
                             start_tile.neighbor_tile(Direction::South, grid)
                         {
@@ -369,7 +389,7 @@ impl TileMap {
                         if let Some(neighbor_tile) =
                             river_tile.neighbor_tile(Direction::SouthEast, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || river_tile.has_river_in_direction(Direction::SouthEast, self)
                             {
                                 break;
@@ -380,7 +400,7 @@ impl TileMap {
                         if let Some(neighbor_tile2) =
                             river_tile.neighbor_tile(Direction::NorthEast, grid)
                         {
-                            if neighbor_tile2.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile2.is_water(self)
                                 || neighbor_tile2.has_river_in_direction(Direction::South, self)
                             {
                                 break;
@@ -389,13 +409,13 @@ impl TileMap {
                             break;
                         }
                     }
-                    (HexOrientation::Flat, Direction::SouthWest) => {
+                    (Some(HexOrientation::Flat), Direction::SouthWest) => {
                         river_tile = start_tile;
                         river.push(RiverEdge::new(river_tile, this_flow_direction));
                         if let Some(neighbor_tile) =
                             river_tile.neighbor_tile(Direction::South, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || river_tile.has_river_in_direction(Direction::South, self)
                                 || neighbor_tile.has_river_in_direction(Direction::NorthEast, self)
                             {
@@ -405,13 +425,13 @@ impl TileMap {
                             break;
                         }
                     }
-                    (HexOrientation::Flat, Direction::West) => {
+                    (Some(HexOrientation::Flat), Direction::West) => {
                         river_tile = start_tile;
                         river.push(RiverEdge::new(river_tile, this_flow_direction));
                         if let Some(neighbor_tile) =
                             river_tile.neighbor_tile(Direction::SouthWest, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || neighbor_tile.has_river_in_direction(Direction::NorthEast, self)
                                 || neighbor_tile.has_river_in_direction(Direction::SouthEast, self)
                             {
@@ -423,13 +443,13 @@ impl TileMap {
                             break;
                         }
                     }
-                    (HexOrientation::Flat, Direction::NorthWest) => {
+                    (Some(HexOrientation::Flat), Direction::NorthWest) => {
                         river_tile = start_tile;
                         river.push(RiverEdge::new(river_tile, this_flow_direction));
                         if let Some(neighbor_tile) =
                             river_tile.neighbor_tile(Direction::North, grid)
                         {
-                            if neighbor_tile.terrain_type(self) == TerrainType::Water
+                            if neighbor_tile.is_water(self)
                                 || neighbor_tile.has_river_in_direction(Direction::South, self)
                                 || neighbor_tile.has_river_in_direction(Direction::SouthEast, self)
                             {
@@ -441,19 +461,30 @@ impl TileMap {
                             break;
                         }
                     } /********** End Flat Hex Orientation And Flow Direction **********/
+                    /********** Square Grid And Flow Direction **********/
+                    (None, _) => {
+                        match self.extend_river_on_square_grid(
+                            start_tile,
+                            this_flow_direction,
+                            &mut river,
+                        ) {
+                            Some(neighbor_tile) => river_tile = neighbor_tile,
+                            None => break,
+                        }
+                    }
                 }
             } else {
                 river_tile = start_tile;
             }
 
-            if river_tile.terrain_type(self) == TerrainType::Water {
+            if river_tile.is_water(self) {
                 break;
             }
 
             // Get all next possible flow directions of the river.
             let next_possible_flow_directions: Vec<Direction> =
                 if let Some(this_flow_direction) = this_flow_direction {
-                    // If `this_flow_direction` is Some, we can choose at most 2 directions as the next flow direction.
+                    // If `this_flow_direction` is Some, we can choose at most 2 directions (3 on square grids) as the next flow direction.
                     // The next flow direction should not be the opposite of the original flow direction.
                     next_flow_directions(this_flow_direction, grid)
                         .into_iter()
@@ -462,15 +493,16 @@ impl TileMap {
                         })
                         .collect()
                 } else {
-                    // If `this_flow_direction` is None, we can choose 6 directions as the next flow direction.
-                    grid.corner_direction_array().to_vec()
+                    // If `this_flow_direction` is None, we can choose all flow directions of the grid as the next flow direction.
+                    river_flow_directions(grid).to_vec()
                 };
 
             // Get next possible flow direction and relative neighbor tile iterator to calculate the best flow direction.
             // NOTICE: When the river flows to the edge of the map, relative neighbor tile may not exist.
             let next_possible_flow_direction_and_neighbor_tile_iter =
                 flow_direction_and_neighbor_tile_direction
-                    .into_iter()
+                    .iter()
+                    .copied()
                     .filter_map(|(flow_direction, direction)| {
                         if next_possible_flow_directions.contains(&flow_direction) {
                             river_tile
@@ -564,8 +596,10 @@ impl TileMap {
 
         let mut sum = tile_elevation(self, tile) * 20;
 
-        // Usually, the tile have 6 neighbors. If not, the sum increases by 40 for each missing neighbor of the tile.
-        sum += 40 * (6 - tile.neighbor_tiles(grid).count() as i32);
+        // Usually, the tile have 6 neighbors on hex grids and 4 neighbors on square grids.
+        // If not, the sum increases by 40 for each missing neighbor of the tile.
+        sum += 40
+            * (grid.edge_direction_array().len() as i32 - tile.neighbor_tiles(grid).count() as i32);
 
         tile.neighbor_tiles(grid).for_each(|neighbor_tile| {
             sum += tile_elevation(self, neighbor_tile);
@@ -580,10 +614,15 @@ impl TileMap {
 
     /// Retrieves a vector contain all valid inland corner tiles based on the provided anchor tile.
     ///
-    /// An *inland corner* is defined as a tile where all neighbors in edge directions `0..3`
+    /// On hex grids, an *inland corner* is defined as a tile where all neighbors in edge directions `0..3`
     /// exist and are not water. This function evaluates the current tile and its neighbors
     /// in directions `3..6` as potential candidates, returning all valid inland corners.
     ///
+    /// On square grids, the river starts from the north-east corner of the start tile,
+    /// so an *inland corner* is a tile whose north, east and north-east tiles exist and are not water.
+    /// The candidates are the tiles whose north-east corner is a corner of the anchor tile,
+    /// i.e. the anchor tile and its west, south and south-west tiles.
+    ///
     /// # Arguments
     ///
     /// - `tile`: The anchor tile used to generate the inland corner tiles.
@@ -593,6 +632,31 @@ impl TileMap {
     /// A vector containing all valid inland corners according to the anchor tile.
     fn get_inland_corner_list(&mut self, tile: Tile) -> Vec<Tile> {
         let grid = self.world_grid.grid;
+
+        if let MapGrid::Square(_) = grid {
+            let south_tile = tile.neighbor_tile(Direction::South, grid);
+            return [
+                Some(tile),
+                tile.neighbor_tile(Direction::West, grid),
+                south_tile,
+                south_tile.and_then(|south_tile| south_tile.neighbor_tile(Direction::West, grid)),
+            ]
+            .into_iter()
+            .flatten()
+            .filter(|&candidate| {
+                let north_tile = candidate.neighbor_tile(Direction::North, grid);
+                [
+                    north_tile,
+                    candidate.neighbor_tile(Direction::East, grid),
+                    north_tile
+                        .and_then(|north_tile| north_tile.neighbor_tile(Direction::East, grid)),
+                ]
+                .into_iter()
+                .all(|corner_tile| corner_tile.is_some_and(|t| t.is_land(self)))
+            })
+            .collect();
+        }
+
         let edge_dirs = grid.edge_direction_array();
         // Construct an iterator over potential candidates: the current tile plus its neighbors in directions 3..6
         std::iter::once(tile)
@@ -606,12 +670,74 @@ impl TileMap {
                 edge_dirs[0..3].iter().all(|&dir| {
                     candidate
                         .neighbor_tile(dir, grid)
-                        .is_some_and(|t| t.terrain_type(self) != TerrainType::Water)
+                        .is_some_and(|t| t.is_land(self))
                 })
             })
             .collect()
     }
 
+    /// Extends `river` on a square grid by one edge, which starts at the north-east corner of `start_tile` and flows in `flow_direction`.
+    ///
+    /// Returns the tile whose north-east corner is the end of the new edge,
+    /// or `None` if the river goes off the map or ends at that corner, see [`TileMap::is_river_end_on_square_grid`].
+    fn extend_river_on_square_grid(
+        &self,
+        start_tile: Tile,
+        flow_direction: Direction,
+        river: &mut River,
+    ) -> Option<Tile> {
+        let grid = self.world_grid.grid;
+        let north_tile = start_tile.neighbor_tile(Direction::North, grid);
+
+        let (edge_tile, river_tile) = match flow_direction {
+            // The river edge is the east edge of the north neighbor of `start_tile`.
+            Direction::North => (north_tile, north_tile),
+            // The river edge is the south edge of the north-east tile of `start_tile`.
+            Direction::East => (
+                north_tile.and_then(|north_tile| north_tile.neighbor_tile(Direction::East, grid)),
+                start_tile.neighbor_tile(Direction::East, grid),
+            ),
+            // The river edge is the east edge of `start_tile`.
+            Direction::South => (
+                Some(start_tile),
+                start_tile.neighbor_tile(Direction::South, grid),
+            ),
+            // The river edge is the south edge of the north neighbor of `start_tile`.
+            Direction::West => (north_tile, start_tile.neighbor_tile(Direction::West, grid)),
+            _ => unreachable!(),
+        };
+
+        river.push(RiverEdge::new(edge_tile?, flow_direction));
+        let river_tile = river_tile?;
+        (!self.is_river_end_on_square_grid(river_tile)).then_some(river_tile)
+    }
+
+    /// Checks if the river on a square grid ends at the north-east corner of `tile`.
+    ///
+    /// The river ends when any of the 4 tiles meeting at the corner is water or off the map,
+    /// or when any river edge of the other rivers meets at the corner.
+    fn is_river_end_on_square_grid(&self, tile: Tile) -> bool {
+        let grid = self.world_grid.grid;
+
+        let (Some(north_tile), Some(east_tile)) = (
+            tile.neighbor_tile(Direction::North, grid),
+            tile.neighbor_tile(Direction::East, grid),
+        ) else {
+            return true;
+        };
+        let Some(north_east_tile) = north_tile.neighbor_tile(Direction::East, grid) else {
+            return true;
+        };
+
+        [tile, north_tile, east_tile, north_east_tile]
+            .into_iter()
+            .any(|corner_tile| corner_tile.is_water(self))
+            || tile.has_river_in_direction(Direction::East, self)
+            || tile.has_river_in_direction(Direction::North, self)
+            || north_tile.has_river_in_direction(Direction::East, self)
+            || east_tile.has_river_in_direction(Direction::North, self)
+    }
+
     /// Returns the number of river edges in the current area according to `area_id`
     fn river_edge_count(&self, current_area_id: usize) -> u32 {
         self.river_list
@@ -641,6 +767,19 @@ impl TileMap {
     }
 }
 
+/// Returns the flow directions of the rivers on `grid`.
+///
+/// On hex grids, a river edge is parallel to the direction from the center of a hexagon to one of its corners,
+/// so the flow directions are [`Grid::corner_direction_array`].
+/// On square grids, a river edge is parallel to the direction from the center of a square to one of its edges,
+/// so the flow directions are [`Grid::edge_direction_array`].
+pub(crate) fn river_flow_directions(grid: MapGrid) -> DirectionArray {
+    match grid {
+        MapGrid::Hex(_) => grid.corner_direction_array(),
+        MapGrid::Square(_) => grid.edge_direction_array(),
+    }
+}
+
 /// Returns the next possible flow directions of the river based on the current flow direction.
 ///
 /// # Arguments
@@ -650,13 +789,29 @@ impl TileMap {
 ///
 /// # Returns
 ///
-/// An array containing two `Direction` values:
+/// An array containing the next possible flow directions:
 /// - The first element represents the flow direction after a clockwise turn.
 /// - The second element represents the flow direction after a counterclockwise turn.
-fn next_flow_directions(flow_direction: Direction, grid: HexGrid) -> [Direction; 2] {
-    let hex_orientation = grid.layout.orientation;
-    [
-        hex_orientation.corner_clockwise(flow_direction), // turn_right_flow_direction
-        hex_orientation.corner_counter_clockwise(flow_direction), // turn_left_flow_direction
-    ]
+/// - On square grids, the third element is `flow_direction` itself, because the river can also flow straight on.
+pub(crate) fn next_flow_directions(
+    flow_direction: Direction,
+    grid: MapGrid,
+) -> ArrayVec<Direction, 3> {
+    match grid {
+        MapGrid::Hex(grid) => {
+            let hex_orientation = grid.layout.orientation;
+            ArrayVec::from_iter([
+                hex_orientation.corner_clockwise(flow_direction), // turn_right_flow_direction
+                hex_orientation.corner_counter_clockwise(flow_direction), // turn_left_flow_direction
+            ])
+        }
+        MapGrid::Square(grid) => {
+            let square_orientation = grid.layout.orientation;
+            ArrayVec::from([
+                square_orientation.edge_clockwise(flow_direction), // turn_right_flow_direction
+                square_orientation.edge_counter_clockwise(flow_direction), // turn_left_flow_direction
+                flow_direction,
+            ])
+        }
+    }
 }
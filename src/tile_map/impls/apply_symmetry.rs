@@ -0,0 +1,566 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BTreeMap,
+    sync::OnceLock,
+};
+
+use crate::{
+    grid::{Grid, Hex, HexOrientation, MapGrid, OffsetCoordinate},
+    map_parameters::{MapParameters, Symmetry},
+    ruleset::enums::Nation,
+    tile::Tile,
+    tile_map::{River, RiverEdge, TileMap},
+};
+
+impl TileMap {
+    /// Mirrors one half of the map onto the other half according to [`MapParameters::symmetry`].
+    ///
+    /// For each tile and its mirror image (see [`TileMap::symmetric_tile`]), the one with the smaller index is the source,
+    /// and its terrain type, elevation, base terrain and feature are copied to the other one.
+    /// The river edges on the source side are mirrored onto the other side, their flow directions are mirrored by [`Symmetry::mirror_direction`].
+    /// The river edges which were on the other side are removed.
+    ///
+    /// It does nothing when [`MapParameters::symmetry`] is [`Symmetry::None`].
+    ///
+    /// # Notes
+    ///
+    /// This method should be called after [`TileMap::add_features`] and before [`TileMap::recalculate_areas`],
+    /// so the areas, regions and starting tiles are computed on the symmetric map.
+    /// The starting tiles and resources are mirrored later by [`TileMap::apply_symmetry_to_placements`].
+    pub fn apply_symmetry(&mut self, map_parameters: &MapParameters) {
+        let symmetry = map_parameters.symmetry;
+
+        if symmetry == Symmetry::None {
+            return;
+        }
+
+        for tile in self.all_tiles() {
+            let Some(mirror_tile) = self.symmetric_tile(tile, symmetry) else {
+                continue;
+            };
+
+            if tile.index() >= mirror_tile.index() {
+                continue;
+            }
+
+            self.copy_tile_content(tile, mirror_tile);
+        }
+
+        let mut kept_river_list = Vec::new();
+        let mut mirrored_river_list = Vec::new();
+
+        for river in std::mem::take(&mut self.river_list) {
+            // A river is split where its edges on the other side are removed, so every river stays continuous.
+            let mut kept_river = River::new();
+            let mut mirrored_river = River::new();
+
+            for river_edge in river {
+                match self.symmetric_river_edge(&river_edge, symmetry) {
+                    // The edge is on the source side.
+                    Some((Ordering::Less, mirrored_edge)) => {
+                        kept_river.push(river_edge);
+                        mirrored_river.push(mirrored_edge);
+                    }
+                    // The edge is on the other side.
+                    Some((Ordering::Greater, _)) => {
+                        flush_river(&mut kept_river, &mut kept_river_list);
+                        flush_river(&mut mirrored_river, &mut mirrored_river_list);
+                    }
+                    // The edge is its own mirror image, or it has no mirror image on the map.
+                    Some((Ordering::Equal, _)) | None => kept_river.push(river_edge),
+                }
+            }
+
+            flush_river(&mut kept_river, &mut kept_river_list);
+            flush_river(&mut mirrored_river, &mut mirrored_river_list);
+        }
+
+        kept_river_list.extend(mirrored_river_list);
+        self.river_list = kept_river_list;
+    }
+
+    /// Mirrors the starting tiles of civilizations and city states, and the resources, according to [`MapParameters::symmetry`].
+    ///
+    /// The starting tiles are chosen on the symmetric map as usual, so they are close to symmetric but usually not exactly.
+    /// This method keeps one starting tile of each mirror pair, and moves the other civilizations to the mirror images of the kept starting tiles:
+    /// - The starting tiles farther from their mirror images are kept first.
+    /// - A starting tile is kept only when it and its mirror image are at least 5 tiles away from the other kept starting tiles and their mirror images.
+    ///   If there are not enough such starting tiles, the civilizations are allowed to start closer, but never on the same tile.
+    /// - Only as many starting tiles as needed are kept. So the starting tiles are symmetric when the number of civilizations is even,
+    ///   otherwise one of them has no mirror image.
+    /// - The city states are handled in the same way after the civilizations. The city states which can't be mirrored are removed,
+    ///   so the starting tiles of city states are always symmetric.
+    ///
+    /// Then the terrain types, elevations, base terrains, features and resources of every tile and its mirror image are made the same:
+    /// - The tiles within 3 tiles of a kept starting tile and within 1 tile of a natural wonder are the source,
+    ///   so the balanced surroundings of the starting tiles and the surroundings of the natural wonders are kept and mirrored.
+    /// - Otherwise the one with the smaller index is the source, as [`TileMap::apply_symmetry`].
+    /// - A natural wonder is unique, so it is not mirrored. Its mirror image keeps its terrain, but its resource is removed.
+    ///
+    /// When a civilization is moved, [`Region::starting_tile`] of the region which had its starting tile is updated to the new starting tile.
+    ///
+    /// It does nothing when [`MapParameters::symmetry`] is [`Symmetry::None`].
+    ///
+    /// # Notes
+    ///
+    /// This method should be called after the starting tiles, natural wonders and resources are placed.
+    ///
+    /// [`Region::starting_tile`]: crate::tile_map::Region::starting_tile
+    pub fn apply_symmetry_to_placements(&mut self, map_parameters: &MapParameters) {
+        const START_SURROUNDING_RADIUS: u32 = 3;
+        const NATURAL_WONDER_SURROUNDING_RADIUS: u32 = 1;
+
+        let symmetry = map_parameters.symmetry;
+
+        if symmetry == Symmetry::None {
+            return;
+        }
+
+        let grid = self.world_grid.grid;
+
+        let mut used_tiles = Vec::new();
+        let mut kept_starting_tiles = Vec::new();
+
+        let starting_tile_and_civilization =
+            std::mem::take(&mut self.starting_tile_and_civilization);
+        self.starting_tile_and_civilization = self.symmetric_starting_tiles(
+            starting_tile_and_civilization.clone(),
+            symmetry,
+            false,
+            &mut used_tiles,
+            &mut kept_starting_tiles,
+        );
+
+        // Move the starting tiles of the regions with the civilizations.
+        for (&old_starting_tile, &civilization) in &starting_tile_and_civilization {
+            let Some(new_starting_tile) = self
+                .starting_tile_and_civilization
+                .iter()
+                .find_map(|(&tile, &nation)| (nation == civilization).then_some(tile))
+            else {
+                continue;
+            };
+            if new_starting_tile == old_starting_tile {
+                continue;
+            }
+            if let Some(region) = self
+                .region_list
+                .iter_mut()
+                .find(|region| region.starting_tile.get() == Some(&old_starting_tile))
+            {
+                region.starting_tile = OnceLock::from(new_starting_tile);
+            }
+        }
+
+        let starting_tile_and_city_state = std::mem::take(&mut self.starting_tile_and_city_state);
+        self.starting_tile_and_city_state = self.symmetric_starting_tiles(
+            starting_tile_and_city_state,
+            symmetry,
+            true,
+            &mut used_tiles,
+            &mut kept_starting_tiles,
+        );
+
+        // Whether the tile should be the source of its mirror pair.
+        let mut is_source = vec![false; self.resource_list.len()];
+
+        let natural_wonder_tiles: Vec<_> = self
+            .all_tiles()
+            .filter(|tile| tile.natural_wonder(self).is_some())
+            .collect();
+
+        kept_starting_tiles
+            .iter()
+            .flat_map(|tile| tile.tiles_in_distance(START_SURROUNDING_RADIUS, grid))
+            .chain(
+                natural_wonder_tiles.iter().flat_map(|tile| {
+                    tile.tiles_in_distance(NATURAL_WONDER_SURROUNDING_RADIUS, grid)
+                }),
+            )
+            .for_each(|tile| is_source[tile.index()] = true);
+
+        for tile in self.all_tiles() {
+            let Some(mirror_tile) = self.symmetric_tile(tile, symmetry) else {
+                continue;
+            };
+
+            if tile.index() >= mirror_tile.index() {
+                continue;
+            }
+
+            if tile.natural_wonder(self).is_some() || mirror_tile.natural_wonder(self).is_some() {
+                tile.clear_resource(self);
+                mirror_tile.clear_resource(self);
+                continue;
+            }
+
+            if is_source[mirror_tile.index()] && !is_source[tile.index()] {
+                self.copy_tile_content(mirror_tile, tile);
+            } else {
+                self.copy_tile_content(tile, mirror_tile);
+            }
+        }
+    }
+
+    /// Returns the starting tiles made symmetric from `starting_tile_and_nation`,
+    /// see [`TileMap::apply_symmetry_to_placements`].
+    ///
+    /// - `is_city_state`: Whether the nations are city states. The city states which can't be mirrored are removed,
+    ///   but every civilization must have a starting tile, so the civilizations may start close to other nations if necessary.
+    /// - `used_tiles`: The kept starting tiles and their mirror images. The tiles used here are added to it.
+    /// - `kept_starting_tiles`: The kept starting tiles are added to it.
+    fn symmetric_starting_tiles(
+        &self,
+        starting_tile_and_nation: BTreeMap<Tile, Nation>,
+        symmetry: Symmetry,
+        is_city_state: bool,
+        used_tiles: &mut Vec<Tile>,
+        kept_starting_tiles: &mut Vec<Tile>,
+    ) -> BTreeMap<Tile, Nation> {
+        // A nation doesn't start in the influence range of a city state, which is 4 in the original CIV5.
+        const MIN_STARTING_TILE_DISTANCE: u32 = 5;
+
+        let num_nations = starting_tile_and_nation.len();
+
+        // The mirror image is `None` if the starting tile is its own mirror image,
+        // or the mirror image is outside the map or has a natural wonder.
+        let mut starting_tile_list: Vec<_> = starting_tile_and_nation
+            .into_iter()
+            .map(|(tile, nation)| {
+                let mirror_tile = self.symmetric_tile(tile, symmetry).filter(|&mirror_tile| {
+                    mirror_tile != tile && mirror_tile.natural_wonder(self).is_none()
+                });
+                (tile, mirror_tile, nation)
+            })
+            .collect();
+
+        // The sort is stable, so the starting tiles with the same distance keep their order.
+        starting_tile_list.sort_by_key(|&(tile, mirror_tile, _)| {
+            Reverse(mirror_tile.map_or(0, |mirror_tile| tile.distance_to(mirror_tile, self)))
+        });
+
+        let min_distance_list: &[u32] = if is_city_state {
+            &[MIN_STARTING_TILE_DISTANCE]
+        } else {
+            &[MIN_STARTING_TILE_DISTANCE, 1]
+        };
+
+        let mut kept_starting_tile_and_nation = BTreeMap::new();
+        // The kept starting tile and its mirror image, which is waiting for a nation.
+        let mut mirror_pairs = Vec::new();
+
+        for &min_distance in min_distance_list {
+            starting_tile_list.retain(|&(tile, mirror_tile, nation)| {
+                if kept_starting_tile_and_nation.len() + mirror_pairs.len() >= num_nations {
+                    return true;
+                }
+
+                let is_far_enough = |tile: Tile| {
+                    used_tiles
+                        .iter()
+                        .all(|&used_tile| tile.distance_to(used_tile, self) >= min_distance)
+                };
+
+                if !is_far_enough(tile)
+                    || mirror_tile.is_some_and(|mirror_tile| {
+                        tile.distance_to(mirror_tile, self) < min_distance
+                            || !is_far_enough(mirror_tile)
+                    })
+                {
+                    return true;
+                }
+
+                kept_starting_tile_and_nation.insert(tile, nation);
+                used_tiles.push(tile);
+                if let Some(mirror_tile) = mirror_tile {
+                    used_tiles.push(mirror_tile);
+                    mirror_pairs.push((tile, mirror_tile));
+                }
+                false
+            });
+        }
+
+        #[cfg(debug_assertions)]
+        for &tile in kept_starting_tile_and_nation.keys() {
+            if !mirror_pairs.iter().any(|&(kept_tile, _)| kept_tile == tile) {
+                eprintln!(
+                    "The starting tile {:?} has no valid mirror image, so it is kept without one.",
+                    tile.to_offset_coordinate(self)
+                );
+            }
+        }
+
+        let unassigned_nations: Vec<_> = starting_tile_list
+            .into_iter()
+            .map(|(_, _, nation)| nation)
+            .collect();
+
+        let num_assigned_mirror_pairs = unassigned_nations.len().min(mirror_pairs.len());
+
+        if is_city_state {
+            for &(tile, _) in &mirror_pairs[num_assigned_mirror_pairs..] {
+                kept_starting_tile_and_nation.remove(&tile);
+            }
+        }
+
+        kept_starting_tiles.extend(kept_starting_tile_and_nation.keys().copied());
+
+        kept_starting_tile_and_nation.extend(
+            mirror_pairs
+                .into_iter()
+                .map(|(_, mirror_tile)| mirror_tile)
+                .zip(unassigned_nations),
+        );
+
+        kept_starting_tile_and_nation
+    }
+
+    /// Copies the terrain type, elevation, base terrain, feature and resource of `source` to `target`.
+    fn copy_tile_content(&mut self, source: Tile, target: Tile) {
+        target.set_terrain_type(self, source.terrain_type(self));
+        target.set_elevation(self, source.elevation(self));
+        target.set_base_terrain(self, source.base_terrain(self));
+        match source.feature(self) {
+            Some(feature) => target.set_feature(self, feature),
+            None => target.clear_feature(self),
+        }
+        match source.resource(self) {
+            Some((resource, quantity)) => target.set_resource(self, resource, quantity),
+            None => target.clear_resource(self),
+        }
+    }
+
+    /// Returns the mirror image of `tile` according to `symmetry`, or `None` if the mirror image is outside the map.
+    ///
+    /// - [`Symmetry::Horizontal`] mirrors the tile about the vertical centerline of the map.
+    /// - [`Symmetry::Rotational`] rotates the tile by 180 degrees about the center of the map.
+    /// - [`Symmetry::None`] returns the tile itself.
+    ///
+    /// The mirror image of the mirror image is the tile itself.
+    pub fn symmetric_tile(&self, tile: Tile, symmetry: Symmetry) -> Option<Tile> {
+        let grid = self.world_grid.grid;
+        let width = grid.width() as i32;
+        let height = grid.height() as i32;
+
+        let hex_grid = match grid {
+            MapGrid::Hex(hex_grid) => hex_grid,
+            MapGrid::Square(_) => {
+                let [x, y] = tile.to_offset(grid).to_array();
+                let mirror_offset_coordinate = match symmetry {
+                    Symmetry::None => return Some(tile),
+                    Symmetry::Horizontal => OffsetCoordinate::new(width - 1 - x, y),
+                    Symmetry::Rotational => OffsetCoordinate::new(width - 1 - x, height - 1 - y),
+                };
+                return grid
+                    .grid_coordinate_to_cell(mirror_offset_coordinate)
+                    .map(Tile::from_cell);
+            }
+        };
+
+        let hex = tile.to_hex(hex_grid);
+
+        let mirror_hex = match symmetry {
+            Symmetry::None => return Some(tile),
+            // Reflect the hex about the vertical line through the origin, then translate it back onto the map.
+            Symmetry::Horizontal => match hex_grid.layout.orientation {
+                HexOrientation::Pointy => Hex::new(width - 1 - hex.x() - hex.y(), hex.y()),
+                HexOrientation::Flat => {
+                    // The translation must be an even number of columns,
+                    // otherwise the tiles would be moved by half a tile vertically.
+                    let columns = width - 1 - (width - 1) % 2;
+                    Hex::new(columns - hex.x(), hex.x() + hex.y() - columns / 2)
+                }
+            },
+            // Rotate the hex about the origin, then translate it so the first tile becomes the last tile.
+            Symmetry::Rotational => {
+                let last_hex = Hex::from_offset(
+                    OffsetCoordinate::new(width - 1, height - 1),
+                    hex_grid.layout.orientation,
+                    hex_grid.offset,
+                );
+                last_hex - hex
+            }
+        };
+
+        hex_grid
+            .grid_coordinate_to_cell(mirror_hex)
+            .map(Tile::from_cell)
+    }
+
+    /// Returns the mirror image of `river_edge` according to `symmetry`,
+    /// and how the edge compares with its mirror image, which decides the side of the map the edge is on.
+    ///
+    /// Returns `None` if the edge or its mirror image is not between two tiles on the map.
+    fn symmetric_river_edge(
+        &self,
+        river_edge: &RiverEdge,
+        symmetry: Symmetry,
+    ) -> Option<(Ordering, RiverEdge)> {
+        let grid = self.world_grid.grid;
+
+        let edge_direction = river_edge.edge_direction(grid);
+        let neighbor_tile = river_edge.tile.neighbor_tile(edge_direction, grid)?;
+
+        let mirror_tile = self.symmetric_tile(river_edge.tile, symmetry)?;
+        let mirror_neighbor_tile = self.symmetric_tile(neighbor_tile, symmetry)?;
+
+        // A river edge is stored in the tile on its west/north side, see `Tile::has_river_in_direction`.
+        let mirror_edge_direction = symmetry.mirror_direction(edge_direction);
+        let mirror_edge_tile =
+            if grid.edge_index(mirror_edge_direction) < grid.edge_direction_array().len() / 2 {
+                mirror_tile
+            } else {
+                mirror_neighbor_tile
+            };
+
+        let mirrored_edge = RiverEdge::new(
+            mirror_edge_tile,
+            symmetry.mirror_direction(river_edge.flow_direction),
+        );
+
+        let edge_key = sorted_pair(river_edge.tile, neighbor_tile);
+        let mirror_edge_key = sorted_pair(mirror_tile, mirror_neighbor_tile);
+
+        Some((edge_key.cmp(&mirror_edge_key), mirrored_edge))
+    }
+}
+
+/// Moves `river` into `river_list` if it is not empty.
+fn flush_river(river: &mut River, river_list: &mut Vec<River>) {
+    if !river.is_empty() {
+        river_list.push(std::mem::take(river));
+    }
+}
+
+fn sorted_pair(a: Tile, b: Tile) -> (Tile, Tile) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map, map_parameters::Symmetry, ruleset::enums::Nation,
+        test_utils::test_map_parameters, tile_map::TileMap,
+    };
+
+    /// Tests that a map with [`Symmetry::Horizontal`] has terrain and rivers symmetric about the vertical centerline
+    /// at the end of the terrain generation.
+    ///
+    /// The starting tiles and resources are placed afterwards and may change the terrain, so the map is checked in the hook.
+    #[test]
+    fn test_horizontal_symmetry() {
+        use crate::{
+            grid::Grid,
+            map_generator::{GenerationObserver, Generator, fractal::Fractal},
+        };
+
+        struct SymmetryChecker(bool);
+
+        impl GenerationObserver for SymmetryChecker {
+            fn after_features(&mut self, tile_map: &TileMap) {
+                let grid = tile_map.world_grid.grid;
+                assert!(!tile_map.river_list.is_empty());
+
+                for tile in tile_map.all_tiles() {
+                    let mirror_tile = tile_map.symmetric_tile(tile, Symmetry::Horizontal).unwrap();
+                    let [x, y] = tile.to_offset(grid).to_array();
+                    let [mirror_x, mirror_y] = mirror_tile.to_offset(grid).to_array();
+
+                    assert_eq!(y, mirror_y);
+                    assert_eq!(
+                        tile_map.symmetric_tile(mirror_tile, Symmetry::Horizontal),
+                        Some(tile)
+                    );
+                    assert_eq!(
+                        tile.terrain_type(tile_map),
+                        mirror_tile.terrain_type(tile_map),
+                        "({x}, {y}) and ({mirror_x}, {mirror_y}) have different terrain types"
+                    );
+                    assert_eq!(
+                        tile.base_terrain(tile_map),
+                        mirror_tile.base_terrain(tile_map)
+                    );
+                    assert_eq!(tile.feature(tile_map), mirror_tile.feature(tile_map));
+
+                    for direction in grid.edge_direction_array() {
+                        assert_eq!(
+                            tile.has_river_in_direction(direction, tile_map),
+                            mirror_tile.has_river_in_direction(
+                                Symmetry::Horizontal.mirror_direction(direction),
+                                tile_map
+                            ),
+                        );
+                    }
+                }
+
+                self.0 = true;
+            }
+        }
+
+        let map_parameters = test_map_parameters().symmetry(Symmetry::Horizontal).build();
+
+        let mut checker = SymmetryChecker(false);
+        Fractal::generate_with_hooks(&map_parameters, &mut checker);
+        assert!(checker.0);
+    }
+
+    /// Tests that the starting tiles of civilizations and city states, and the resources are symmetric
+    /// with [`Symmetry::Horizontal`] and [`Symmetry::Rotational`] when the number of civilizations is even,
+    /// and the starting tiles of the regions are the starting tiles of the civilizations.
+    #[test]
+    fn test_symmetric_starting_tiles_and_resources() {
+        for symmetry in [Symmetry::Horizontal, Symmetry::Rotational] {
+            let map_parameters = test_map_parameters()
+                .symmetry(symmetry)
+                .civilization_list(vec![
+                    Nation::England,
+                    Nation::America,
+                    Nation::Germany,
+                    Nation::China,
+                ])
+                .build();
+            let map = generate_map(&map_parameters);
+
+            assert_eq!(map.starting_tile_and_civilization.len(), 4);
+            assert!(!map.starting_tile_and_city_state.is_empty());
+
+            // The starting tiles of the regions are moved together with the civilizations.
+            let mut region_starting_tiles: Vec<_> = map
+                .region_list()
+                .iter()
+                .map(|region| *region.starting_tile.get().unwrap())
+                .collect();
+            region_starting_tiles.sort();
+            assert!(
+                region_starting_tiles
+                    .iter()
+                    .eq(map.starting_tile_and_civilization.keys())
+            );
+
+            for starting_tiles in [
+                &map.starting_tile_and_civilization,
+                &map.starting_tile_and_city_state,
+            ] {
+                for &tile in starting_tiles.keys() {
+                    let mirror_tile = map.symmetric_tile(tile, symmetry).unwrap();
+                    assert_ne!(mirror_tile, tile);
+                    assert!(
+                        starting_tiles.contains_key(&mirror_tile),
+                        "The mirror image of the starting tile {tile:?} is not a starting tile with {symmetry:?}"
+                    );
+                }
+            }
+
+            for tile in map.all_tiles() {
+                let Some(mirror_tile) = map.symmetric_tile(tile, symmetry) else {
+                    continue;
+                };
+                assert_eq!(
+                    map.resource_list[tile.index()],
+                    map.resource_list[mirror_tile.index()],
+                    "{tile:?} and {mirror_tile:?} have different resources with {symmetry:?}"
+                );
+            }
+        }
+    }
+}
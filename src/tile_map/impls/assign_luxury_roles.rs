@@ -1,6 +1,6 @@
 use crate::{
     grid::WorldSizeType,
-    map_parameters::MapParameters,
+    map_parameters::{LuxuryVariety, MapParameters},
     ruleset::{RegionType, enums::*},
     tile_map::TileMap,
 };
@@ -114,8 +114,10 @@ impl TileMap {
         let luxury_assigned_to_special_case = vec![Resource::Marble];
 
         // Assign appropriate amount to be Disabled, then assign the rest to be Random.
-        let num_disabled_luxury_type =
-            get_disabled_luxuries_target_number(map_parameters.world_grid.world_size_type);
+        let num_disabled_luxury_type = get_disabled_luxuries_target_number(
+            map_parameters.world_grid.world_size_type,
+            map_parameters.luxury_variety,
+        );
 
         // Get the list of resources that are not assigned to regions or city states.
         let mut remaining_resource_list = luxury_city_state_weights
@@ -550,13 +552,45 @@ pub struct LuxuryResourceRole {
 }
 
 /// Determines the target number of disabled luxury resources which can not be placed on the map.
-fn get_disabled_luxuries_target_number(world_size_type: WorldSizeType) -> u32 {
-    match world_size_type {
+///
+/// The number depends on the world size, and then it is adjusted by `luxury_variety`, see [`LuxuryVariety`].
+fn get_disabled_luxuries_target_number(
+    world_size_type: WorldSizeType,
+    luxury_variety: LuxuryVariety,
+) -> u32 {
+    let target_number = match world_size_type {
         WorldSizeType::Duel => 11,
         WorldSizeType::Tiny => 8,
         WorldSizeType::Small => 6,
         WorldSizeType::Standard => 4,
         WorldSizeType::Large => 2,
         WorldSizeType::Huge => 1,
+    };
+
+    match luxury_variety {
+        LuxuryVariety::Low => target_number + 2,
+        LuxuryVariety::Normal => target_number,
+        LuxuryVariety::High => target_number.saturating_sub(2),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_map, test_utils::test_map_parameters};
+
+    /// Tests that a low luxury variety disables more luxury types than a high luxury variety.
+    #[test]
+    fn test_luxury_variety() {
+        use crate::map_parameters::LuxuryVariety;
+
+        let disabled_luxury_count = |luxury_variety: LuxuryVariety| {
+            let map_parameters = test_map_parameters().luxury_variety(luxury_variety).build();
+            let map = generate_map(&map_parameters);
+            map.luxury_resource_role().disabled.len()
+        };
+
+        assert!(
+            disabled_luxury_count(LuxuryVariety::Low) > disabled_luxury_count(LuxuryVariety::High)
+        );
     }
 }
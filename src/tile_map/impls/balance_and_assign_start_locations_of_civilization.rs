@@ -9,7 +9,7 @@ use rand::{
     Rng, RngExt,
     seq::{IndexedRandom, SliceRandom},
 };
-use std::{cmp::max, collections::BTreeSet};
+use std::{cmp::max, collections::BTreeSet, sync::OnceLock};
 
 impl TileMap {
     // function AssignStartingPlots:BalanceAndAssign
@@ -25,15 +25,13 @@ impl TileMap {
     ) {
         let ruleset = &map_parameters.ruleset;
         // Get the starting civilization in the map.
-        let mut start_civilization_list: Vec<_> = map_parameters.civilization_list.clone();
+        let start_civilization_list: Vec<_> = map_parameters.civilization_list.clone();
 
-        for region_index in 0..self.region_list.len() {
-            self.normalize_start_tile_of_civilization(map_parameters, region_index);
-        }
+        self.normalize_start_locations_of_civilization(map_parameters);
 
         // If disbable_start_bias is true, then the starting tile will be chosen randomly.
         if map_parameters.disable_start_bias_of_civ {
-            start_civilization_list.shuffle(&mut self.random_number_generator);
+            let start_civilization_list = self.shuffled(&start_civilization_list);
             self.starting_tile_and_civilization = start_civilization_list
                 .iter()
                 .zip(self.region_list.iter())
@@ -58,7 +56,15 @@ impl TileMap {
         // If the region index has been assigned a civilization, then it will be removed from the list.
         let mut region_index_list = (0..self.region_list.len()).collect::<BTreeSet<_>>();
 
-        for &civilization in start_civilization_list.iter() {
+        // The start biases are honored according to `start_bias_strength`, see `MapParameters::start_bias_strength`.
+        // When the start biases are not honored, every civilization is treated as having no start bias,
+        // so it will be assigned to one of the remaining regions at the end.
+        // Otherwise, when the strict start bias of a civilization can't be honored,
+        // its fallback is tried with the probability `start_bias_strength`.
+        let start_bias_strength = map_parameters.start_bias_strength;
+        let honor_start_bias = start_bias_strength > 0.0;
+
+        for &civilization in start_civilization_list.iter().filter(|_| honor_start_bias) {
             let nation_info = &ruleset.nations[civilization];
             let Some(start_bias) = &nation_info.start_bias else {
                 continue;
@@ -109,37 +115,20 @@ impl TileMap {
                     regions_with_lake_start.shuffle(&mut self.random_number_generator);
                 }
 
-                // If `civs_needing_coastal_start.len() > regions_with_coastal_start.len() + regions_with_lake_start.len()`,
-                // that means there are not enough coastal and lake starting tiles,
-                // so `num_coastal_civs_remaining = civs_needing_coastal_start.len() - (regions_with_coastal_start.len() + regions_with_lake_start.len())`,
-                // if there are enough coastal and lake starting tiles, `num_coastal_civs_remaining = 0`.
-                num_coastal_civs_remaining = civs_needing_coastal_start.len().saturating_sub(
-                    regions_with_coastal_start.len() + regions_with_lake_start.len(),
-                );
-
-                let num_assigned_civs =
-                    civs_needing_coastal_start.len() - num_coastal_civs_remaining;
-
                 // Assign starting tile to civilizations with coastal bias or lake bias,
                 // and remove the assigned civilizations from `civs_needing_coastal_start`.
                 // When civilization should be along ocean, we assign starting tile to civilizations following these rules:
                 //   1. At first, we assign starting tile to civilizations with coastal bias.
                 //   2. If there are not enough coastal starting tiles, we assign starting tile to civilizations with lake bias.
-                civs_needing_coastal_start
-                    .drain(..num_assigned_civs)
-                    .zip(
-                        regions_with_coastal_start
-                            .iter()
-                            .chain(regions_with_lake_start.iter()),
-                    )
-                    .for_each(|(civilization, &region_index)| {
-                        let starting_tile =
-                            *self.region_list[region_index].starting_tile.get().unwrap();
-                        self.starting_tile_and_civilization
-                            .insert(starting_tile, civilization);
-                        // Remove region index that has been assigned from region index list
-                        region_index_list.remove(&region_index);
-                    });
+                self.assign_regions_with_fallback(
+                    &mut civs_needing_coastal_start,
+                    regions_with_coastal_start,
+                    regions_with_lake_start,
+                    start_bias_strength,
+                    &mut region_index_list,
+                );
+
+                num_coastal_civs_remaining = civs_needing_coastal_start.len();
             }
         }
 
@@ -161,7 +150,10 @@ impl TileMap {
                 }
             }
 
-            if regions_with_river_start.len() + regions_with_near_river_start.len() > 0 {
+            let num_river_regions =
+                regions_with_river_start.len() + regions_with_near_river_start.len();
+
+            if num_river_regions > 0 {
                 civs_needing_river_start.shuffle(&mut self.random_number_generator);
 
                 if !regions_with_river_start.is_empty() {
@@ -172,42 +164,22 @@ impl TileMap {
                     regions_with_near_river_start.shuffle(&mut self.random_number_generator);
                 }
 
-                // If `civs_needing_river_start.len() > regions_with_river_start.len() + regions_with_near_river_start.len()`,
-                // that means there are not enough river and near river starting tiles,
-                // so `civs_needing_river_start.len() - (regions_with_river_start.len() + regions_with_near_river_start.len())`,
-                // if there are enough river and near river starting tiles, `num_river_civs_remaining = 0`.
-                let num_river_civs_remaining = civs_needing_river_start.len().saturating_sub(
-                    regions_with_river_start.len() + regions_with_near_river_start.len(),
-                );
-
-                let num_assigned_civs = civs_needing_river_start.len() - num_river_civs_remaining;
-
                 // Assign starting tile to civilizations with river bias or near river bias,
                 // and remove the assigned civilizations from `civs_needing_river_start`.
                 // When civilization should be along river, we assign starting tile to civilizations following these rules:
                 //   1. At first, we assign starting tile to civilizations with river bias.
                 //   2. If there are not enough river starting tiles, we assign starting tile to civilizations with near river bias.
-                civs_needing_river_start
-                    .drain(..num_assigned_civs)
-                    .zip(
-                        regions_with_river_start
-                            .iter()
-                            .chain(regions_with_near_river_start.iter()),
-                    )
-                    .for_each(|(civilization, &region_index)| {
-                        let starting_tile =
-                            *self.region_list[region_index].starting_tile.get().unwrap();
-                        self.starting_tile_and_civilization
-                            .insert(starting_tile, civilization);
-                        // Remove region index that has been assigned from region index list
-                        region_index_list.remove(&region_index);
-                    });
+                self.assign_regions_with_fallback(
+                    &mut civs_needing_river_start,
+                    regions_with_river_start,
+                    regions_with_near_river_start,
+                    start_bias_strength,
+                    &mut region_index_list,
+                );
             }
 
             // Now handle any fallbacks for unassigned coastal bias.
-            if num_coastal_civs_remaining > 0
-                && civs_needing_river_start.len()
-                    < regions_with_river_start.len() + regions_with_near_river_start.len()
+            if num_coastal_civs_remaining > 0 && civs_needing_river_start.len() < num_river_regions
             {
                 let mut fallbacks_with_river_start = Vec::new();
                 let mut fallbacks_with_near_river_start = Vec::new();
@@ -236,28 +208,18 @@ impl TileMap {
                         fallbacks_with_near_river_start.shuffle(&mut self.random_number_generator);
                     }
 
-                    num_coastal_civs_remaining = civs_needing_coastal_start.len().saturating_sub(
-                        fallbacks_with_river_start.len() + fallbacks_with_near_river_start.len(),
+                    // The coastal bias of these civilizations can't be honored,
+                    // so every river and near river region is a fallback for them.
+                    let mut fallback_regions = fallbacks_with_river_start;
+                    fallback_regions.extend(fallbacks_with_near_river_start);
+
+                    self.assign_regions_with_fallback(
+                        &mut civs_needing_coastal_start,
+                        Vec::new(),
+                        fallback_regions,
+                        start_bias_strength,
+                        &mut region_index_list,
                     );
-
-                    let num_assigned_civs =
-                        civs_needing_coastal_start.len() - num_coastal_civs_remaining;
-
-                    civs_needing_coastal_start
-                        .drain(..num_assigned_civs)
-                        .zip(
-                            fallbacks_with_river_start
-                                .iter()
-                                .chain(fallbacks_with_near_river_start.iter()),
-                        )
-                        .for_each(|(civilization, &region_index)| {
-                            let starting_tile =
-                                *self.region_list[region_index].starting_tile.get().unwrap();
-                            self.starting_tile_and_civilization
-                                .insert(starting_tile, civilization);
-                            // Remove region index that has been assigned from region index list
-                            region_index_list.remove(&region_index);
-                        });
                 }
             }
         }
@@ -402,7 +364,11 @@ impl TileMap {
                         &region_index_list,
                     );
 
-                    if let Some(region_index) = region_index {
+                    // The fallback is tried with the probability `start_bias_strength`.
+                    if let Some(region_index) = region_index.filter(|_| {
+                        self.random_number_generator
+                            .random_bool(start_bias_strength)
+                    }) {
                         let starting_tile =
                             *self.region_list[region_index].starting_tile.get().unwrap();
                         self.starting_tile_and_civilization
@@ -483,6 +449,49 @@ impl TileMap {
         // although in original CIV 5 there is a funtion but it does nothing.
     }
 
+    /// Assigns the regions to `civilizations` in order, and removes the assigned civilizations from `civilizations`.
+    ///
+    /// Each civilization is assigned to the next region of `regions` at first. When `regions` runs out,
+    /// that means its strict start bias can't be honored, it is assigned to the next region of `fallback_regions`
+    /// with the probability `start_bias_strength`, see [`MapParameters::start_bias_strength`].
+    ///
+    /// The assigned regions are removed from `region_index_list`.
+    fn assign_regions_with_fallback(
+        &mut self,
+        civilizations: &mut Vec<Nation>,
+        regions: Vec<usize>,
+        fallback_regions: Vec<usize>,
+        start_bias_strength: f64,
+        region_index_list: &mut BTreeSet<usize>,
+    ) {
+        let mut regions = regions.into_iter();
+        let mut fallback_regions = fallback_regions.into_iter().peekable();
+
+        civilizations.retain(|&civilization| {
+            // Notice: `random_bool(1.0)` doesn't consume the random number generator,
+            //         so the default strength keeps the random sequence of the original CIV5.
+            let region_index = regions.next().or_else(|| {
+                (fallback_regions.peek().is_some()
+                    && self
+                        .random_number_generator
+                        .random_bool(start_bias_strength))
+                .then(|| fallback_regions.next())
+                .flatten()
+            });
+
+            let Some(region_index) = region_index else {
+                return true;
+            };
+
+            let starting_tile = *self.region_list[region_index].starting_tile.get().unwrap();
+            self.starting_tile_and_civilization
+                .insert(starting_tile, civilization);
+            // Remove region index that has been assigned from region index list
+            region_index_list.remove(&region_index);
+            false
+        });
+    }
+
     // function AssignStartingPlots:FindFallbackForUnmatchedRegionPriority
     /// Finds fallback region index for civilizations with unmatched region priority.
     ///
@@ -617,6 +626,21 @@ impl TileMap {
         }
     }
 
+    /// Normalizes the starting tile of every region,
+    /// and places [`MapParameters::starting_bonus_resource`] on or adjacent to it.
+    ///
+    /// It is called by [`TileMap::balance_and_assign_start_locations_of_civilization`],
+    /// and by [`TileMap::regenerate_resources`] to place the compensation resources again.
+    pub(crate) fn normalize_start_locations_of_civilization(
+        &mut self,
+        map_parameters: &MapParameters,
+    ) {
+        for region_index in 0..self.region_list.len() {
+            self.normalize_start_tile_of_civilization(map_parameters, region_index);
+            self.place_starting_bonus_resource(map_parameters, region_index);
+        }
+    }
+
     // function AssignStartingPlots:NormalizeStartLocation
     /// Normalizes civilization starting tile.
     ///
@@ -628,6 +652,7 @@ impl TileMap {
     /// 4. If resource_setting is [`ResourceSetting::StrategicBalance`], call [`TileMap::add_strategic_balance_resources`] to add strategic resources to the starting tile's 1-3 radius.
     /// 5. Add bonus resource for compensation to city state location's 1-2 radius if it has not enough food.
     /// 6. Set current region's [`Region::start_location_condition`](crate::tile_map::Region::start_location_condition) field.
+    /// 7. Remove any resource from the starting tile if [`MapParameters::clear_resource_on_start`] is true.
     fn normalize_start_tile_of_civilization(
         &mut self,
         map_parameters: &MapParameters,
@@ -977,9 +1002,9 @@ impl TileMap {
             });
 
         // Set start location condition for the region, which will be used in later start location evaluation and adjustment.
-        self.region_list[region_index]
-            .start_location_condition
-            .set(StartLocationCondition {
+        // It is overwritten when the starting tile is normalized again, e.g. by `TileMap::regenerate_resources`.
+        self.region_list[region_index].start_location_condition =
+            OnceLock::from(StartLocationCondition {
                 along_ocean,
                 next_to_lake,
                 is_river,
@@ -987,11 +1012,12 @@ impl TileMap {
                 near_mountain,
                 forest_count,
                 jungle_count,
-            })
-            .unwrap();
+            });
 
         // Adjust the hammer situation, if needed.
-        let mut inner_hammer_score = (4 * inner_hill) + (2 * inner_forest) + inner_one_hammer;
+        // The original score is kept for `RegionStartScore`, `inner_hammer_score` is increased when a hill is added below.
+        let original_inner_hammer_score = (4 * inner_hill) + (2 * inner_forest) + inner_one_hammer;
+        let mut inner_hammer_score = original_inner_hammer_score;
         let outer_hammer_score = (2 * outer_hill) + outer_forest + outer_one_hammer;
         let early_hammer_score =
             (2 * inner_forest) + outer_forest + inner_one_hammer + outer_one_hammer;
@@ -1081,6 +1107,21 @@ impl TileMap {
             }
         }
 
+        // Record the start score of the region, so that the fairness of the starting tiles can be inspected after generation.
+        // Like the start location condition, it is overwritten when the starting tile is normalized again.
+        self.region_list[region_index].start_score = OnceLock::from(RegionStartScore {
+            inner_food_score,
+            outer_food_score,
+            inner_hammer_score: original_inner_hammer_score,
+            outer_hammer_score,
+            early_hammer_score,
+            inner_can_have_bonus,
+            outer_can_have_bonus,
+            inner_bad_tiles,
+            outer_bad_tiles,
+            num_food_bonus_needed,
+        });
+
         if num_food_bonus_needed > 0 {
             let _max_bonuses_possible = inner_can_have_bonus + outer_can_have_bonus;
             let mut inner_placed = 0;
@@ -1281,6 +1322,57 @@ impl TileMap {
                 }
             }
         }
+
+        if map_parameters.clear_resource_on_start {
+            starting_tile.clear_resource(self);
+        }
+    }
+
+    /// Places [`MapParameters::starting_bonus_resource`] on or adjacent to the starting tile of the region.
+    ///
+    /// The resource is placed on a random tile within radius 1 of the starting tile,
+    /// which has no resource and meets one of the required terrains of the resource.
+    /// Nothing is placed if there is already such a resource within radius 1, or if no tile meets the requirement.
+    /// If [`MapParameters::clear_resource_on_start`] is true, the starting tile itself is never chosen.
+    fn place_starting_bonus_resource(
+        &mut self,
+        map_parameters: &MapParameters,
+        region_index: usize,
+    ) {
+        let Some(resource) = &map_parameters.starting_bonus_resource else {
+            return;
+        };
+
+        let grid = self.world_grid.grid;
+        let resource = Resource::from_str(resource);
+        let required_terrain_list = &map_parameters.ruleset.resources[resource].required_terrain;
+
+        let starting_tile = *self.region_list[region_index].starting_tile.get().unwrap();
+
+        let tile_list: Vec<Tile> = starting_tile.tiles_in_distance(1, grid).collect();
+
+        if tile_list
+            .iter()
+            .any(|tile| tile.resource(self).is_some_and(|(r, _)| r == resource))
+        {
+            return;
+        }
+
+        let candidate_tile_list: Vec<Tile> = tile_list
+            .into_iter()
+            .filter(|tile| {
+                (!map_parameters.clear_resource_on_start || *tile != starting_tile)
+                    && tile.resource(self).is_none()
+                    && tile.natural_wonder(self).is_none()
+                    && required_terrain_list
+                        .iter()
+                        .any(|required_terrain| tile.meets_required_terrain(self, required_terrain))
+            })
+            .collect();
+
+        if let Some(&tile) = candidate_tile_list.choose(&mut self.random_number_generator) {
+            tile.set_resource(self, resource, 1);
+        }
     }
 
     // function AssignStartingPlots:AddStrategicBalanceResources
@@ -1382,7 +1474,10 @@ impl TileMap {
 
         // These resource amount is the maximum number of every type resource that can be placed on the tile.
         let (_uran_amt, horse_amt, oil_amt, iron_amt, _coal_amtt, _alum_amt) =
-            get_major_strategic_resource_quantity_values(map_parameters.resource_setting);
+            get_major_strategic_resource_quantity_values(
+                map_parameters.resource_setting,
+                map_parameters.world_grid.world_size_type,
+            );
 
         let mut placed_iron = false;
         let mut placed_horse = false;
@@ -1519,3 +1614,187 @@ impl TileMap {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map,
+        map_parameters::{MapParametersBuilder, WorldGrid},
+        ruleset::enums::{BaseTerrain, Nation, TerrainType},
+        test_utils::test_map_parameters,
+        tile_map::RegionStartScore,
+    };
+
+    /// Tests the start score of a starting tile surrounded by plains, and that normalizing the starting tile again overwrites it.
+    #[test]
+    fn test_region_start_score_values() {
+        let map_parameters = test_map_parameters().build();
+        let mut map = generate_map(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        // Surround the starting tile with 3 rings of plains without feature, river, lake or resource.
+        let starting_tile = *map.region_list[0].starting_tile.get().unwrap();
+        map.river_list.clear();
+        for distance in 0..=3 {
+            for tile in starting_tile.tiles_at_distance(distance, grid) {
+                tile.set_terrain_type(&mut map, TerrainType::Flatland);
+                tile.set_base_terrain(&mut map, BaseTerrain::Plain);
+                tile.clear_feature(&mut map);
+                tile.clear_natural_wonder(&mut map);
+                tile.clear_resource(&mut map);
+            }
+        }
+
+        map.normalize_start_tile_of_civilization(&map_parameters, 0);
+
+        // Every plains tile yields 2 food and 1 hammer, and no plains tile is a native 2-food tile,
+        // so 2 food bonus resources are needed.
+        assert_eq!(
+            map.region_list[0].start_score.get(),
+            Some(&RegionStartScore {
+                inner_food_score: 6,
+                outer_food_score: 12,
+                inner_hammer_score: 6,
+                outer_hammer_score: 12,
+                early_hammer_score: 18,
+                inner_can_have_bonus: 6,
+                outer_can_have_bonus: 12,
+                inner_bad_tiles: 0,
+                outer_bad_tiles: 0,
+                num_food_bonus_needed: 2,
+            })
+        );
+    }
+
+    /// Tests that the coastal start bias is honored when `start_bias_strength` is `1.0`,
+    /// and ignored when `start_bias_strength` is `0.0`.
+    #[test]
+    fn test_start_bias_strength() {
+        // England has coastal start bias, America and Germany have no start bias.
+        let civilization_list = vec![Nation::England, Nation::America, Nation::Germany];
+
+        let mut bias_ignored = false;
+
+        for seed in [0, 1] {
+            let build_map = |strength| {
+                let map_parameters = MapParametersBuilder::new(WorldGrid::default())
+                    .seed(seed)
+                    .civilization_list(civilization_list.clone())
+                    .start_bias_strength(strength)
+                    .build();
+                generate_map(&map_parameters)
+            };
+
+            // Returns whether any starting tile is coastal land,
+            // and whether the starting tile of England is coastal land.
+            let coastal_starts = |map: &crate::tile_map::TileMap| {
+                let any_coastal = map
+                    .starting_tile_and_civilization
+                    .keys()
+                    .any(|tile| tile.is_coastal_land(map));
+                let england_coastal = map
+                    .starting_tile_and_civilization
+                    .iter()
+                    .any(|(tile, &nation)| nation == Nation::England && tile.is_coastal_land(map));
+                (any_coastal, england_coastal)
+            };
+
+            // The strict bias is always tried first, so a coastal start is chosen whenever there is one.
+            let (any_coastal, england_coastal) = coastal_starts(&build_map(1.0));
+            if any_coastal {
+                assert!(england_coastal, "Coastal start bias should be honored");
+            }
+
+            let (any_coastal, england_coastal) = coastal_starts(&build_map(0.0));
+            if any_coastal && !england_coastal {
+                bias_ignored = true;
+            }
+        }
+
+        assert!(bias_ignored, "Coastal start bias should be ignored");
+    }
+
+    /// Tests that ignoring and fully honoring the start biases assign the civilizations differently on the same map.
+    #[test]
+    fn test_start_bias_strength_changes_assignment() {
+        let build_map =
+            |strength| generate_map(&test_map_parameters().start_bias_strength(strength).build());
+
+        assert_ne!(
+            build_map(0.0).starting_tile_and_civilization,
+            build_map(1.0).starting_tile_and_civilization
+        );
+    }
+
+    /// Tests that every civilization starting tile has the starting bonus resource within radius 1 when the terrain allows.
+    #[test]
+    fn test_starting_bonus_resource() {
+        use crate::ruleset::enums::{Feature, Resource};
+
+        let map_parameters = test_map_parameters()
+            .starting_bonus_resource("Wheat".to_string())
+            .build();
+        let map = generate_map(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        let mut num_starting_tiles_with_wheat = 0;
+        for starting_tile in map.starting_tile_and_civilization.keys() {
+            let has_wheat = starting_tile
+                .tiles_in_distance(1, grid)
+                .any(|tile| matches!(tile.resource(&map), Some((Resource::Wheat, _))));
+            if has_wheat {
+                num_starting_tiles_with_wheat += 1;
+                continue;
+            }
+            // Wheat can only be placed on desert with floodplain or fresh water, or on plain without feature.
+            assert!(starting_tile.tiles_in_distance(1, grid).all(|tile| {
+                tile.resource(&map).is_some()
+                    || tile.terrain_type(&map) != TerrainType::Flatland
+                    || match tile.base_terrain(&map) {
+                        BaseTerrain::Plain => tile.feature(&map).is_some(),
+                        BaseTerrain::Desert => {
+                            tile.feature(&map) != Some(Feature::Floodplain)
+                                && (tile.feature(&map).is_some() || !tile.is_freshwater(&map))
+                        }
+                        _ => true,
+                    }
+            }));
+        }
+        assert!(num_starting_tiles_with_wheat > 0);
+    }
+
+    /// Tests that there is a start score for each civilization.
+    #[test]
+    fn test_region_start_scores() {
+        let map_parameters = test_map_parameters().build();
+        let map = generate_map(&map_parameters);
+
+        let region_start_scores = map.region_start_scores();
+        assert_eq!(
+            region_start_scores.len(),
+            map_parameters.civilization_list.len()
+        );
+        assert!(
+            region_start_scores
+                .iter()
+                .all(|score| score.num_food_bonus_needed >= 0)
+        );
+    }
+
+    /// Tests that no civilization starting tile has a resource when `clear_resource_on_start` is true.
+    #[test]
+    fn test_clear_resource_on_start() {
+        for seed in 0..5 {
+            let map_parameters = MapParametersBuilder::new(WorldGrid::default())
+                .seed(seed)
+                .starting_bonus_resource("Wheat".to_string())
+                .clear_resource_on_start(true)
+                .build();
+            let map = generate_map(&map_parameters);
+
+            for starting_tile in map.starting_tile_and_civilization.keys() {
+                assert_eq!(starting_tile.resource(&map), None);
+            }
+        }
+    }
+}
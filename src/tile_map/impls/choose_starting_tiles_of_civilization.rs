@@ -1,6 +1,6 @@
 use crate::{
     grid::*,
-    map_parameters::MapParameters,
+    map_parameters::{MapParameters, StartDistribution},
     ruleset::{RegionType, enums::*},
     tile::Tile,
     tile_map::*,
@@ -12,7 +12,22 @@ use std::collections::HashMap;
 impl TileMap {
     // function AssignStartingPlots:ChooseLocations
     /// Get starting tile for each civilization according to region. Every region will have a starting tile for a civilization.
+    ///
+    /// When [`MapParameters::require_coastal_starts`] is `true`, the starting tile of every region is chosen from coastal land tiles.
+    /// If a region cannot support a coastal start, an inland starting tile is chosen instead, and a warning is logged in debug builds.
+    ///
+    /// When [`MapParameters::min_civ_distance`] is set, the candidate starting tiles too close to the already chosen starting tiles are rejected.
+    ///
+    /// [`MapParameters::start_distribution`] decides where the center-bias areas searched first are in each region.
+    ///
+    /// How the starting tile of each region was placed is recorded in [`Region::start_placement`],
+    /// see [`TileMap::last_start_placement_reasons`].
     pub fn choose_starting_tiles_of_civilization(&mut self, map_parameters: &MapParameters) {
+        // A map without civilizations has no regions, so there is no starting tile to choose.
+        if self.region_list.is_empty() {
+            return;
+        }
+
         let mut sorted_region_index_list: Vec<usize> = (0..self.region_list.len()).collect();
         // Sort the region list by average fertility
         sorted_region_index_list.sort_by(|&a, &b| {
@@ -24,33 +39,92 @@ impl TileMap {
         // When map_parameters.region_divide_method is `RegionDivideMethod::WholeMapRectangle` or `RegionDivideMethod::CustomRectangle`, all region's landmass_id is always `None`.
         let ignore_landmass_id = self.region_list[0].area_id.is_none();
 
+        let grid = self.world_grid.grid;
+
         sorted_region_index_list
             .into_iter()
             .for_each(|region_index| {
-                if ignore_landmass_id {
-                    self.find_start_without_regard_to_area_id(map_parameters, region_index);
-                } else if map_parameters.civ_require_coastal_land_start {
-                    self.find_coastal_land_start(map_parameters, region_index);
+                // Ignore the minimum distance when no tile in the region is far enough from the chosen starting tiles.
+                let min_civ_distance = map_parameters.min_civ_distance.filter(|&distance| {
+                    let feasible = self.region_list[region_index]
+                        .rectangle
+                        .all_cells(&grid)
+                        .map(Tile::from_cell)
+                        .any(|tile| {
+                            tile.can_be_civilization_starting_tile(self)
+                                && self.is_far_from_civilization_starts(tile, Some(distance))
+                        });
+
+                    #[cfg(debug_assertions)]
+                    if !feasible {
+                        eprintln!(
+                            "Region {region_index} has no starting tile at least {distance} tiles away from the other civilizations. Ignoring the minimum distance."
+                        );
+                    }
+
+                    feasible
+                });
+
+                let start_placement = if ignore_landmass_id {
+                    self.find_start_without_regard_to_area_id(
+                        region_index,
+                        map_parameters.require_coastal_starts,
+                        min_civ_distance,
+                    )
+                } else if map_parameters.require_coastal_starts {
+                    self.find_coastal_land_start(
+                        region_index,
+                        min_civ_distance,
+                        map_parameters.start_distribution,
+                    )
                 } else {
-                    self.find_start(map_parameters, region_index);
-                }
+                    self.find_start(
+                        region_index,
+                        min_civ_distance,
+                        map_parameters.start_distribution,
+                    )
+                };
+
+                self.region_list[region_index]
+                    .start_placement
+                    .set(start_placement)
+                    .unwrap();
+            })
+    }
+
+    /// Checks whether `tile` is at least `min_civ_distance` away from the starting tiles already chosen for the regions.
+    ///
+    /// It is always `true` when `min_civ_distance` is `None`. See [`MapParameters::min_civ_distance`].
+    fn is_far_from_civilization_starts(&self, tile: Tile, min_civ_distance: Option<u32>) -> bool {
+        let Some(min_civ_distance) = min_civ_distance else {
+            return true;
+        };
+
+        let grid = self.world_grid.grid;
+
+        self.region_list
+            .iter()
+            .filter_map(|region| region.starting_tile.get())
+            .all(|starting_tile| {
+                grid.distance_to(tile.to_cell(), starting_tile.to_cell()) >= min_civ_distance as i32
             })
     }
 
     // function AssignStartingPlots:FindStartWithoutRegardToAreaID
     /// Find a starting tile for a region without regard to [Region::landmass_id].
     ///
+    /// If `coastal_only` is `true`, only coastal land tiles are considered.
+    /// When there is no coastal land tile to choose, it falls back to any tile without the restriction.
+    ///
     /// # Returns
     ///
-    /// This function returns a tuple:
-    /// - first element. If a starting tile was found in the region, it is `true`, otherwise `false`.
-    /// - second element. If the region had no eligible starting tiles and a starting tile was forced to be placed,
-    ///   and then first element is `false`, and the second element is `true`. If first element is `true`, then the second element is always `false`.
+    /// Returns how the starting tile was placed, see [`StartPlacement`].
     fn find_start_without_regard_to_area_id(
         &mut self,
-        map_parameters: &MapParameters,
         region_index: usize,
-    ) -> (bool, bool) {
+        coastal_only: bool,
+        min_civ_distance: Option<u32>,
+    ) -> StartPlacement {
         let grid = self.world_grid.grid;
 
         let region = &self.region_list[region_index];
@@ -81,7 +155,10 @@ impl TileMap {
 
                 *area_id_and_fertility.entry(area_id).or_insert(0) += tile_fertility;
 
-                if tile.can_be_civilization_starting_tile(self, map_parameters) {
+                if tile.can_be_civilization_starting_tile(self)
+                    && (!coastal_only || tile.is_coastal_land(self))
+                    && self.is_far_from_civilization_starts(tile, min_civ_distance)
+                {
                     area_id_and_candidate_tiles
                         .entry(area_id)
                         .or_default()
@@ -96,7 +173,10 @@ impl TileMap {
 
         // Iterate through the area_id_and_fertility list in descending order of fertility
         for &(area_id, _) in area_id_and_fertility.iter().rev() {
-            let tile_list = &area_id_and_candidate_tiles[&area_id];
+            // The area may have no candidate tiles, e.g. when only coastal land tiles are considered.
+            let Some(tile_list) = area_id_and_candidate_tiles.get(&area_id) else {
+                continue;
+            };
             let (eletion1_tile, election2_tile, _, election2_tile_score) =
                 self.iterate_through_candidate_tile_list(tile_list, region);
 
@@ -106,7 +186,7 @@ impl TileMap {
                     .set(election1_tile)
                     .unwrap();
                 self.place_impact_and_ripples(election1_tile, Layer::Civilization, u32::MAX);
-                return (true, false);
+                return StartPlacement::Natural;
             }
 
             if let Some(election_2_tile) = election2_tile {
@@ -125,7 +205,13 @@ impl TileMap {
                 .set(max_score_tile)
                 .unwrap();
             self.place_impact_and_ripples(max_score_tile, Layer::Civilization, u32::MAX);
-            (true, false)
+            StartPlacement::Fallback
+        } else if coastal_only {
+            #[cfg(debug_assertions)]
+            eprintln!(
+                "Region {region_index} cannot support a coastal start. Falling back to an inland start."
+            );
+            self.find_start_without_regard_to_area_id(region_index, false, min_civ_distance)
         } else {
             let origin = region.rectangle.origin();
 
@@ -139,7 +225,7 @@ impl TileMap {
                 .set(tile)
                 .unwrap();
             self.place_impact_and_ripples(tile, Layer::Civilization, u32::MAX);
-            (false, true)
+            StartPlacement::Forced
         }
     }
 
@@ -151,15 +237,13 @@ impl TileMap {
     ///
     /// # Returns
     ///
-    /// This function returns a tuple:
-    /// - first element. If a starting tile was found in the region, it is `true`, otherwise `false`.
-    /// - second element. If the region had no eligible starting tiles and a starting tile was forced to be placed,
-    ///   and then first element is `false`, and the second element is `true`. If first element is `true`, then the second element is always `false`.
+    /// Returns how the starting tile was placed, see [`StartPlacement`].
     fn find_coastal_land_start(
         &mut self,
-        map_parameters: &MapParameters,
         region_index: usize,
-    ) -> (bool, bool) {
+        min_civ_distance: Option<u32>,
+        start_distribution: StartDistribution,
+    ) -> StartPlacement {
         let grid = self.world_grid.grid;
 
         let mut fallback_tile_and_score = Vec::new();
@@ -174,12 +258,12 @@ impl TileMap {
         if coastal_land_sum < 3 {
             // This region cannot support an Along Ocean start.
             // Try instead to find an inland start for it.
-            // When `success_flag` is `false`,
+            #[cfg(debug_assertions)]
+            eprintln!(
+                "Region {region_index} cannot support a coastal start. Falling back to an inland start."
+            );
             // We don't need write the code to force a starting tile to be placed, because the `find_start` function will do it for us.
-            let (success_flag, forced_placement_flag) =
-                self.find_start(map_parameters, region_index);
-
-            return (success_flag, forced_placement_flag);
+            return self.find_start(region_index, min_civ_distance, start_distribution);
         }
 
         let rectangle = self.region_list[region_index].rectangle;
@@ -188,11 +272,12 @@ impl TileMap {
         const CENTER_BIAS: f64 = 1. / 3.; // d% of radius from region center to examine first
         const MIDDLE_BIAS: f64 = 2. / 3.; // d% of radius from region center to check second
 
-        // Get the rectangle whose width and height is `CENTER_BIAS` times of the original rectangle, and it is in the center of the original rectangle.
-        let center_rectangle = rectangle.scaled_center_crop(CENTER_BIAS, &grid);
+        // Get the rectangle whose width and height is `CENTER_BIAS` times of the original rectangle.
+        // It is in the center of the original rectangle unless `start_distribution` moves it, see `start_bias_crop`.
+        let center_rectangle = self.start_bias_crop(rectangle, CENTER_BIAS, start_distribution);
 
-        // Get the rectangle whose width and height is `MIDDLE_BIAS` times of the original rectangle, and it is in the middle of the original rectangle.
-        let middle_rectangle = rectangle.scaled_center_crop(MIDDLE_BIAS, &grid);
+        // Get the rectangle whose width and height is `MIDDLE_BIAS` times of the original rectangle, it is moved in the same way.
+        let middle_rectangle = self.start_bias_crop(rectangle, MIDDLE_BIAS, start_distribution);
 
         let mut center_coastal_tiles = Vec::new();
         let mut center_tiles_on_river = Vec::new();
@@ -207,7 +292,10 @@ impl TileMap {
         let mut outer_coastal_tiles = Vec::new();
 
         for tile in rectangle.all_cells(&grid).map(Tile::from_cell) {
-            if tile.can_be_civilization_starting_tile(self, map_parameters) {
+            if tile.can_be_civilization_starting_tile(self)
+                && tile.is_coastal_land(self)
+                && self.is_far_from_civilization_starts(tile, min_civ_distance)
+            {
                 let area_id = tile.area_id(self);
                 let landmass_id = self.region_list[region_index].area_id;
                 if landmass_id == Some(area_id) {
@@ -260,7 +348,7 @@ impl TileMap {
                         .set(election1_tile)
                         .unwrap();
                     self.place_impact_and_ripples(election1_tile, Layer::Civilization, u32::MAX);
-                    return (true, false);
+                    return StartPlacement::Natural;
                 }
                 if let Some(election_2_tile) = election2_tile {
                     fallback_tile_and_score.push((election_2_tile, election2_tile_score));
@@ -303,31 +391,34 @@ impl TileMap {
                 // Because south_y >= 0, bullseye_y will always be >= 0.
                 let mut bullseye_y = rectangle.south_y() as f64 + (rectangle.height() as f64 / 2.0);
 
-                match (grid.layout.orientation, grid.offset) {
-                    (HexOrientation::Pointy, Offset::Odd) => {
-                        if bullseye_y / 2.0 != (bullseye_y / 2.0).floor() {
-                            // Y coord is odd, add .5 to X coord for hex-shift.
-                            bullseye_x += 0.5;
+                // Square grids have no hex-shift, so the coordinates are only adjusted on hex grids.
+                if let MapGrid::Hex(hex_grid) = grid {
+                    match (hex_grid.layout.orientation, hex_grid.offset) {
+                        (HexOrientation::Pointy, Offset::Odd) => {
+                            if bullseye_y / 2.0 != (bullseye_y / 2.0).floor() {
+                                // Y coord is odd, add .5 to X coord for hex-shift.
+                                bullseye_x += 0.5;
+                            }
                         }
-                    }
-                    (HexOrientation::Pointy, Offset::Even) => {
-                        if bullseye_y / 2.0 == (bullseye_y / 2.0).floor() {
-                            // Y coord is even, add .5 to X coord for hex-shift.
-                            bullseye_x += 0.5;
+                        (HexOrientation::Pointy, Offset::Even) => {
+                            if bullseye_y / 2.0 == (bullseye_y / 2.0).floor() {
+                                // Y coord is even, add .5 to X coord for hex-shift.
+                                bullseye_x += 0.5;
+                            }
                         }
-                    }
-                    (HexOrientation::Flat, Offset::Odd) => {
-                        // X coord is odd, add .5 to Y coord for hex-shift.
-                        if bullseye_x / 2.0 != (bullseye_x / 2.0).floor() {
+                        (HexOrientation::Flat, Offset::Odd) => {
                             // X coord is odd, add .5 to Y coord for hex-shift.
-                            bullseye_y += 0.5;
+                            if bullseye_x / 2.0 != (bullseye_x / 2.0).floor() {
+                                // X coord is odd, add .5 to Y coord for hex-shift.
+                                bullseye_y += 0.5;
+                            }
                         }
-                    }
-                    (HexOrientation::Flat, Offset::Even) => {
-                        // X coord is even, add .5 to Y coord for hex-shift.
-                        if bullseye_x / 2.0 == (bullseye_x / 2.0).floor() {
+                        (HexOrientation::Flat, Offset::Even) => {
                             // X coord is even, add .5 to Y coord for hex-shift.
-                            bullseye_y += 0.5;
+                            if bullseye_x / 2.0 == (bullseye_x / 2.0).floor() {
+                                // X coord is even, add .5 to Y coord for hex-shift.
+                                bullseye_y += 0.5;
+                            }
                         }
                     }
                 }
@@ -340,29 +431,31 @@ impl TileMap {
                     let mut adjusted_x = x as f64;
                     let mut adjusted_y = y as f64;
 
-                    match (grid.layout.orientation, grid.offset) {
-                        (HexOrientation::Pointy, Offset::Odd) => {
-                            if y % 2 != 0 {
-                                // Y coord is odd, add .5 to X coord for hex-shift.
-                                adjusted_x += 0.5;
+                    if let MapGrid::Hex(hex_grid) = grid {
+                        match (hex_grid.layout.orientation, hex_grid.offset) {
+                            (HexOrientation::Pointy, Offset::Odd) => {
+                                if y % 2 != 0 {
+                                    // Y coord is odd, add .5 to X coord for hex-shift.
+                                    adjusted_x += 0.5;
+                                }
                             }
-                        }
-                        (HexOrientation::Pointy, Offset::Even) => {
-                            if y % 2 == 0 {
-                                // Y coord is even, add .5 to X coord for hex-shift.
-                                adjusted_x += 0.5;
+                            (HexOrientation::Pointy, Offset::Even) => {
+                                if y % 2 == 0 {
+                                    // Y coord is even, add .5 to X coord for hex-shift.
+                                    adjusted_x += 0.5;
+                                }
                             }
-                        }
-                        (HexOrientation::Flat, Offset::Odd) => {
-                            if x % 2 != 0 {
-                                // X coord is odd, add .5 to Y coord for hex-shift.
-                                adjusted_y += 0.5;
+                            (HexOrientation::Flat, Offset::Odd) => {
+                                if x % 2 != 0 {
+                                    // X coord is odd, add .5 to Y coord for hex-shift.
+                                    adjusted_y += 0.5;
+                                }
                             }
-                        }
-                        (HexOrientation::Flat, Offset::Even) => {
-                            if x % 2 == 0 {
-                                // X coord is even, add .5 to Y coord for hex-shift.
-                                adjusted_y += 0.5;
+                            (HexOrientation::Flat, Offset::Even) => {
+                                if x % 2 == 0 {
+                                    // X coord is even, add .5 to Y coord for hex-shift.
+                                    adjusted_y += 0.5;
+                                }
                             }
                         }
                     }
@@ -397,7 +490,7 @@ impl TileMap {
                         .set(closest_tile)
                         .unwrap();
                     self.place_impact_and_ripples(closest_tile, Layer::Civilization, u32::MAX);
-                    return (true, false);
+                    return StartPlacement::Natural;
                 }
             }
 
@@ -418,28 +511,91 @@ impl TileMap {
                 .set(max_score_tile)
                 .unwrap();
             self.place_impact_and_ripples(max_score_tile, Layer::Civilization, u32::MAX);
-            (true, false)
+            StartPlacement::Fallback
         } else {
             // This region cannot support an Along Ocean start.
             // Try instead to find an inland start for it.
-            // When `success_flag` is `false`,
+            #[cfg(debug_assertions)]
+            eprintln!(
+                "Region {region_index} cannot support a coastal start. Falling back to an inland start."
+            );
             // We don't need write the code to force a starting tile to be placed, because the `find_start` function will do it for us.
-            let (success_flag, forced_placement_flag) =
-                self.find_start(map_parameters, region_index);
-            (success_flag, forced_placement_flag)
+            self.find_start(region_index, min_civ_distance, start_distribution)
         }
     }
 
+    /// Returns the crop of the region `rectangle` scaled by `scale`, in which the starting tile is searched first.
+    ///
+    /// - [`StartDistribution::Default`] centers the crop in the region.
+    /// - [`StartDistribution::Clustered`] moves the crop to the side of the region toward the map center on each axis.
+    /// - [`StartDistribution::Spread`] moves the crop to the side of the region away from the map center on each axis.
+    ///
+    /// On a wrapped axis, the map center is reached in the shorter way around the map.
+    /// On an axis where the region is centered on the map center, the crop stays centered.
+    fn start_bias_crop(
+        &self,
+        rectangle: Rectangle,
+        scale: f64,
+        start_distribution: StartDistribution,
+    ) -> Rectangle {
+        let grid = self.world_grid.grid;
+
+        // The position of the crop on the axis, when the map center is in the positive direction from the region center.
+        let toward_map_center = match start_distribution {
+            StartDistribution::Default => return rectangle.scaled_center_crop(scale, &grid),
+            StartDistribution::Clustered => 1.0,
+            StartDistribution::Spread => 0.0,
+        };
+
+        let anchor_on_axis =
+            |region_start: i32, region_length: u32, map_length: u32, wrap: bool| {
+                let map_length = map_length as f64;
+                let mut difference =
+                    map_length / 2.0 - (region_start as f64 + region_length as f64 / 2.0);
+                if wrap {
+                    difference =
+                        (difference + map_length / 2.0).rem_euclid(map_length) - map_length / 2.0;
+                }
+
+                if difference.abs() < 1.0 {
+                    0.5
+                } else if difference > 0.0 {
+                    toward_map_center
+                } else {
+                    1.0 - toward_map_center
+                }
+            };
+
+        let anchor = [
+            anchor_on_axis(
+                rectangle.west_x(),
+                rectangle.width(),
+                grid.width(),
+                grid.wrap_x(),
+            ),
+            anchor_on_axis(
+                rectangle.south_y(),
+                rectangle.height(),
+                grid.height(),
+                grid.wrap_y(),
+            ),
+        ];
+
+        rectangle.scaled_crop(scale, anchor, &grid)
+    }
+
     // function AssignStartingPlots:FindStart
     /// Find a starting tile for a region.
     ///
     /// # Returns
     ///
-    /// This function returns a tuple:
-    /// - first element. If a starting tile was found in the region, it is `true`, otherwise `false`.
-    /// - second element. If the region had no eligible starting tiles and a starting tile was forced to be placed,
-    ///   and then first element is `false`, and the second element is `true`. If first element is `true`, then the second element is always `false`.
-    fn find_start(&mut self, map_parameters: &MapParameters, region_index: usize) -> (bool, bool) {
+    /// Returns how the starting tile was placed, see [`StartPlacement`].
+    fn find_start(
+        &mut self,
+        region_index: usize,
+        min_civ_distance: Option<u32>,
+        start_distribution: StartDistribution,
+    ) -> StartPlacement {
         let grid = self.world_grid.grid;
 
         let mut fallback_tile_and_score = Vec::new();
@@ -452,11 +608,12 @@ impl TileMap {
         const CENTER_BIAS: f64 = 1. / 3.; // d% of radius from region center to examine first
         const MIDDLE_BIAS: f64 = 2. / 3.; // d% of radius from region center to check second
 
-        // Get the rectangle whose width and height is `CENTER_BIAS` times of the original rectangle, and it is in the center of the original rectangle.
-        let center_rectangle = rectangle.scaled_center_crop(CENTER_BIAS, &grid);
+        // Get the rectangle whose width and height is `CENTER_BIAS` times of the original rectangle.
+        // It is in the center of the original rectangle unless `start_distribution` moves it, see `start_bias_crop`.
+        let center_rectangle = self.start_bias_crop(rectangle, CENTER_BIAS, start_distribution);
 
-        // Get the rectangle whose width and height is `MIDDLE_BIAS` times of the original rectangle, and it is in the middle of the original rectangle.
-        let middle_rectangle = rectangle.scaled_center_crop(MIDDLE_BIAS, &grid);
+        // Get the rectangle whose width and height is `MIDDLE_BIAS` times of the original rectangle, it is moved in the same way.
+        let middle_rectangle = self.start_bias_crop(rectangle, MIDDLE_BIAS, start_distribution);
 
         let mut center_candidates = Vec::new();
         let mut center_river = Vec::new();
@@ -471,7 +628,9 @@ impl TileMap {
         let mut outer_tiles = Vec::new();
 
         for tile in region.rectangle.all_cells(&grid).map(Tile::from_cell) {
-            if tile.can_be_civilization_starting_tile(self, map_parameters) {
+            if tile.can_be_civilization_starting_tile(self)
+                && self.is_far_from_civilization_starts(tile, min_civ_distance)
+            {
                 let area_id = tile.area_id(self);
                 if region.area_id == Some(area_id) {
                     if center_rectangle.contains(tile.to_cell(), &grid) {
@@ -521,7 +680,7 @@ impl TileMap {
                         .set(election1_tile)
                         .unwrap();
                     self.place_impact_and_ripples(election1_tile, Layer::Civilization, u32::MAX);
-                    return (true, false);
+                    return StartPlacement::Natural;
                 }
                 if let Some(election_2_tile) = election2_tile {
                     fallback_tile_and_score.push((election_2_tile, election2_tile_score));
@@ -564,31 +723,34 @@ impl TileMap {
                 // Because south_y >= 0, bullseye_y will always be >= 0.
                 let mut bullseye_y = rectangle.south_y() as f64 + (rectangle.height() as f64 / 2.0);
 
-                match (grid.layout.orientation, grid.offset) {
-                    (HexOrientation::Pointy, Offset::Odd) => {
-                        if bullseye_y / 2.0 != (bullseye_y / 2.0).floor() {
-                            // Y coord is odd, add .5 to X coord for hex-shift.
-                            bullseye_x += 0.5;
+                // Square grids have no hex-shift, so the coordinates are only adjusted on hex grids.
+                if let MapGrid::Hex(hex_grid) = grid {
+                    match (hex_grid.layout.orientation, hex_grid.offset) {
+                        (HexOrientation::Pointy, Offset::Odd) => {
+                            if bullseye_y / 2.0 != (bullseye_y / 2.0).floor() {
+                                // Y coord is odd, add .5 to X coord for hex-shift.
+                                bullseye_x += 0.5;
+                            }
                         }
-                    }
-                    (HexOrientation::Pointy, Offset::Even) => {
-                        if bullseye_y / 2.0 == (bullseye_y / 2.0).floor() {
-                            // Y coord is even, add .5 to X coord for hex-shift.
-                            bullseye_x += 0.5;
+                        (HexOrientation::Pointy, Offset::Even) => {
+                            if bullseye_y / 2.0 == (bullseye_y / 2.0).floor() {
+                                // Y coord is even, add .5 to X coord for hex-shift.
+                                bullseye_x += 0.5;
+                            }
                         }
-                    }
-                    (HexOrientation::Flat, Offset::Odd) => {
-                        // X coord is odd, add .5 to Y coord for hex-shift.
-                        if bullseye_x / 2.0 != (bullseye_x / 2.0).floor() {
+                        (HexOrientation::Flat, Offset::Odd) => {
                             // X coord is odd, add .5 to Y coord for hex-shift.
-                            bullseye_y += 0.5;
+                            if bullseye_x / 2.0 != (bullseye_x / 2.0).floor() {
+                                // X coord is odd, add .5 to Y coord for hex-shift.
+                                bullseye_y += 0.5;
+                            }
                         }
-                    }
-                    (HexOrientation::Flat, Offset::Even) => {
-                        // X coord is even, add .5 to Y coord for hex-shift.
-                        if bullseye_x / 2.0 == (bullseye_x / 2.0).floor() {
+                        (HexOrientation::Flat, Offset::Even) => {
                             // X coord is even, add .5 to Y coord for hex-shift.
-                            bullseye_y += 0.5;
+                            if bullseye_x / 2.0 == (bullseye_x / 2.0).floor() {
+                                // X coord is even, add .5 to Y coord for hex-shift.
+                                bullseye_y += 0.5;
+                            }
                         }
                     }
                 }
@@ -601,29 +763,31 @@ impl TileMap {
                     let mut adjusted_x = x as f64;
                     let mut adjusted_y = y as f64;
 
-                    match (grid.layout.orientation, grid.offset) {
-                        (HexOrientation::Pointy, Offset::Odd) => {
-                            if y % 2 != 0 {
-                                // Y coord is odd, add .5 to X coord for hex-shift.
-                                adjusted_x += 0.5;
+                    if let MapGrid::Hex(hex_grid) = grid {
+                        match (hex_grid.layout.orientation, hex_grid.offset) {
+                            (HexOrientation::Pointy, Offset::Odd) => {
+                                if y % 2 != 0 {
+                                    // Y coord is odd, add .5 to X coord for hex-shift.
+                                    adjusted_x += 0.5;
+                                }
                             }
-                        }
-                        (HexOrientation::Pointy, Offset::Even) => {
-                            if y % 2 == 0 {
-                                // Y coord is even, add .5 to X coord for hex-shift.
-                                adjusted_x += 0.5;
+                            (HexOrientation::Pointy, Offset::Even) => {
+                                if y % 2 == 0 {
+                                    // Y coord is even, add .5 to X coord for hex-shift.
+                                    adjusted_x += 0.5;
+                                }
                             }
-                        }
-                        (HexOrientation::Flat, Offset::Odd) => {
-                            if x % 2 != 0 {
-                                // X coord is odd, add .5 to Y coord for hex-shift.
-                                adjusted_y += 0.5;
+                            (HexOrientation::Flat, Offset::Odd) => {
+                                if x % 2 != 0 {
+                                    // X coord is odd, add .5 to Y coord for hex-shift.
+                                    adjusted_y += 0.5;
+                                }
                             }
-                        }
-                        (HexOrientation::Flat, Offset::Even) => {
-                            if x % 2 == 0 {
-                                // X coord is even, add .5 to Y coord for hex-shift.
-                                adjusted_y += 0.5;
+                            (HexOrientation::Flat, Offset::Even) => {
+                                if x % 2 == 0 {
+                                    // X coord is even, add .5 to Y coord for hex-shift.
+                                    adjusted_y += 0.5;
+                                }
                             }
                         }
                     }
@@ -658,7 +822,7 @@ impl TileMap {
                         .set(closest_tile)
                         .unwrap();
                     self.place_impact_and_ripples(closest_tile, Layer::Civilization, u32::MAX);
-                    return (true, false);
+                    return StartPlacement::Natural;
                 }
             }
 
@@ -679,7 +843,7 @@ impl TileMap {
                 .set(max_score_tile)
                 .unwrap();
             self.place_impact_and_ripples(max_score_tile, Layer::Civilization, u32::MAX);
-            (true, false)
+            StartPlacement::Fallback
         } else {
             let origin = region.rectangle.origin();
 
@@ -693,7 +857,7 @@ impl TileMap {
                 .set(tile)
                 .unwrap();
             self.place_impact_and_ripples(tile, Layer::Civilization, u32::MAX);
-            (false, true)
+            StartPlacement::Forced
         }
     }
 
@@ -769,8 +933,10 @@ impl TileMap {
             coastal_land_score = 40;
         }
 
-        // Usually, the tile have 6 neighbors. If not, we count the missing neighbors as junk.
-        junk_total += 6 - tile.neighbor_tiles(grid).count() as i32;
+        // Usually, the tile have 6 neighbors on hex grids and 4 neighbors on square grids.
+        // If not, we count the missing neighbors as junk.
+        junk_total +=
+            grid.edge_direction_array().len() as i32 - tile.neighbor_tiles(grid).count() as i32;
 
         tile.neighbor_tiles(grid).for_each(|neighbor_tile| {
             let yield_flags = self.measure_tile_yield(neighbor_tile, region);
@@ -1066,3 +1232,211 @@ bitflags! {
         const Junk = 1 << 3;
     }
 }
+
+/// How the starting tile of a region was placed by [`TileMap::choose_starting_tiles_of_civilization`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StartPlacement {
+    /// The starting tile meets the minimum requirements of a starting tile.
+    Natural,
+    /// No tile meets the minimum requirements, the best scored candidate tile is used instead.
+    Fallback,
+    /// The region has no candidate tile at all, so the origin of the region is forced to be a grassland starting tile.
+    Forced,
+}
+
+/// How the starting tile of a region was placed, see [`TileMap::last_start_placement_reasons`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StartPlacementOutcome {
+    /// The index of the region in [`TileMap::region_list`].
+    pub region_index: usize,
+    /// How the starting tile of the region was placed.
+    pub start_placement: StartPlacement,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map,
+        map_parameters::{MapParametersBuilder, MapType, WorldGrid},
+        ruleset::enums::TerrainType,
+        test_utils::test_map_parameters,
+        tile::Tile,
+        tile_map::TileMap,
+    };
+
+    /// Tests that all civilizations start on the same landmass when [`StartRestriction::OldWorldOnly`] is set.
+    ///
+    /// [`StartRestriction::OldWorldOnly`]: crate::map_parameters::StartRestriction::OldWorldOnly
+    #[test]
+    fn test_old_world_only_starts() {
+        use crate::map_parameters::StartRestriction;
+        use std::collections::BTreeSet;
+
+        for seed in 1..4 {
+            let map_parameters = MapParametersBuilder::new(WorldGrid::default())
+                .seed(seed)
+                .start_continent(StartRestriction::OldWorldOnly)
+                .build();
+            let map = generate_map(&map_parameters);
+
+            let landmass_ids: BTreeSet<_> = map
+                .starting_tile_and_civilization
+                .keys()
+                .map(|tile| tile.landmass_id(&map))
+                .collect();
+
+            assert_eq!(
+                map.starting_tile_and_civilization.len(),
+                map_parameters.civilization_list.len()
+            );
+            assert_eq!(landmass_ids.len(), 1);
+        }
+    }
+
+    /// Tests that most civilization starting tiles are coastal land on an ocean-heavy fractal map
+    /// when [`MapParameters::require_coastal_starts`] is `true`.
+    ///
+    /// [`MapParameters::require_coastal_starts`]: crate::map_parameters::MapParameters::require_coastal_starts
+    #[test]
+    fn test_require_coastal_starts() {
+        use crate::map_parameters::SeaLevel;
+
+        let map_parameters = test_map_parameters()
+            .map_type(MapType::Fractal)
+            .sea_level(SeaLevel::High)
+            .require_coastal_starts(true)
+            .build();
+        let map = generate_map(&map_parameters);
+
+        let num_starts = map.starting_tile_and_civilization.len();
+        let num_coastal_starts = map
+            .starting_tile_and_civilization
+            .keys()
+            .filter(|tile| tile.is_coastal_land(&map))
+            .count();
+        assert!(
+            num_coastal_starts * 2 > num_starts,
+            "{num_coastal_starts} of {num_starts} starts are coastal"
+        );
+    }
+
+    /// Tests that no two civilization starting tiles are closer than [`MapParameters::min_civ_distance`](crate::map_parameters::MapParameters::min_civ_distance).
+    #[test]
+    fn test_min_civ_distance() {
+        use crate::grid::Grid;
+
+        let min_start_distance = |min_civ_distance: Option<u32>| {
+            let mut builder = test_map_parameters();
+            if let Some(distance) = min_civ_distance {
+                builder = builder.min_civ_distance(distance);
+            }
+            let map = generate_map(&builder.build());
+            let grid = map.world_grid.grid;
+
+            let starting_tiles: Vec<_> = map.starting_tile_and_civilization.keys().collect();
+            let mut min_distance = i32::MAX;
+            for (i, a) in starting_tiles.iter().enumerate() {
+                for b in &starting_tiles[i + 1..] {
+                    min_distance = min_distance.min(grid.distance_to(a.to_cell(), b.to_cell()));
+                }
+            }
+            min_distance
+        };
+
+        // The usual spacing places some starting tiles closer than 14 tiles on this map.
+        assert!(min_start_distance(None) < 14);
+        assert!(min_start_distance(Some(14)) >= 14);
+    }
+
+    /// Tests that every region records how its starting tile was placed,
+    /// and that a region whose land is all snow mountains gets a forced starting tile.
+    #[test]
+    fn test_last_start_placement_reasons() {
+        use crate::{
+            generate_common_methods, map_generator::Generator, map_parameters::MapParameters,
+            ruleset::enums::BaseTerrain, tile_map::StartPlacement,
+        };
+
+        /// A generator which turns all the land of the first region into snow mountains before choosing the starting tiles.
+        struct SnowRegion(TileMap);
+
+        impl Generator for SnowRegion {
+            generate_common_methods!();
+
+            fn choose_starting_tiles_of_civilization(&mut self, map_parameters: &MapParameters) {
+                let grid = self.0.world_grid.grid;
+                let rectangle = self.0.region_list()[0].rectangle;
+                for tile in rectangle.all_cells(&grid).map(Tile::from_cell) {
+                    if tile.is_land(&self.0) {
+                        tile.set_terrain_type(&mut self.0, TerrainType::Mountain);
+                        tile.set_base_terrain(&mut self.0, BaseTerrain::Snow);
+                        tile.clear_feature(&mut self.0);
+                    }
+                }
+                self.0.choose_starting_tiles_of_civilization(map_parameters);
+            }
+        }
+
+        let map_parameters = test_map_parameters().build();
+
+        let map = generate_map(&map_parameters);
+        let outcomes = map.last_start_placement_reasons();
+        assert_eq!(outcomes.len(), map.region_list().len());
+        assert!(
+            outcomes
+                .iter()
+                .enumerate()
+                .all(|(region_index, outcome)| outcome.region_index == region_index)
+        );
+
+        // `TileMap::assign_luxury_roles` sorts the regions after the starting tiles are chosen,
+        // so the snowed region is found by its forced starting tile, which is the origin of its rectangle.
+        let map = SnowRegion::generate(&map_parameters);
+        let grid = map.world_grid.grid;
+        let forced_outcomes: Vec<_> = map
+            .last_start_placement_reasons()
+            .into_iter()
+            .filter(|outcome| outcome.start_placement == StartPlacement::Forced)
+            .collect();
+        assert_eq!(forced_outcomes.len(), 1);
+        let region = &map.region_list()[forced_outcomes[0].region_index];
+        assert_eq!(
+            *region.starting_tile.get().unwrap(),
+            Tile::from_offset(region.rectangle.origin(), grid)
+        );
+    }
+
+    /// Tests that the starting tiles are farther apart on average with [`StartDistribution::Spread`] than with [`StartDistribution::Clustered`].
+    ///
+    /// [`StartDistribution::Spread`]: crate::map_parameters::StartDistribution::Spread
+    /// [`StartDistribution::Clustered`]: crate::map_parameters::StartDistribution::Clustered
+    #[test]
+    fn test_start_distribution() {
+        use crate::{grid::Grid, map_parameters::StartDistribution};
+
+        let mean_start_distance = |start_distribution: StartDistribution| {
+            let map_parameters = test_map_parameters()
+                .start_distribution(start_distribution)
+                .build();
+            let map = generate_map(&map_parameters);
+            let grid = map.world_grid.grid;
+
+            let starting_tiles: Vec<Tile> =
+                map.starting_tile_and_civilization.keys().copied().collect();
+            let mut total_distance = 0;
+            let mut pair_count = 0;
+            for (i, &tile) in starting_tiles.iter().enumerate() {
+                for &other_tile in &starting_tiles[i + 1..] {
+                    total_distance += grid.distance_to(tile.to_cell(), other_tile.to_cell());
+                    pair_count += 1;
+                }
+            }
+            total_distance as f64 / pair_count as f64
+        };
+
+        assert!(
+            mean_start_distance(StartDistribution::Spread)
+                > mean_start_distance(StartDistribution::Clustered)
+        );
+    }
+}
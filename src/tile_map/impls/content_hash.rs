@@ -0,0 +1,100 @@
+use crate::tile_map::{FNV_OFFSET_BASIS, TileMap, fnv1a_hash};
+
+impl TileMap {
+    /// Returns a hash of the content of the map, which is useful to check that a seed still generates the same map.
+    ///
+    /// The hash covers the size of the map, the terrain type, base terrain, feature, natural wonder and resource of every tile,
+    /// the rivers, and the starting tiles of the civilizations and the city states.
+    /// The random number generator, the layers and the other intermediate data are not included.
+    ///
+    /// The hash is stable across runs, platforms and Rust versions, because it doesn't depend on `DefaultHasher`
+    /// or the iteration order of a `HashMap`, the starting tiles are hashed in the order of their tile indices.
+    /// It changes when the map changes, so the generation can be checked against a known hash in tests.
+    pub fn content_hash(&self) -> u64 {
+        let mut hash = FNV_OFFSET_BASIS;
+        let mut write = |value: u64| hash = fnv1a_hash(hash, &value.to_le_bytes());
+
+        let size = self.world_grid.size();
+        write(size.width as u64);
+        write(size.height as u64);
+
+        // `0` means `None`, otherwise it is the discriminant plus `1`.
+        for tile in self.all_tiles() {
+            write(tile.terrain_type(self) as u64);
+            write(tile.base_terrain(self) as u64);
+            write(tile.feature(self).map_or(0, |feature| feature as u64 + 1));
+            write(
+                tile.natural_wonder(self)
+                    .map_or(0, |natural_wonder| natural_wonder as u64 + 1),
+            );
+            match tile.resource(self) {
+                Some((resource, quantity)) => {
+                    write(resource as u64 + 1);
+                    write(quantity as u64);
+                }
+                None => write(0),
+            }
+        }
+
+        write(self.river_list.len() as u64);
+        for river in &self.river_list {
+            write(river.len() as u64);
+            for river_edge in river {
+                write(river_edge.tile.index() as u64);
+                write(river_edge.flow_direction as u64);
+            }
+        }
+
+        for starting_tile_and_nation in [
+            &self.starting_tile_and_civilization,
+            &self.starting_tile_and_city_state,
+        ] {
+            write(starting_tile_and_nation.len() as u64);
+            // `BTreeMap` iterates in the order of the keys.
+            for (tile, &nation) in starting_tile_and_nation {
+                write(tile.index() as u64);
+                write(nation as u64);
+            }
+        }
+
+        hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map,
+        map_parameters::{MapParametersBuilder, WorldGrid},
+        test_utils::test_map_parameters,
+        tile::Tile,
+    };
+
+    /// Tests that [`TileMap::content_hash`] is the same for the same seed, and changes with any change of the content.
+    #[test]
+    fn test_content_hash() {
+        use crate::ruleset::enums::Feature;
+
+        let map_parameters = test_map_parameters().build();
+        let mut map = generate_map(&map_parameters);
+        let hash = map.content_hash();
+
+        assert_eq!(generate_map(&map_parameters).content_hash(), hash);
+
+        let other_map_parameters = MapParametersBuilder::new(WorldGrid::default())
+            .seed(54321)
+            .build();
+        assert_ne!(generate_map(&other_map_parameters).content_hash(), hash);
+
+        // Any change of the content changes the hash.
+        let tile = Tile::new(0);
+        let feature = tile.feature(&map);
+        tile.set_feature(&mut map, Feature::Fallout);
+        assert_ne!(map.content_hash(), hash);
+        match feature {
+            Some(feature) => tile.set_feature(&mut map, feature),
+            None => tile.clear_feature(&mut map),
+        }
+        assert_eq!(map.content_hash(), hash);
+    }
+}
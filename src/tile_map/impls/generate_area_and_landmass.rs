@@ -5,7 +5,10 @@ use crate::{
     tile_map::TileMap,
 };
 use bitflags::bitflags;
-use std::collections::{BTreeSet, VecDeque};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, HashSet, VecDeque},
+};
 
 pub const UNINITIALIZED_AREA_ID: usize = usize::MAX;
 pub const UNINITIALIZED_LANDMASS_ID: usize = usize::MAX;
@@ -14,21 +17,38 @@ impl TileMap {
     /// Recalculates Area and Landmass in the map.
     ///
     /// This function is called when the map is generated or when the [`TerrainType`] of certain tiles changes.
+    ///
+    /// The area IDs may change, so [`Region::area_id`](crate::tile_map::Region::area_id) of every region is updated to the new ID of its area.
     pub fn recalculate_areas(&mut self, map_parameters: &MapParameters) {
+        let grid = self.world_grid.grid;
+
+        // Remember a tile in the area of each region, so we can find the new area ID of the region after recalculation.
+        let region_area_tiles: Vec<Option<Tile>> = self
+            .region_list
+            .iter()
+            .map(|region| {
+                let area_id = region.area_id?;
+                region
+                    .rectangle
+                    .all_cells(&grid)
+                    .map(Tile::from_cell)
+                    .find(|tile| tile.area_id(self) == area_id)
+            })
+            .collect();
+
         self.calculate_areas(map_parameters);
         self.calculate_landmasses();
+        self.calculate_water_bodies(map_parameters);
+
+        for (region_index, tile) in region_area_tiles.into_iter().enumerate() {
+            if let Some(tile) = tile {
+                self.region_list[region_index].area_id = Some(tile.area_id(self));
+            }
+        }
     }
 
     fn calculate_areas(&mut self, map_parameters: &MapParameters) {
-        const MIN_AREA_SIZE: u32 = 7;
-
-        let grid = self.world_grid.grid;
-        let height = grid.size.height;
-        let width = grid.size.width;
-
-        let ruleset = &map_parameters.ruleset;
-
-        let size = (height * width) as usize;
+        let size = self.world_grid.size().area() as usize;
 
         // Define the area id for each tile and initialize it to `UNINITIALIZED_AREA_ID`.
         // `UNINITIALIZED_AREA_ID` means that the tile is not part of any area.
@@ -37,10 +57,110 @@ impl TileMap {
         // Each area's ID is its index in the vector.
         let mut area_list = Vec::new();
 
+        let all_tiles: Vec<Tile> = self.all_tiles().collect();
+        self.assign_areas(
+            &all_tiles,
+            &map_parameters.ruleset,
+            &mut area_id_list,
+            &mut area_list,
+        );
+
+        // Update the area ID list and area list
+        self.area_id_list = area_id_list;
+        self.area_list = area_list;
+    }
+
+    /// Recalculates the areas affected by a change of the [`TerrainType`], feature or natural wonder of `tile`.
+    ///
+    /// Only the area of `tile` and the areas of its neighbors are flooded again,
+    /// so it is much cheaper than [`TileMap::recalculate_areas`] after a local edit.
+    /// It detects the splits and merges of these areas, e.g. a land tile turned to water bisecting an area.
+    ///
+    /// The IDs of the unaffected areas are kept. The IDs freed by the affected areas are reused by the new areas,
+    /// so [`TileMap::area_list`] stays compact and the ID of each area is still its index.
+    ///
+    /// # Notes
+    ///
+    /// - This function should be called after [`TileMap::recalculate_areas`] has been called at least once.
+    /// - Landmasses, water bodies and [`Region::area_id`](crate::tile_map::Region::area_id) are not updated.
+    ///   Call [`TileMap::recalculate_areas`] if they are needed.
+    pub fn recalculate_areas_around(&mut self, tile: Tile, ruleset: &Ruleset) {
+        let grid = self.world_grid.grid;
+
+        let affected_area_ids: BTreeSet<usize> = std::iter::once(tile)
+            .chain(tile.neighbor_tiles(grid))
+            .map(|tile| self.area_id_list[tile.index()])
+            .collect();
+
+        let affected_tiles: Vec<Tile> = self
+            .all_tiles()
+            .filter(|tile| affected_area_ids.contains(&self.area_id_list[tile.index()]))
+            .collect();
+
+        let mut area_id_list = std::mem::take(&mut self.area_id_list);
+        let mut area_list = std::mem::take(&mut self.area_list);
+
+        for tile in &affected_tiles {
+            area_id_list[tile.index()] = UNINITIALIZED_AREA_ID;
+        }
+
+        // The new areas are appended to the end of `area_list`.
+        self.assign_areas(&affected_tiles, ruleset, &mut area_id_list, &mut area_list);
+
+        // Fill the IDs freed by the affected areas with the areas at the end of `area_list`.
+        // `remap[old_id]` is the new ID of the area whose ID was `old_id`.
+        let mut remap: Vec<usize> = (0..area_list.len()).collect();
+        let mut free_area_ids = affected_area_ids;
+        while let Some(&free_area_id) = free_area_ids.first() {
+            let last_area_id = area_list.len() - 1;
+            let mut area = area_list.pop().unwrap();
+            // The last area is also freed, so it is just removed.
+            if free_area_ids.remove(&last_area_id) {
+                continue;
+            }
+            area.id = free_area_id;
+            area_list[free_area_id] = area;
+            remap[last_area_id] = free_area_id;
+            free_area_ids.remove(&free_area_id);
+        }
+
+        area_id_list
+            .iter_mut()
+            .for_each(|area_id| *area_id = remap[*area_id]);
+
+        self.area_id_list = area_id_list;
+        self.area_list = area_list;
+    }
+
+    /// Groups `tiles` into areas, and appends the new areas to `area_list`.
+    ///
+    /// The area ID of every tile in `tiles` must be `UNINITIALIZED_AREA_ID` in `area_id_list`.
+    /// The flood fills never leave `tiles`, but a small area can be merged into a neighboring area which is not in `tiles`.
+    fn assign_areas(
+        &self,
+        tiles: &[Tile],
+        ruleset: &Ruleset,
+        area_id_list: &mut [usize],
+        area_list: &mut Vec<Area>,
+    ) {
+        const MIN_AREA_SIZE: u32 = 7;
+
+        let grid = self.world_grid.grid;
+
+        let mut in_scope = vec![false; area_id_list.len()];
+        for tile in tiles {
+            in_scope[tile.index()] = true;
+        }
+
         // Check if the current tile has the same impassable state and water state as the before tile.
         // And then check their common neighbors to see if they have the same impassable state and same water state as the before tile.
         // If they do, add the current tile to the area.
         let check_tile = |tile: Tile, before_tile: Tile| {
+            // Only the tiles in scope can be grouped into the new areas.
+            if !in_scope[tile.index()] {
+                return false;
+            }
+
             // Check if both tiles have the same terrain properties
             if tile.is_impassable(self, ruleset) != before_tile.is_impassable(self, ruleset)
                 || tile.is_water(self) != before_tile.is_water(self)
@@ -65,7 +185,7 @@ impl TileMap {
         };
 
         // First iterate, wide area
-        for tile in self.all_tiles() {
+        for &tile in tiles {
             // If the tile is already part of an area, skip it.
             if area_id_list[tile.index()] != UNINITIALIZED_AREA_ID {
                 continue;
@@ -100,12 +220,13 @@ impl TileMap {
         // Check if the current tile has the same impassable and water properties as the before tile. If so, add it to the area.
         let check_tile = |tile: Tile, before_tile: Tile| {
             // Check if both tiles have the same terrain properties
-            tile.is_impassable(self, ruleset) == before_tile.is_impassable(self, ruleset)
+            in_scope[tile.index()]
+                && tile.is_impassable(self, ruleset) == before_tile.is_impassable(self, ruleset)
                 && tile.is_water(self) == before_tile.is_water(self)
         };
 
         // Second iterate, all the rest, small and thin area
-        for tile in self.all_tiles() {
+        for &tile in tiles {
             // If the tile is already part of an area, skip it.
             if area_id_list[tile.index()] != UNINITIALIZED_AREA_ID {
                 continue;
@@ -176,10 +297,6 @@ impl TileMap {
                 area_id_list[tile.index()] = current_area_id;
             }
         }
-
-        // Update the area ID list and area list
-        self.area_id_list = area_id_list;
-        self.area_list = area_list;
     }
 
     fn calculate_landmasses(&mut self) {
@@ -233,6 +350,166 @@ impl TileMap {
         self.landmass_list = landmass_list;
     }
 
+    /// Classifies each water landmass as an ocean or a lake, and stores the result in [`TileMap::water_body_list`] and [`TileMap::water_body_id_list`].
+    ///
+    /// A water landmass is a lake when its size is not greater than [`MapParameters::max_lake_area_size`]
+    /// and it does not touch the map border. Otherwise it is an ocean.
+    fn calculate_water_bodies(&mut self, map_parameters: &MapParameters) {
+        // Map each water landmass to a water body, and check whether it touches the map border.
+        let mut landmass_id_to_water_body_id = vec![None; self.landmass_list.len()];
+        let mut water_body_list = Vec::new();
+        let mut touches_map_border = Vec::new();
+
+        for landmass in self
+            .landmass_list
+            .iter()
+            .filter(|landmass| landmass.landmass_type == LandmassType::Water)
+        {
+            landmass_id_to_water_body_id[landmass.id] = Some(water_body_list.len());
+            water_body_list.push(WaterBody {
+                id: water_body_list.len(),
+                kind: WaterBodyKind::Ocean,
+                size: landmass.size,
+            });
+            touches_map_border.push(false);
+        }
+
+        let water_body_id_list: Vec<Option<usize>> = self
+            .all_tiles()
+            .map(|tile| {
+                let water_body_id = landmass_id_to_water_body_id[tile.landmass_id(self)];
+                if let Some(water_body_id) = water_body_id
+                    && self.is_on_map_border(tile)
+                {
+                    touches_map_border[water_body_id] = true;
+                }
+                water_body_id
+            })
+            .collect();
+
+        for water_body in water_body_list.iter_mut() {
+            if water_body.size <= map_parameters.max_lake_area_size
+                && !touches_map_border[water_body.id]
+            {
+                water_body.kind = WaterBodyKind::Lake;
+            }
+        }
+
+        self.water_body_id_list = water_body_id_list;
+        self.water_body_list = water_body_list;
+    }
+
+    /// Returns the water body the tile belongs to, or `None` if the tile is land.
+    ///
+    /// # Notes
+    ///
+    /// Ensure [`TileMap::recalculate_areas`] has been called after the last terrain type change before calling this function.
+    pub fn water_body(&self, tile: Tile) -> Option<WaterBody> {
+        self.water_body_id_list[tile.index()]
+            .map(|water_body_id| self.water_body_list[water_body_id])
+    }
+
+    /// Returns all distinct water bodies in the map and their sizes, sorted by size in descending order.
+    ///
+    /// A water body is a [`Landmass`] whose type is [`LandmassType::Water`],
+    /// so each element of the returned list is `(landmass_id, size)`, the landmass ID can be used as the ID of the water body.
+    /// Water bodies with the same size are sorted by their ID in ascending order.
+    ///
+    /// # Notes
+    ///
+    /// Ensure [`TileMap::recalculate_areas`] has been called after the last terrain type change before calling this function.
+    pub fn ocean_bodies(&self) -> Vec<(usize, u32)> {
+        let mut ocean_bodies: Vec<_> = self
+            .landmass_list
+            .iter()
+            .filter(|landmass| landmass.landmass_type == LandmassType::Water)
+            .map(|landmass| (landmass.id, landmass.size))
+            .collect();
+
+        ocean_bodies.sort_by_key(|&(_, size)| Reverse(size));
+
+        ocean_bodies
+    }
+
+    /// Returns all tiles in the landmass with the given ID, sorted by the tile index.
+    ///
+    /// The landmass can be land or water, see [`LandmassType`].
+    ///
+    /// # Notes
+    ///
+    /// Ensure [`TileMap::recalculate_areas`] has been called after the last terrain type change before calling this function.
+    pub fn landmass_tiles(&self, landmass_id: usize) -> Vec<Tile> {
+        self.all_tiles()
+            .filter(|tile| tile.landmass_id(self) == landmass_id)
+            .collect()
+    }
+
+    /// Returns the coastline length of the landmass with the given ID.
+    ///
+    /// The coastline length is the number of land tiles in the landmass which are adjacent to at least one water tile.
+    /// The neighbors are computed by the grid, so the coastline wraps around the map edges when the map wraps.
+    /// It returns `0` if the landmass is a water body.
+    ///
+    /// # Notes
+    ///
+    /// Ensure [`TileMap::recalculate_areas`] has been called after the last terrain type change before calling this function.
+    pub fn landmass_coastline_length(&self, landmass_id: usize) -> u32 {
+        let grid = self.world_grid.grid;
+
+        self.all_tiles()
+            .filter(|tile| {
+                tile.landmass_id(self) == landmass_id
+                    && tile.is_land(self)
+                    && tile
+                        .neighbor_tiles(grid)
+                        .any(|neighbor_tile| neighbor_tile.is_water(self))
+            })
+            .count() as u32
+    }
+
+    /// Returns all tiles reachable from `start` by moving between neighboring tiles that satisfy `predicate`.
+    ///
+    /// It is useful to measure contiguous regions, such as deserts or forests.
+    /// The neighbors are computed by the grid, so the region wraps around the map edges when the map wraps,
+    /// and each tile is visited at most once.
+    /// The returned set contains `start` itself, unless `start` doesn't satisfy `predicate`, in which case it is empty.
+    pub fn flood_fill(&self, start: Tile, predicate: impl Fn(Tile) -> bool) -> HashSet<Tile> {
+        if !predicate(start) {
+            return HashSet::new();
+        }
+
+        self.flood_fill_connected_tiles(start, |tile, _| predicate(tile))
+            .into_iter()
+            .collect()
+    }
+
+    /// Groups all tiles that satisfy `predicate` into connected components, where neighboring tiles are connected.
+    ///
+    /// It is [`TileMap::flood_fill`] started from every tile which is not in a component yet,
+    /// so it is useful to find the bodies of land, lakes, etc.
+    /// The components are in the order of their first tile index, and the tiles of each component are sorted by the tile index.
+    pub(crate) fn connected_components(
+        &self,
+        predicate: impl Fn(Tile) -> bool,
+    ) -> Vec<BTreeSet<Tile>> {
+        let mut in_component = vec![false; self.terrain_type_list.len()];
+        let mut components = Vec::new();
+
+        for tile in self.all_tiles() {
+            if in_component[tile.index()] || !predicate(tile) {
+                continue;
+            }
+
+            let component = self.flood_fill_connected_tiles(tile, |tile, _| predicate(tile));
+            for component_tile in &component {
+                in_component[component_tile.index()] = true;
+            }
+            components.push(component);
+        }
+
+        components
+    }
+
     /// Performs a flood-fill algorithm to collect all connected tiles that satisfy a given condition.
     ///
     /// This function starts from `start_tile` and explores all neighboring tiles using breadth-first search (BFS).
@@ -341,6 +618,13 @@ pub struct Landmass {
     pub landmass_type: LandmassType,
 }
 
+impl Landmass {
+    /// Checks if `tile` belongs to this landmass in the given [`TileMap`].
+    pub fn contains(&self, tile: Tile, tile_map: &TileMap) -> bool {
+        tile.landmass_id(tile_map) == self.id
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// Represents the type of landmass.
 pub enum LandmassType {
@@ -349,3 +633,259 @@ pub enum LandmassType {
     /// All tiles in the landmass are [`TerrainType::Water`].
     Water,
 }
+
+/// Represents a water body in the map.
+/// A water body is a [`Landmass`] whose type is [`LandmassType::Water`], classified as an ocean or a lake.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct WaterBody {
+    /// Water body ID. The ID is equal to the index of the water body in the [`TileMap::water_body_list`].
+    pub id: usize,
+    /// The kind of the water body.
+    pub kind: WaterBodyKind,
+    /// Size of the water body in tiles.
+    pub size: u32,
+}
+
+/// Represents the kind of water body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaterBodyKind {
+    /// A large water body, or a water body touching the map border.
+    Ocean,
+    /// A small water body enclosed by land, whose size is not greater than [`MapParameters::max_lake_area_size`].
+    Lake,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ruleset::enums::TerrainType,
+        test_utils::{generate_test_map, test_map_parameters},
+        tile::Tile,
+        tile_map::TileMap,
+    };
+
+    /// Tests that the sizes of all ocean bodies sum to the total number of water tiles.
+    #[test]
+    fn test_ocean_bodies_size_sum() {
+        let map = generate_test_map();
+
+        let ocean_bodies = map.ocean_bodies();
+        assert!(ocean_bodies.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let water_tile_count = map.all_tiles().filter(|tile| tile.is_water(&map)).count();
+        let size_sum: u32 = ocean_bodies.iter().map(|&(_, size)| size).sum();
+        assert_eq!(size_sum as usize, water_tile_count);
+    }
+
+    /// Tests the coastline length of islands, including an island crossing the wrapping map edge.
+    #[test]
+    fn test_landmass_coastline_length() {
+        use crate::grid::{Grid, OffsetCoordinate};
+
+        let map_parameters = test_map_parameters().build();
+        let mut map = TileMap::new(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        // A single tile island: its only tile is on the coast.
+        let small_island = Tile::from_offset(OffsetCoordinate::new(10, 10), grid);
+        small_island.set_terrain_type(&mut map, TerrainType::Flatland);
+
+        // A big island of radius 2: only the outer ring is on the coast, the inner 7 tiles are inland.
+        let big_island_center = Tile::from_offset(OffsetCoordinate::new(30, 20), grid);
+        for tile in big_island_center.tiles_in_distance(2, grid) {
+            tile.set_terrain_type(&mut map, TerrainType::Flatland);
+        }
+
+        // An island crossing the wrapping edge of the map.
+        let width = grid.width() as i32;
+        let wrapped_island = [
+            Tile::from_offset(OffsetCoordinate::new(0, 30), grid),
+            Tile::from_offset(OffsetCoordinate::new(width - 1, 30), grid),
+        ];
+        for tile in wrapped_island {
+            tile.set_terrain_type(&mut map, TerrainType::Flatland);
+        }
+
+        map.recalculate_areas(&map_parameters);
+
+        let small_island_id = small_island.landmass_id(&map);
+        let big_island_id = big_island_center.landmass_id(&map);
+        let wrapped_island_id = wrapped_island[0].landmass_id(&map);
+
+        assert_eq!(map.landmass_coastline_length(small_island_id), 1);
+        assert_eq!(map.landmass_coastline_length(big_island_id), 12);
+        assert_eq!(wrapped_island[1].landmass_id(&map), wrapped_island_id);
+        assert_eq!(map.landmass_coastline_length(wrapped_island_id), 2);
+
+        // Water bodies have no coastline.
+        assert_eq!(map.landmass_coastline_length(map.ocean_bodies()[0].0), 0);
+
+        // The coastline can never be longer than the number of tiles of the landmass.
+        for landmass in map.landmass_list.iter() {
+            assert!(map.landmass_coastline_length(landmass.id) <= landmass.size);
+        }
+    }
+
+    /// Tests that a small enclosed sea is classified as a lake,
+    /// while a small sea touching the map border and the large sea are classified as oceans.
+    #[test]
+    fn test_water_body_kind() {
+        use crate::grid::OffsetCoordinate;
+        use crate::tile_map::WaterBodyKind;
+
+        let map_parameters = test_map_parameters().build();
+        let mut map = TileMap::new(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        // Make a continent in the middle of the map, leaving the ocean around it.
+        for y in 5..35 {
+            for x in 10..70 {
+                let tile = Tile::from_offset(OffsetCoordinate::new(x, y), grid);
+                tile.set_terrain_type(&mut map, TerrainType::Flatland);
+            }
+        }
+
+        // A small sea enclosed by the continent.
+        let enclosed_sea = Tile::from_offset(OffsetCoordinate::new(40, 20), grid);
+        for tile in enclosed_sea.tiles_in_distance(1, grid) {
+            tile.set_terrain_type(&mut map, TerrainType::Water);
+        }
+
+        // A small sea which touches the south border of the map, and it is enclosed by land.
+        for y in 0..5 {
+            for x in 10..70 {
+                let tile = Tile::from_offset(OffsetCoordinate::new(x, y), grid);
+                tile.set_terrain_type(&mut map, TerrainType::Flatland);
+            }
+        }
+        let border_sea = Tile::from_offset(OffsetCoordinate::new(40, 0), grid);
+        border_sea.set_terrain_type(&mut map, TerrainType::Water);
+
+        map.recalculate_areas(&map_parameters);
+
+        let enclosed_sea = map.water_body(enclosed_sea).unwrap();
+        assert_eq!(enclosed_sea.kind, WaterBodyKind::Lake);
+        assert_eq!(enclosed_sea.size, 7);
+
+        let border_sea = map.water_body(border_sea).unwrap();
+        assert_eq!(border_sea.kind, WaterBodyKind::Ocean);
+        assert_eq!(border_sea.size, 1);
+
+        let ocean = map.water_body(Tile::from_offset(OffsetCoordinate::new(0, 20), grid));
+        assert_eq!(ocean.unwrap().kind, WaterBodyKind::Ocean);
+
+        let land = Tile::from_offset(OffsetCoordinate::new(20, 20), grid);
+        assert_eq!(map.water_body(land), None);
+    }
+
+    /// Tests that flood-filling the land from a tile of a landmass covers the whole landmass.
+    #[test]
+    fn test_flood_fill() {
+        use crate::tile_map::LandmassType;
+
+        let map = generate_test_map();
+
+        let landmass = map
+            .landmass_list
+            .iter()
+            .filter(|landmass| landmass.landmass_type == LandmassType::Land)
+            .max_by_key(|landmass| landmass.size)
+            .unwrap();
+        let start = map
+            .all_tiles()
+            .find(|tile| tile.landmass_id(&map) == landmass.id)
+            .unwrap();
+
+        let tiles = map.flood_fill(start, |tile| tile.is_land(&map));
+        assert_eq!(tiles.len() as u32, landmass.size);
+        assert!(
+            tiles
+                .iter()
+                .all(|tile| tile.landmass_id(&map) == landmass.id)
+        );
+
+        // The start tile doesn't satisfy the predicate.
+        assert!(map.flood_fill(start, |tile| tile.is_water(&map)).is_empty());
+    }
+
+    /// Tests that [`TileMap::recalculate_areas_around`] gives the same areas as [`TileMap::recalculate_areas`]
+    /// after a land tile bisecting an area is turned to water.
+    #[test]
+    fn test_recalculate_areas_around() {
+        use crate::grid::OffsetCoordinate;
+
+        let map_parameters = test_map_parameters().build();
+        let mut map = TileMap::new(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        // A straight land strip of 21 tiles in the ocean.
+        let strip: Vec<Tile> = (10..31)
+            .map(|x| Tile::from_offset(OffsetCoordinate::new(x, 20), grid))
+            .collect();
+        for &tile in &strip {
+            tile.set_terrain_type(&mut map, TerrainType::Flatland);
+        }
+        map.recalculate_areas(&map_parameters);
+        assert!(
+            strip
+                .iter()
+                .all(|tile| tile.area_id(&map) == strip[0].area_id(&map))
+        );
+
+        // Cut the strip in the middle.
+        let middle_tile = strip[10];
+        middle_tile.set_terrain_type(&mut map, TerrainType::Water);
+        map.recalculate_areas_around(middle_tile, &map_parameters.ruleset);
+        assert_ne!(strip[0].area_id(&map), strip[20].area_id(&map));
+
+        let local_area_id_list = map.area_id_list.clone();
+        let local_area_list = map.area_list.clone();
+        assert!(
+            local_area_list
+                .iter()
+                .enumerate()
+                .all(|(index, area)| area.id == index)
+        );
+
+        map.recalculate_areas(&map_parameters);
+        assert_eq!(local_area_list.len(), map.area_list.len());
+
+        // The area IDs may differ, but the tiles must be grouped the same way.
+        for tile in map.all_tiles() {
+            let local_area = local_area_list[local_area_id_list[tile.index()]];
+            let area = map.area_list[tile.area_id(&map)];
+            assert_eq!(local_area.size, area.size);
+            assert_eq!(local_area.area_flags, area.area_flags);
+            for other_tile in tile.neighbor_tiles(grid) {
+                assert_eq!(
+                    local_area_id_list[tile.index()] == local_area_id_list[other_tile.index()],
+                    tile.area_id(&map) == other_tile.area_id(&map)
+                );
+            }
+        }
+    }
+
+    /// Tests that the tiles of all the land landmasses together are exactly the land tiles of the map.
+    #[test]
+    fn test_landmass_tiles() {
+        use crate::tile_map::LandmassType;
+
+        let map = generate_test_map();
+
+        let mut land_tile_count = 0;
+        for landmass in &map.landmass_list {
+            let landmass_tiles = map.landmass_tiles(landmass.id);
+            assert_eq!(landmass_tiles.len(), landmass.size as usize);
+            assert!(
+                landmass_tiles
+                    .iter()
+                    .all(|&tile| landmass.contains(tile, &map))
+            );
+            if landmass.landmass_type == LandmassType::Land {
+                land_tile_count += landmass_tiles.len();
+            }
+        }
+
+        assert_eq!(land_tile_count, map.land_tile_count());
+    }
+}
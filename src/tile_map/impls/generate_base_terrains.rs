@@ -1,7 +1,9 @@
 use crate::{
     fractal::{CvFractal, CvFractalBuilder, FractalFlags},
+    grid::Grid,
     map_parameters::Temperature,
     ruleset::enums::*,
+    tile::Tile,
     tile_map::{MapParameters, TileMap},
 };
 use rand::{Rng, RngExt};
@@ -51,6 +53,13 @@ impl TileMap {
             }
         }
 
+        // When there is an equatorial ocean, the edges of the band play the role of the equator,
+        // so the latitudes of the land tiles are remapped from `[band_latitude, 1.0]` to `[0.0, 1.0]`.
+        // In this way, the land still gets the full sequence of climate bands, starting from the equatorial grassland,
+        // instead of starting in the desert band when the ocean band is wide.
+        let band_latitude =
+            (map_parameters.equatorial_ocean_width as f64 / grid.height() as f64).min(1.0);
+
         let desert_top_percent = 100;
         let desert_bottom_percent = 100u32.saturating_sub(desert_percent);
         let plains_top_percent = 100;
@@ -76,9 +85,10 @@ impl TileMap {
         let [plains_top, plains_bottom] = plains_fractal
             .height_thresholds_from_percents([plains_top_percent, plains_bottom_percent]);
 
-        self.all_tiles().for_each(|tile| {
-            let terrain_type = tile.terrain_type(self);
-            match terrain_type {
+        // Compute the base terrain of each tile. It only reads the tile map and the fractals,
+        // and every tile is independent of the others, so it can be computed in parallel.
+        let base_terrain_of_tile = |tile: Tile| -> BaseTerrain {
+            match tile.terrain_type(self) {
                 TerrainType::Water => {
                     // Generate coast terrain.
                     //
@@ -86,11 +96,13 @@ impl TileMap {
                     // 1. They are ocean, that means they are water, not lake and not already coast.
                     // 2. They have at least one neighbor that is not water.
                     if tile.base_terrain(self) == BaseTerrain::Ocean
-                        && tile.neighbor_tiles(grid).any(|neighbor_tile| {
-                            neighbor_tile.terrain_type(self) != TerrainType::Water
-                        })
+                        && tile
+                            .neighbor_tiles(grid)
+                            .any(|neighbor_tile| neighbor_tile.is_land(self))
                     {
-                        tile.set_base_terrain(self, BaseTerrain::Coast);
+                        BaseTerrain::Coast
+                    } else {
+                        tile.base_terrain(self)
                     }
                 }
                 TerrainType::Flatland | TerrainType::Hill | TerrainType::Mountain => {
@@ -99,39 +111,62 @@ impl TileMap {
                     let x = x as u32;
                     let y = y as u32;
 
-                    // Set default base terrain of all land tiles to `BaseTerrain::Grassland` because the default base terrain is `BaseTerrain::Ocean` in the tile map.
-                    tile.set_base_terrain(self, BaseTerrain::Grassland);
-
                     let deserts_height = deserts_fractal.height(x, y);
                     let plains_height = plains_fractal.height(x, y);
 
                     let mut latitude = tile.latitude(grid);
+                    if band_latitude > 0.0 && band_latitude < 1.0 {
+                        latitude = ((latitude - band_latitude) / (1.0 - band_latitude)).max(0.0);
+                    }
                     latitude += (128. - variation_fractal.height(x, y) as f64) / (255.0 * 5.0);
                     latitude = latitude.clamp(0., 1.);
 
-                    if latitude >= snow_latitude {
-                        tile.set_base_terrain(self, BaseTerrain::Snow);
+                    // The default base terrain of all land tiles is `BaseTerrain::Grassland`.
+                    // When snow is not allowed, the snow band becomes a part of the tundra band.
+                    if latitude >= snow_latitude && map_parameters.allow_snow {
+                        BaseTerrain::Snow
                     } else if latitude >= tundra_latitude {
-                        tile.set_base_terrain(self, BaseTerrain::Tundra);
+                        BaseTerrain::Tundra
                     } else if latitude < grass_latitude {
-                        tile.set_base_terrain(self, BaseTerrain::Grassland);
+                        BaseTerrain::Grassland
                     } else if deserts_height >= desert_bottom
                         && deserts_height <= desert_top
                         && latitude >= desert_bottom_latitude
                         && latitude < desert_top_latitude
                     {
-                        tile.set_base_terrain(self, BaseTerrain::Desert);
+                        BaseTerrain::Desert
                     } else if plains_height >= plains_bottom && plains_height <= plains_top {
-                        tile.set_base_terrain(self, BaseTerrain::Plain);
+                        BaseTerrain::Plain
+                    } else {
+                        BaseTerrain::Grassland
                     }
                 }
             }
-        });
+        };
+
+        #[cfg(feature = "parallel")]
+        let base_terrain_list = if map_parameters.parallel {
+            use rayon::prelude::*;
+            (0..self.base_terrain_list.len())
+                .into_par_iter()
+                .map(|index| base_terrain_of_tile(Tile::new(index)))
+                .collect()
+        } else {
+            self.all_tiles().map(base_terrain_of_tile).collect()
+        };
+
+        #[cfg(not(feature = "parallel"))]
+        let base_terrain_list = self.all_tiles().map(base_terrain_of_tile).collect();
+
+        self.base_terrain_list = base_terrain_list;
     }
 
     /// Expand coast terrain.
     ///
-    /// The tiles that can be expanded should meet all the conditions as follows:
+    /// At first, the ocean tiles adjacent to coast are turned into coast ring by ring,
+    /// until the coast is [`MapParameters::coast_width`] rings wide.
+    ///
+    /// Then the coast is expanded randomly. The tiles that can be expanded should meet all the conditions as follows:
     /// 1. They are water and not already coast
     /// 2. They have at least one neighbor that is coast
     /// 3. A random number generator will be used to determine whether the tile will be expanded.
@@ -139,8 +174,27 @@ impl TileMap {
     /// # Notes
     ///
     /// This method is called after the [`TileMap::generate_base_terrains`] method.
+    /// Only [`BaseTerrain::Ocean`] tiles can become coast, so lakes are never expanded into.
     pub fn expand_coasts(&mut self, map_parameters: &MapParameters) {
         let grid = self.world_grid.grid;
+
+        // The first ring of coast is generated by `generate_base_terrains`.
+        for _ in 1..map_parameters.coast_width {
+            let expansion_tile: Vec<_> = self
+                .all_tiles()
+                .filter(|tile| {
+                    tile.base_terrain(self) == BaseTerrain::Ocean
+                        && tile.neighbor_tiles(grid).any(|neighbor_tile| {
+                            neighbor_tile.base_terrain(self) == BaseTerrain::Coast
+                        })
+                })
+                .collect();
+
+            expansion_tile.into_iter().for_each(|tile| {
+                tile.set_base_terrain(self, BaseTerrain::Coast);
+            });
+        }
+
         map_parameters
             .coast_expand_chance
             .iter()
@@ -170,3 +224,43 @@ impl TileMap {
             });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_map, ruleset::enums::BaseTerrain, test_utils::test_map_parameters};
+
+    /// Tests that a wider coast gives strictly more coast tiles on the same map.
+    #[test]
+    fn test_coast_width() {
+        let coast_tile_count = |coast_width| {
+            let map_parameters = test_map_parameters().coast_width(coast_width).build();
+            let map = generate_map(&map_parameters);
+            map.all_tiles()
+                .filter(|tile| tile.base_terrain(&map) == BaseTerrain::Coast)
+                .count()
+        };
+
+        assert!(coast_tile_count(2) > coast_tile_count(1));
+    }
+
+    /// Tests that no tile is snow when snow is not allowed, even on a cool map.
+    #[test]
+    fn test_allow_snow() {
+        use crate::map_parameters::Temperature;
+
+        let snow_count = |allow_snow: bool| {
+            let map_parameters = test_map_parameters()
+                .temperature(Temperature::Cool)
+                .allow_snow(allow_snow)
+                .build();
+            let map = generate_map(&map_parameters);
+
+            map.all_tiles()
+                .filter(|tile| tile.base_terrain(&map) == BaseTerrain::Snow)
+                .count()
+        };
+
+        assert!(snow_count(true) > 0);
+        assert_eq!(snow_count(false), 0);
+    }
+}
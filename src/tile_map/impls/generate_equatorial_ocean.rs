@@ -0,0 +1,83 @@
+use std::ops::Range;
+
+use crate::{
+    grid::{Grid, OffsetCoordinate},
+    map_parameters::MapParameters,
+    ruleset::enums::TerrainType,
+    tile::Tile,
+    tile_map::TileMap,
+};
+
+impl TileMap {
+    /// Forces an ocean band across the equator according to [`MapParameters::equatorial_ocean_width`].
+    ///
+    /// All the tiles in the rows returned by [`TileMap::equatorial_ocean_rows`] are set to [`TerrainType::Water`].
+    /// It does nothing when [`MapParameters::equatorial_ocean_width`] is `0`.
+    ///
+    /// # Notes
+    ///
+    /// This method should be called after [`TileMap::shift_terrain_types`],
+    /// otherwise the band will be moved away from the equator when the terrain types are shifted.
+    pub fn generate_equatorial_ocean(&mut self, map_parameters: &MapParameters) {
+        let grid = self.world_grid.grid;
+
+        for y in self.equatorial_ocean_rows(map_parameters) {
+            for x in 0..grid.width() {
+                let tile = Tile::from_offset(OffsetCoordinate::new(x as i32, y as i32), grid);
+                tile.set_terrain_type(self, TerrainType::Water);
+                tile.set_elevation(self, 0);
+            }
+        }
+    }
+
+    /// Returns the rows covered by the equatorial ocean band.
+    ///
+    /// The band is centered on the equator and its height is [`MapParameters::equatorial_ocean_width`],
+    /// clamped to the height of the map. The range is empty when the width is `0`.
+    pub(crate) fn equatorial_ocean_rows(&self, map_parameters: &MapParameters) -> Range<u32> {
+        let height = self.world_grid.grid.height();
+        let width = map_parameters.equatorial_ocean_width.min(height);
+
+        let start = height / 2 - width / 2;
+        start..start + width
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map,
+        map_parameters::{MapParametersBuilder, MapType, WorldGrid},
+        ruleset::enums::TerrainType,
+        test_utils::TEST_SEED,
+        tile::Tile,
+    };
+
+    /// Tests that the equatorial ocean band is all water at the configured width.
+    #[test]
+    fn test_equatorial_ocean() {
+        use crate::grid::{Grid, OffsetCoordinate};
+
+        let width = 4;
+        let world_grid = WorldGrid::default();
+        let grid = world_grid.grid;
+
+        for map_type in [MapType::Fractal, MapType::Pangaea] {
+            let map_parameters = MapParametersBuilder::new(world_grid)
+                .seed(TEST_SEED)
+                .map_type(map_type)
+                .equatorial_ocean_width(width)
+                .build();
+            let map = generate_map(&map_parameters);
+
+            let rows = map.equatorial_ocean_rows(&map_parameters);
+            assert_eq!(rows.len(), width as usize);
+            for y in rows {
+                for x in 0..grid.width() {
+                    let tile = Tile::from_offset(OffsetCoordinate::new(x as i32, y as i32), grid);
+                    assert_eq!(tile.terrain_type(&map), TerrainType::Water);
+                }
+            }
+        }
+    }
+}
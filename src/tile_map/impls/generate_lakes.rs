@@ -20,6 +20,7 @@ impl TileMap {
     /// Add lakes to the map.
     ///
     /// Besides the lakes generated by [`TileMap::generate_lakes`], this function will add more lakes to the map.
+    /// At last, the lakes larger than [`MapParameters::max_freshwater_lake_size`] become salt lakes, see [`TileMap::convert_large_lakes_to_salt_water`].
     pub fn add_lakes(&mut self, map_parameters: &MapParameters) {
         let num_large_lake = map_parameters.num_large_lakes;
         // TODO: `lake_tile_rand` should be configurable by the user in the future.
@@ -45,6 +46,24 @@ impl TileMap {
                 tile.clear_feature(self);
             }
         });
+
+        self.convert_large_lakes_to_salt_water(map_parameters);
+    }
+
+    /// Turns every connected body of [`BaseTerrain::Lake`] tiles larger than [`MapParameters::max_freshwater_lake_size`] into [`BaseTerrain::Coast`].
+    ///
+    /// Such a body is a salt lake, so it does not provide fresh water to the adjacent land tiles.
+    pub(crate) fn convert_large_lakes_to_salt_water(&mut self, map_parameters: &MapParameters) {
+        let lake_bodies =
+            self.connected_components(|tile| tile.base_terrain(self) == BaseTerrain::Lake);
+
+        for lake_tiles in lake_bodies {
+            if lake_tiles.len() as u32 > map_parameters.max_freshwater_lake_size {
+                for lake_tile in lake_tiles {
+                    lake_tile.set_base_terrain(self, BaseTerrain::Coast);
+                }
+            }
+        }
     }
 
     /// Transform the neighboring tiles of the given tile into lakes if possible.
@@ -104,17 +123,67 @@ impl TileMap {
     fn can_add_lake(&self, tile: Tile) -> bool {
         let grid = self.world_grid.grid;
         // Check if the current tile is suitable for a lake
-        if tile.terrain_type(self) == TerrainType::Water
-            || tile.natural_wonder(self).is_some()
-            || tile.has_river(self)
-        {
+        if tile.is_water(self) || tile.natural_wonder(self).is_some() || tile.has_river(self) {
             return false;
         }
 
         // Check if all neighbor tiles are also suitable
         tile.neighbor_tiles(grid).all(|neighbor_tile| {
-            neighbor_tile.terrain_type(self) != TerrainType::Water
-                && neighbor_tile.natural_wonder(self).is_none()
+            neighbor_tile.is_land(self) && neighbor_tile.natural_wonder(self).is_none()
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ruleset::enums::{BaseTerrain, TerrainType},
+        test_utils::test_map_parameters,
+        tile::Tile,
+        tile_map::TileMap,
+    };
+
+    /// Tests that the lakes larger than `max_freshwater_lake_size` become salt water, and the smaller ones stay freshwater.
+    #[test]
+    fn test_max_freshwater_lake_size() {
+        use crate::grid::OffsetCoordinate;
+
+        let map_parameters = test_map_parameters().max_freshwater_lake_size(2).build();
+        let mut map = TileMap::new(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        for tile in map.all_tiles() {
+            tile.set_terrain_type(&mut map, TerrainType::Flatland);
+            tile.set_base_terrain(&mut map, BaseTerrain::Grassland);
+        }
+
+        let tile_at = |x, y| Tile::from_offset(OffsetCoordinate::new(x, y), grid);
+
+        // A basin of 7 tiles, and a lake of 2 tiles far away from it.
+        let basin_center = tile_at(10, 10);
+        let basin_tiles: Vec<Tile> = std::iter::once(basin_center)
+            .chain(basin_center.neighbor_tiles(grid))
+            .collect();
+        let small_lake_tiles = [tile_at(30, 20), tile_at(31, 20)];
+        for &tile in basin_tiles.iter().chain(&small_lake_tiles) {
+            tile.set_terrain_type(&mut map, TerrainType::Water);
+            tile.set_base_terrain(&mut map, BaseTerrain::Lake);
+        }
+
+        map.convert_large_lakes_to_salt_water(&map_parameters);
+
+        assert!(
+            basin_tiles
+                .iter()
+                .all(|tile| tile.base_terrain(&map) == BaseTerrain::Coast)
+        );
+        assert!(!tile_at(10, 12).is_freshwater(&map));
+
+        assert!(
+            small_lake_tiles
+                .iter()
+                .all(|tile| tile.base_terrain(&map) == BaseTerrain::Lake)
+        );
+        assert!(tile_at(32, 20).is_freshwater(&map));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    grid::Grid,
+    grid::{Direction, Grid},
     map_parameters::MapParameters,
     ruleset::{enums::*, *},
     tile::Tile,
@@ -13,26 +13,13 @@ impl TileMap {
     /// Generate natural wonders on the map.
     ///
     /// This function is like to Civ6's natural wonder generation. We edit it to fit our game which is like Civ5.
+    ///
+    /// Only the natural wonders in [`MapParameters::allowed_natural_wonders`] are placed when it is set.
     pub fn place_natural_wonders(&mut self, map_parameters: &MapParameters) {
         let ruleset = &map_parameters.ruleset;
         let grid = self.world_grid.grid;
 
-        // Get the number of natural wonders to place based on the world size
-        let num_natural_wonders = map_parameters.world_size_type_profile.num_natural_wonders;
-
-        // Collect the natural wonders and their possible tile locations
-        let mut natural_wonder_and_tile_list: EnumMap<NaturalWonder, Vec<Tile>> =
-            EnumMap::default();
-
-        let mut land_area_id_and_size: Vec<_> = self
-            .area_list
-            .iter()
-            .filter(|area| area.area_flags.contains(AreaFlags::FlatlandOrHill))
-            .map(|area| (area.id, area.size))
-            .collect();
-
-        // Sort by `area_size` in descending order
-        land_area_id_and_size.sort_by_key(|&(_, area_size)| Reverse(area_size));
+        let num_natural_wonders = num_natural_wonders_to_place(map_parameters);
 
         /***** Tackle with the natural wonders which require 2 adjacent tiles *****/
         // When a natural wonder requires occupying 2 adjacent tiles,
@@ -48,133 +35,19 @@ impl TileMap {
             .choose(&mut self.random_number_generator)
             .expect("Failed to choose a random direction");
 
-        for tile in self.all_tiles() {
-            // If tile is a civilization start location, or a city state start location, or has natural wonder, then it cannot be chosen as the location for placing natural wonder.
-            if self.starting_tile_and_civilization.contains_key(&tile)
-                || self.starting_tile_and_city_state.contains_key(&tile)
-                || tile.natural_wonder(self).is_some()
-            {
-                continue;
-            }
-
-            for (natural_wonder, tile_list) in natural_wonder_and_tile_list.iter_mut() {
-                let natural_wonder_info = &ruleset.natural_wonders[natural_wonder];
-
-                let required_terrain = &natural_wonder_info.required_terrain;
-
-                if let Some(freshwater) = required_terrain.freshwater
-                    && tile.is_freshwater(self) != freshwater
-                {
-                    continue;
-                }
-
-                if !required_terrain
-                    .terrain_type
-                    .contains(&tile.terrain_type(self))
-                    || !required_terrain
-                        .base_terrain
-                        .contains(&tile.base_terrain(self))
-                {
-                    continue;
-                }
-
-                match natural_wonder {
-                    NaturalWonder::GreatBarrierReef => {
-                        if let Some(neighbor_tile) =
-                            tile.neighbor_tile(neighbor_tile_direction, grid)
-                        {
-                            let mut all_neigbor_tiles = HashSet::new();
-
-                            all_neigbor_tiles.extend(tile.neighbor_tiles(grid));
-                            all_neigbor_tiles.extend(neighbor_tile.neighbor_tiles(grid));
-
-                            // We only check neighbors of the current tile and the neighbor tile.
-                            // So we remove them from the set of all neighbor tiles.
-                            all_neigbor_tiles.remove(&tile);
-                            all_neigbor_tiles.remove(&neighbor_tile);
-
-                            // The tile should meet the following conditions:
-                            // 1. All neighboring tiles exist
-                            // 2. All neighboring tiles are water and not lake, not ice
-                            // 3. At least 4 neighboring tiles are coast
-                            if all_neigbor_tiles.len() == 8
-                                && all_neigbor_tiles.iter().all(|&tile| {
-                                    tile.terrain_type(self) == TerrainType::Water
-                                        && tile.base_terrain(self) != BaseTerrain::Lake
-                                        && tile.feature(self) != Some(Feature::Ice)
-                                })
-                                && all_neigbor_tiles
-                                    .iter()
-                                    .filter(|tile| tile.base_terrain(self) == BaseTerrain::Coast)
-                                    .count()
-                                    >= 4
-                            {
-                                tile_list.push(tile);
-                            }
-                        }
-                    }
-                    _ => {
-                        let check_extra_conditions =
-                            required_terrain.extra_conditions.iter().all(|unique| {
-                                let unique = Unique::new(unique);
-                                match unique.placeholder_text.as_str() {
-                                    "Must be adjacent to [] [] tiles" => {
-                                        let count = tile
-                                            .neighbor_tiles(grid)
-                                            .filter(|tile| {
-                                                self.matches_wonder_filter(
-                                                    *tile,
-                                                    unique.params[1].as_str(),
-                                                )
-                                            })
-                                            .count();
-                                        count == unique.params[0].parse::<usize>().unwrap()
-                                    }
-                                    "Must be adjacent to [] to [] [] tiles" => {
-                                        let count = tile
-                                            .neighbor_tiles(grid)
-                                            .filter(|tile| {
-                                                self.matches_wonder_filter(
-                                                    *tile,
-                                                    unique.params[2].as_str(),
-                                                )
-                                            })
-                                            .count();
-                                        count >= unique.params[0].parse::<usize>().unwrap()
-                                            && count <= unique.params[1].parse::<usize>().unwrap()
-                                    }
-                                    "Must not be on [] largest landmasses" => {
-                                        // index is the ranking of the current landmass among all landmasses sorted by size from highest to lowest.
-                                        let index = unique.params[0].parse::<usize>().unwrap();
-                                        // Check if the tile isn't on the landmass with the given index
-                                        land_area_id_and_size
-                                            .get(index)
-                                            .is_none_or(|&(id, _)| id != tile.area_id(self))
-                                    }
-                                    "Must be on [] largest landmasses" => {
-                                        // index is the ranking of the current landmass among all landmasses sorted by size from highest to lowest.
-                                        let index = unique.params[0].parse::<usize>().unwrap();
-                                        // Check if the tile is on the landmass with the given index
-                                        land_area_id_and_size
-                                            .get(index)
-                                            .is_some_and(|&(id, _)| id == tile.area_id(self))
-                                    }
-                                    _ => true,
-                                }
-                            });
-
-                        if check_extra_conditions {
-                            tile_list.push(tile);
-                        }
-                    }
-                }
-            }
-        }
+        let mut natural_wonder_and_tile_list =
+            self.natural_wonder_and_candidate_tiles(map_parameters, neighbor_tile_direction);
 
         // Collect the natural wonders that can be placed
         let mut selected_natural_wonder_list: Vec<_> = natural_wonder_and_tile_list
             .iter()
-            .filter(|(_, tiles)| !tiles.is_empty())
+            .filter(|(natural_wonder, tiles)| {
+                !tiles.is_empty()
+                    && map_parameters
+                        .allowed_natural_wonders
+                        .as_ref()
+                        .is_none_or(|allowed| allowed.contains(natural_wonder))
+            })
             .map(|(natural_wonder, _)| natural_wonder)
             .collect();
 
@@ -198,76 +71,19 @@ impl TileMap {
                     tile_list.shuffle(&mut self.random_number_generator);
 
                     for &tile in tile_list.iter() {
-                        if self.layer_data[Layer::NaturalWonder][tile.index()] == 0 {
-                            let natural_wonder_info = &ruleset.natural_wonders[natural_wonder];
-
-                            // At first, we should remove feature from the tile
-                            tile.clear_feature(self);
-
-                            match natural_wonder {
-                                NaturalWonder::GreatBarrierReef => {
-                                    // The neighbor tile absolutely exists because we have checked it before.
-                                    let neighbor_tile = tile
-                                        .neighbor_tile(neighbor_tile_direction, grid)
-                                        .expect("Neighbor tile does not exist");
-
-                                    // All related tiles should contain:
-                                    // 1. Current tile
-                                    // 2. Neighbor tile according to neighbor_tile_direction
-                                    // 3. All neighbor tiles of current tile and neighbor tile
-                                    let mut all_related_tiles = HashSet::new();
-
-                                    all_related_tiles.extend(tile.neighbor_tiles(grid));
-                                    all_related_tiles.extend(neighbor_tile.neighbor_tiles(grid));
-
-                                    all_related_tiles.into_iter().for_each(|tile| {
-                                        tile.set_terrain_type(self, TerrainType::Water);
-                                        tile.set_base_terrain(self, BaseTerrain::Coast);
-                                    });
-                                    // place the natural wonder on the candidate position and its adjacent tile
-                                    tile.set_natural_wonder(self, natural_wonder);
-                                    neighbor_tile.set_natural_wonder(self, natural_wonder);
-                                    // add the position of the placed natural wonder to the list of placed natural wonder positions
-                                    placed_natural_wonder_tiles.push(tile);
-                                    placed_natural_wonder_tiles.push(neighbor_tile);
-                                }
-                                NaturalWonder::RockOfGibraltar => {
-                                    tile.neighbor_tiles(grid).for_each(|neighbor_tile| {
-                                        if neighbor_tile.terrain_type(self) == TerrainType::Water {
-                                            neighbor_tile
-                                                .set_base_terrain(self, BaseTerrain::Coast);
-                                        } else {
-                                            neighbor_tile
-                                                .set_terrain_type(self, TerrainType::Mountain);
-                                        }
-                                    });
-                                    // Edit the choice tile's terrain_type to match the natural wonder
-                                    tile.set_terrain_type(self, TerrainType::Flatland);
-                                    // Edit the choice tile's base_terrain to match the natural wonder
-                                    tile.set_base_terrain(self, BaseTerrain::Grassland);
-                                    // place the natural wonder on the candidate position
-                                    tile.set_natural_wonder(self, natural_wonder);
-                                    // add the position of the placed natural wonder to the list of placed natural wonder positions
-                                    placed_natural_wonder_tiles.push(tile);
-                                }
-                                _ => {
-                                    let turns_into_terrain =
-                                        &natural_wonder_info.turns_into_terrain;
-                                    // Edit the choice tile's `terrain_type` to match the natural wonder
-                                    tile.set_terrain_type(self, turns_into_terrain.terrain_type);
-
-                                    // Edit the choice tile's `base_terrain` to match the natural wonder
-                                    if let Some(turn_into_base_terrain) =
-                                        turns_into_terrain.base_terrain
-                                    {
-                                        tile.set_base_terrain(self, turn_into_base_terrain);
-                                    }
-                                    // place the natural wonder on the candidate position
-                                    tile.set_natural_wonder(self, natural_wonder);
-                                    // add the position of the placed natural wonder to the list of placed natural wonder positions
-                                    placed_natural_wonder_tiles.push(tile);
-                                }
-                            }
+                        if self.layer_data[Layer::NaturalWonder][tile.index()] == 0
+                            && !self.natural_wonder_disturbs_resources(
+                                tile,
+                                natural_wonder,
+                                neighbor_tile_direction,
+                            )
+                        {
+                            placed_natural_wonder_tiles.extend(self.set_natural_wonder_on_tile(
+                                tile,
+                                natural_wonder,
+                                ruleset,
+                                neighbor_tile_direction,
+                            ));
 
                             self.place_impact_and_ripples(tile, Layer::NaturalWonder, u32::MAX);
 
@@ -286,39 +102,7 @@ impl TileMap {
             );
         }
 
-        // If the natural wonder is not a lake, and it has water neighbors, then change the water neighbor tiles to lake or coast.
-        placed_natural_wonder_tiles.iter().for_each(|&tile| {
-            if tile.terrain_type(self) != TerrainType::Water
-                && tile
-                    .neighbor_tiles(grid)
-                    .any(|neighbor_tile| neighbor_tile.terrain_type(self) == TerrainType::Water)
-            {
-                let water_neighbor_tiles: Vec<_> = tile
-                    .neighbor_tiles(grid)
-                    .filter(|&neighbor_tile| neighbor_tile.terrain_type(self) == TerrainType::Water)
-                    .collect();
-
-                water_neighbor_tiles
-                    .iter()
-                    .for_each(|&water_neighbor_tile| {
-                        // If the water neighbor tile has a lake neighbor, then change the water neighbor tile to a lake.
-                        // Otherwise, change the water neighbor tile to a coast.
-                        let has_lake_neighbor = water_neighbor_tile.neighbor_tiles(grid).any(
-                            |neighbor_neighbor_tile| {
-                                neighbor_neighbor_tile.base_terrain(self) == BaseTerrain::Lake
-                            },
-                        );
-                        water_neighbor_tile.set_base_terrain(
-                            self,
-                            if has_lake_neighbor {
-                                BaseTerrain::Lake
-                            } else {
-                                BaseTerrain::Coast
-                            },
-                        );
-                    });
-            }
-        });
+        self.fix_water_around_natural_wonders(&placed_natural_wonder_tiles);
     }
 
     /// Generate natural wonders on the map.
@@ -328,22 +112,7 @@ impl TileMap {
         let ruleset = &map_parameters.ruleset;
         let grid = self.world_grid.grid;
 
-        // Get the number of natural wonders to place based on the world size
-        let num_natural_wonders = map_parameters.world_size_type_profile.num_natural_wonders;
-
-        // Collect the natural wonders and their possible tile locations
-        let mut natural_wonder_and_tile_list: EnumMap<NaturalWonder, Vec<Tile>> =
-            EnumMap::default();
-
-        let mut land_area_id_and_size: Vec<_> = self
-            .area_list
-            .iter()
-            .filter(|area| area.area_flags.contains(AreaFlags::FlatlandOrHill))
-            .map(|area| (area.id, area.size))
-            .collect();
-
-        // Sort by `area_size` in descending order
-        land_area_id_and_size.sort_by_key(|&(_, area_size)| Reverse(area_size));
+        let num_natural_wonders = num_natural_wonders_to_place(map_parameters);
 
         /***** Tackle with the natural wonders which require 2 adjacent tiles *****/
         // When a natural wonder requires occupying 2 adjacent tiles,
@@ -359,128 +128,8 @@ impl TileMap {
             .choose(&mut self.random_number_generator)
             .expect("Failed to choose a random direction");
 
-        for tile in self.all_tiles() {
-            // If tile is a civilization start location, or a city state start location, or has natural wonder, then it cannot be chosen as the location for placing natural wonder.
-            if self.starting_tile_and_civilization.contains_key(&tile)
-                || self.starting_tile_and_city_state.contains_key(&tile)
-                || tile.natural_wonder(self).is_some()
-            {
-                continue;
-            }
-
-            for (natural_wonder, tile_list) in natural_wonder_and_tile_list.iter_mut() {
-                let natural_wonder_info = &ruleset.natural_wonders[natural_wonder];
-
-                let required_terrain = &natural_wonder_info.required_terrain;
-
-                if let Some(freshwater) = required_terrain.freshwater
-                    && tile.is_freshwater(self) != freshwater
-                {
-                    continue;
-                }
-
-                if !required_terrain
-                    .terrain_type
-                    .contains(&tile.terrain_type(self))
-                    || !required_terrain
-                        .base_terrain
-                        .contains(&tile.base_terrain(self))
-                {
-                    continue;
-                }
-
-                match natural_wonder {
-                    NaturalWonder::GreatBarrierReef => {
-                        if let Some(neighbor_tile) =
-                            tile.neighbor_tile(neighbor_tile_direction, grid)
-                        {
-                            let mut all_neigbor_tiles = HashSet::new();
-
-                            all_neigbor_tiles.extend(tile.neighbor_tiles(grid));
-                            all_neigbor_tiles.extend(neighbor_tile.neighbor_tiles(grid));
-
-                            // We only check neighbors of the current tile and the neighbor tile.
-                            // So we remove them from the set of all neighbor tiles.
-                            all_neigbor_tiles.remove(&tile);
-                            all_neigbor_tiles.remove(&neighbor_tile);
-
-                            // The tile should meet the following conditions:
-                            // 1. All neighboring tiles exist
-                            // 2. All neighboring tiles are water and not lake, not ice
-                            // 3. At least 4 neighboring tiles are coast
-                            if all_neigbor_tiles.len() == 8
-                                && all_neigbor_tiles.iter().all(|&tile| {
-                                    tile.terrain_type(self) == TerrainType::Water
-                                        && tile.base_terrain(self) != BaseTerrain::Lake
-                                        && tile.feature(self) != Some(Feature::Ice)
-                                })
-                                && all_neigbor_tiles
-                                    .iter()
-                                    .filter(|tile| tile.base_terrain(self) == BaseTerrain::Coast)
-                                    .count()
-                                    >= 4
-                            {
-                                tile_list.push(tile);
-                            }
-                        }
-                    }
-                    _ => {
-                        let check_extra_conditions =
-                            required_terrain.extra_conditions.iter().all(|unique| {
-                                let unique = Unique::new(unique);
-                                match unique.placeholder_text.as_str() {
-                                    "Must be adjacent to [] [] tiles" => {
-                                        let count = tile
-                                            .neighbor_tiles(grid)
-                                            .filter(|tile| {
-                                                self.matches_wonder_filter(
-                                                    *tile,
-                                                    unique.params[1].as_str(),
-                                                )
-                                            })
-                                            .count();
-                                        count == unique.params[0].parse::<usize>().unwrap()
-                                    }
-                                    "Must be adjacent to [] to [] [] tiles" => {
-                                        let count = tile
-                                            .neighbor_tiles(grid)
-                                            .filter(|tile| {
-                                                self.matches_wonder_filter(
-                                                    *tile,
-                                                    unique.params[2].as_str(),
-                                                )
-                                            })
-                                            .count();
-                                        count >= unique.params[0].parse::<usize>().unwrap()
-                                            && count <= unique.params[1].parse::<usize>().unwrap()
-                                    }
-                                    "Must not be on [] largest landmasses" => {
-                                        // index is the ranking of the current landmass among all landmasses sorted by size from highest to lowest.
-                                        let index = unique.params[0].parse::<usize>().unwrap();
-                                        // Check if the tile isn't on the landmass with the given index
-                                        land_area_id_and_size
-                                            .get(index)
-                                            .is_none_or(|&(id, _)| id != tile.area_id(self))
-                                    }
-                                    "Must be on [] largest landmasses" => {
-                                        // index is the ranking of the current landmass among all landmasses sorted by size from highest to lowest.
-                                        let index = unique.params[0].parse::<usize>().unwrap();
-                                        // Check if the tile is on the landmass with the given index
-                                        land_area_id_and_size
-                                            .get(index)
-                                            .is_some_and(|&(id, _)| id == tile.area_id(self))
-                                    }
-                                    _ => true,
-                                }
-                            });
-
-                        if check_extra_conditions {
-                            tile_list.push(tile);
-                        }
-                    }
-                }
-            }
-        }
+        let natural_wonder_and_tile_list =
+            self.natural_wonder_and_candidate_tiles(map_parameters, neighbor_tile_direction);
 
         // Get the natural wonders that can be placed
         let mut selected_natural_wonder_list: Vec<_> = natural_wonder_and_tile_list
@@ -536,78 +185,12 @@ impl TileMap {
                         .unwrap();
 
                     if !placed_natural_wonder_tiles.contains(&max_score_tile) {
-                        let natural_wonder_info = &ruleset.natural_wonders[natural_wonder];
-
-                        // At first, we should remove feature from the tile
-                        max_score_tile.clear_feature(self);
-
-                        match natural_wonder {
-                            NaturalWonder::GreatBarrierReef => {
-                                // The neighbor tile absolutely exists because we have checked it before.
-                                let neighbor_tile = max_score_tile
-                                    .neighbor_tile(neighbor_tile_direction, grid)
-                                    .expect("Neighbor tile does not exist");
-
-                                // All related tiles should contain:
-                                // 1. Current tile (`max_score_tile``)
-                                // 2. Neighbor tile according to `neighbor_tile_direction`
-                                // 3. All neighbor tiles of current tile and neighbor tile
-                                let mut all_related_tiles = HashSet::new();
-
-                                all_related_tiles.extend(max_score_tile.neighbor_tiles(grid));
-                                all_related_tiles.extend(neighbor_tile.neighbor_tiles(grid));
-
-                                all_related_tiles.into_iter().for_each(|tile| {
-                                    tile.set_terrain_type(self, TerrainType::Water);
-                                    tile.set_base_terrain(self, BaseTerrain::Coast);
-                                });
-
-                                // place the natural wonder on the candidate position and its adjacent tile
-                                max_score_tile.set_natural_wonder(self, natural_wonder);
-                                neighbor_tile.set_natural_wonder(self, natural_wonder);
-                                // add the position of the placed natural wonder to the list of placed natural wonder positions
-                                placed_natural_wonder_tiles.push(max_score_tile);
-                                placed_natural_wonder_tiles.push(neighbor_tile);
-                            }
-                            NaturalWonder::RockOfGibraltar => {
-                                max_score_tile
-                                    .neighbor_tiles(grid)
-                                    .for_each(|neighbor_tile| {
-                                        if neighbor_tile.terrain_type(self) == TerrainType::Water {
-                                            neighbor_tile
-                                                .set_base_terrain(self, BaseTerrain::Coast);
-                                        } else {
-                                            neighbor_tile
-                                                .set_terrain_type(self, TerrainType::Mountain);
-                                        }
-                                    });
-                                // Edit the choice tile's terrain_type to match the natural wonder
-                                max_score_tile.set_terrain_type(self, TerrainType::Flatland);
-                                // Edit the choice tile's base_terrain to match the natural wonder
-                                max_score_tile.set_base_terrain(self, BaseTerrain::Grassland);
-                                // place the natural wonder on the candidate position
-                                max_score_tile.set_natural_wonder(self, natural_wonder);
-                                // add the position of the placed natural wonder to the list of placed natural wonder positions
-                                placed_natural_wonder_tiles.push(max_score_tile);
-                            }
-                            _ => {
-                                let turns_into_terrain = &natural_wonder_info.turns_into_terrain;
-                                // Edit the choice tile's `terrain_type` to match the natural wonder
-                                max_score_tile
-                                    .set_terrain_type(self, turns_into_terrain.terrain_type);
-
-                                // Edit the choice tile's `base_terrain` to match the natural wonder
-                                if let Some(turn_into_base_terrain) =
-                                    turns_into_terrain.base_terrain
-                                {
-                                    max_score_tile.set_base_terrain(self, turn_into_base_terrain);
-                                }
-                                // place the natural wonder on the candidate position
-                                max_score_tile.set_natural_wonder(self, natural_wonder);
-                                // add the position of the placed natural wonder to the list of placed natural wonder positions
-                                placed_natural_wonder_tiles.push(max_score_tile);
-                            }
-                        }
+                        placed_natural_wonder_tiles.extend(self.set_natural_wonder_on_tile(
+                            max_score_tile,
+                            natural_wonder,
+                            ruleset,
+                            neighbor_tile_direction,
+                        ));
                         num_placed_natural_wonders += 1;
                     }
                 }
@@ -621,16 +204,232 @@ impl TileMap {
             );
         }
 
+        self.fix_water_around_natural_wonders(&placed_natural_wonder_tiles);
+    }
+
+    /// Places `natural_wonder` on `tile`, which is useful for scenario designers.
+    ///
+    /// The tile must be one of [`TileMap::natural_wonder_candidates`].
+    /// For [`NaturalWonder::GreatBarrierReef`], the second tile is the neighbor in the first direction of [`Grid::edge_direction_array`] which fits.
+    ///
+    /// Like [`TileMap::place_natural_wonders`], the feature of the tile is removed, the terrain around it is changed to match the natural wonder,
+    /// and the impact of [`Layer::NaturalWonder`] is placed by [`TileMap::place_impact_and_ripples`].
+    /// The resources on the tiles occupied by the natural wonder and on the tiles whose terrain is changed are removed as well,
+    /// e.g. the neighbors of [`NaturalWonder::RockOfGibraltar`] turned to mountains.
+    /// At last, the areas are recalculated by [`TileMap::recalculate_areas`], because the terrain types may be changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlacementError::Occupied`] if the tile is a starting tile or already has a natural wonder,
+    /// and [`PlacementError::Ineligible`] if the tile doesn't meet the requirements of the natural wonder.
+    /// The map is not changed when an error is returned.
+    pub fn place_natural_wonder_at(
+        &mut self,
+        tile: Tile,
+        natural_wonder: NaturalWonder,
+        map_parameters: &MapParameters,
+    ) -> Result<(), PlacementError> {
+        let grid = self.world_grid.grid;
+
+        if self.starting_tile_and_civilization.contains_key(&tile)
+            || self.starting_tile_and_city_state.contains_key(&tile)
+            || tile.natural_wonder(self).is_some()
+        {
+            return Err(PlacementError::Occupied(tile));
+        }
+
+        let land_area_id_and_size = self.land_area_id_and_size();
+
+        let neighbor_tile_direction = grid
+            .edge_direction_array()
+            .into_iter()
+            .find(|&direction| {
+                self.is_natural_wonder_candidate(
+                    tile,
+                    natural_wonder,
+                    map_parameters,
+                    &land_area_id_and_size,
+                    direction,
+                )
+            })
+            .ok_or(PlacementError::Ineligible(tile, natural_wonder))?;
+
+        let related_tiles =
+            self.natural_wonder_related_tiles(tile, natural_wonder, neighbor_tile_direction);
+        let terrain_before: Vec<_> = related_tiles
+            .iter()
+            .map(|tile| {
+                (
+                    tile.terrain_type(self),
+                    tile.base_terrain(self),
+                    tile.feature(self),
+                )
+            })
+            .collect();
+
+        let placed_tiles = self.set_natural_wonder_on_tile(
+            tile,
+            natural_wonder,
+            &map_parameters.ruleset,
+            neighbor_tile_direction,
+        );
+
+        self.place_impact_and_ripples(tile, Layer::NaturalWonder, u32::MAX);
+
+        self.fix_water_around_natural_wonders(&placed_tiles);
+
+        for (related_tile, terrain) in related_tiles.into_iter().zip(terrain_before) {
+            let terrain_changed = (
+                related_tile.terrain_type(self),
+                related_tile.base_terrain(self),
+                related_tile.feature(self),
+            ) != terrain;
+            if terrain_changed || placed_tiles.contains(&related_tile) {
+                related_tile.clear_resource(self);
+            }
+        }
+
+        self.recalculate_areas(map_parameters);
+
+        Ok(())
+    }
+
+    /// Sets `natural_wonder` on `tile`, and changes the terrain of the tile and its neighbors to match the natural wonder.
+    ///
+    /// `neighbor_tile_direction` is the direction of the second tile occupied by [`NaturalWonder::GreatBarrierReef`].
+    ///
+    /// Returns the tiles occupied by the natural wonder.
+    fn set_natural_wonder_on_tile(
+        &mut self,
+        tile: Tile,
+        natural_wonder: NaturalWonder,
+        ruleset: &Ruleset,
+        neighbor_tile_direction: Direction,
+    ) -> Vec<Tile> {
+        let grid = self.world_grid.grid;
+
+        // At first, we should remove feature from the tile
+        tile.clear_feature(self);
+
+        match natural_wonder {
+            NaturalWonder::GreatBarrierReef => {
+                // The neighbor tile absolutely exists because we have checked it before.
+                let neighbor_tile = tile
+                    .neighbor_tile(neighbor_tile_direction, grid)
+                    .expect("Neighbor tile does not exist");
+
+                // All related tiles should contain:
+                // 1. Current tile
+                // 2. Neighbor tile according to neighbor_tile_direction
+                // 3. All neighbor tiles of current tile and neighbor tile
+                let mut all_related_tiles = HashSet::new();
+
+                all_related_tiles.extend(tile.neighbor_tiles(grid));
+                all_related_tiles.extend(neighbor_tile.neighbor_tiles(grid));
+
+                all_related_tiles.into_iter().for_each(|tile| {
+                    tile.set_terrain_type(self, TerrainType::Water);
+                    tile.set_base_terrain(self, BaseTerrain::Coast);
+                });
+                // place the natural wonder on the candidate position and its adjacent tile
+                tile.set_natural_wonder(self, natural_wonder);
+                neighbor_tile.set_natural_wonder(self, natural_wonder);
+
+                vec![tile, neighbor_tile]
+            }
+            NaturalWonder::RockOfGibraltar => {
+                tile.neighbor_tiles(grid).for_each(|neighbor_tile| {
+                    if neighbor_tile.is_water(self) {
+                        neighbor_tile.set_base_terrain(self, BaseTerrain::Coast);
+                    } else {
+                        neighbor_tile.set_terrain_type(self, TerrainType::Mountain);
+                    }
+                });
+                // Edit the choice tile's terrain_type to match the natural wonder
+                tile.set_terrain_type(self, TerrainType::Flatland);
+                // Edit the choice tile's base_terrain to match the natural wonder
+                tile.set_base_terrain(self, BaseTerrain::Grassland);
+                // place the natural wonder on the candidate position
+                tile.set_natural_wonder(self, natural_wonder);
+
+                vec![tile]
+            }
+            _ => {
+                let turns_into_terrain =
+                    &ruleset.natural_wonders[natural_wonder].turns_into_terrain;
+                // Edit the choice tile's `terrain_type` to match the natural wonder
+                tile.set_terrain_type(self, turns_into_terrain.terrain_type);
+
+                // Edit the choice tile's `base_terrain` to match the natural wonder
+                if let Some(turn_into_base_terrain) = turns_into_terrain.base_terrain {
+                    tile.set_base_terrain(self, turn_into_base_terrain);
+                }
+                // place the natural wonder on the candidate position
+                tile.set_natural_wonder(self, natural_wonder);
+
+                vec![tile]
+            }
+        }
+    }
+
+    /// Returns the tiles which may be occupied by `natural_wonder` on `tile` or have their terrain changed by it.
+    ///
+    /// They are `tile` and its neighbors, and the neighbors of the second tile of [`NaturalWonder::GreatBarrierReef`],
+    /// see [`TileMap::set_natural_wonder_on_tile`] and [`TileMap::fix_water_around_natural_wonders`].
+    fn natural_wonder_related_tiles(
+        &self,
+        tile: Tile,
+        natural_wonder: NaturalWonder,
+        neighbor_tile_direction: Direction,
+    ) -> Vec<Tile> {
+        let grid = self.world_grid.grid;
+
+        let mut related_tiles: Vec<Tile> = std::iter::once(tile)
+            .chain(tile.neighbor_tiles(grid))
+            .collect();
+
+        if natural_wonder == NaturalWonder::GreatBarrierReef
+            && let Some(neighbor_tile) = tile.neighbor_tile(neighbor_tile_direction, grid)
+        {
+            related_tiles.extend(neighbor_tile.neighbor_tiles(grid));
+        }
+
+        related_tiles.sort_unstable();
+        related_tiles.dedup();
+
+        related_tiles
+    }
+
+    /// Checks if placing `natural_wonder` on `tile` would occupy or change the terrain of a tile with a resource.
+    ///
+    /// The natural wonder may change the terrain of its neighbors, see [`TileMap::natural_wonder_related_tiles`],
+    /// so the neighbors are checked as well.
+    /// It only matters when the resources are placed before the natural wonders, see [`WonderResourceOrder::ResourcesFirst`](crate::map_parameters::WonderResourceOrder::ResourcesFirst).
+    fn natural_wonder_disturbs_resources(
+        &self,
+        tile: Tile,
+        natural_wonder: NaturalWonder,
+        neighbor_tile_direction: Direction,
+    ) -> bool {
+        self.natural_wonder_related_tiles(tile, natural_wonder, neighbor_tile_direction)
+            .into_iter()
+            .any(|tile| tile.resource(self).is_some())
+    }
+
+    /// Changes the water neighbors of the land tiles in `natural_wonder_tiles` to lake or coast.
+    fn fix_water_around_natural_wonders(&mut self, natural_wonder_tiles: &[Tile]) {
+        let grid = self.world_grid.grid;
+
         // If the natural wonder is not a lake, and it has water neighbors, then change the water neighbor tiles to lake or coast.
-        placed_natural_wonder_tiles.iter().for_each(|&tile| {
-            if tile.terrain_type(self) != TerrainType::Water
+        natural_wonder_tiles.iter().for_each(|&tile| {
+            if tile.is_land(self)
                 && tile
                     .neighbor_tiles(grid)
-                    .any(|neighbor_tile| neighbor_tile.terrain_type(self) == TerrainType::Water)
+                    .any(|neighbor_tile| neighbor_tile.is_water(self))
             {
                 let water_neighbor_tiles: Vec<_> = tile
                     .neighbor_tiles(grid)
-                    .filter(|&neighbor_tile| neighbor_tile.terrain_type(self) == TerrainType::Water)
+                    .filter(|&neighbor_tile| neighbor_tile.is_water(self))
                     .collect();
 
                 water_neighbor_tiles
@@ -656,6 +455,204 @@ impl TileMap {
         });
     }
 
+    /// Returns the tiles where `natural_wonder` could be placed on the current map, without placing anything.
+    ///
+    /// It uses the same eligibility checks as [`TileMap::place_natural_wonders`] and [`TileMap::generate_natural_wonders`],
+    /// including the required terrain, the adjacency conditions and the water requirements in the ruleset.
+    ///
+    /// [`NaturalWonder::GreatBarrierReef`] occupies a tile and one of its neighbors.
+    /// The placement chooses the direction of the neighbor randomly, so here a tile is a candidate if any direction fits.
+    pub fn natural_wonder_candidates(
+        &self,
+        natural_wonder: NaturalWonder,
+        map_parameters: &MapParameters,
+    ) -> Vec<Tile> {
+        let grid = self.world_grid.grid;
+        let land_area_id_and_size = self.land_area_id_and_size();
+
+        self.all_tiles()
+            .filter(|&tile| {
+                grid.edge_direction_array().iter().any(|&direction| {
+                    self.is_natural_wonder_candidate(
+                        tile,
+                        natural_wonder,
+                        map_parameters,
+                        &land_area_id_and_size,
+                        direction,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Collects the candidate tiles of every natural wonder.
+    ///
+    /// `neighbor_tile_direction` is the direction of the second tile occupied by [`NaturalWonder::GreatBarrierReef`].
+    fn natural_wonder_and_candidate_tiles(
+        &self,
+        map_parameters: &MapParameters,
+        neighbor_tile_direction: Direction,
+    ) -> EnumMap<NaturalWonder, Vec<Tile>> {
+        let land_area_id_and_size = self.land_area_id_and_size();
+
+        // Collect the natural wonders and their possible tile locations
+        let mut natural_wonder_and_tile_list: EnumMap<NaturalWonder, Vec<Tile>> =
+            EnumMap::default();
+
+        for tile in self.all_tiles() {
+            for (natural_wonder, tile_list) in natural_wonder_and_tile_list.iter_mut() {
+                if self.is_natural_wonder_candidate(
+                    tile,
+                    natural_wonder,
+                    map_parameters,
+                    &land_area_id_and_size,
+                    neighbor_tile_direction,
+                ) {
+                    tile_list.push(tile);
+                }
+            }
+        }
+
+        natural_wonder_and_tile_list
+    }
+
+    /// Returns the id and size of the land areas, sorted by size in descending order.
+    fn land_area_id_and_size(&self) -> Vec<(usize, u32)> {
+        let mut land_area_id_and_size: Vec<_> = self
+            .area_list
+            .iter()
+            .filter(|area| area.area_flags.contains(AreaFlags::FlatlandOrHill))
+            .map(|area| (area.id, area.size))
+            .collect();
+
+        // Sort by `area_size` in descending order
+        land_area_id_and_size.sort_by_key(|&(_, area_size)| Reverse(area_size));
+
+        land_area_id_and_size
+    }
+
+    /// Checks whether `natural_wonder` can be placed on `tile`.
+    ///
+    /// - `land_area_id_and_size`: the land areas sorted by size in descending order, see [`TileMap::land_area_id_and_size`].
+    /// - `neighbor_tile_direction`: the direction of the second tile occupied by [`NaturalWonder::GreatBarrierReef`].
+    ///   It is ignored by the other natural wonders.
+    fn is_natural_wonder_candidate(
+        &self,
+        tile: Tile,
+        natural_wonder: NaturalWonder,
+        map_parameters: &MapParameters,
+        land_area_id_and_size: &[(usize, u32)],
+        neighbor_tile_direction: Direction,
+    ) -> bool {
+        let ruleset = &map_parameters.ruleset;
+        let grid = self.world_grid.grid;
+
+        // If tile is a civilization start location, or a city state start location, or has natural wonder, then it cannot be chosen as the location for placing natural wonder.
+        // If `natural_wonders_avoid_border` is true, the tile on the map border cannot be chosen either.
+        if self.starting_tile_and_civilization.contains_key(&tile)
+            || self.starting_tile_and_city_state.contains_key(&tile)
+            || tile.natural_wonder(self).is_some()
+            || (map_parameters.natural_wonders_avoid_border && self.is_on_map_border(tile))
+        {
+            return false;
+        }
+
+        let natural_wonder_info = &ruleset.natural_wonders[natural_wonder];
+
+        let required_terrain = &natural_wonder_info.required_terrain;
+
+        if let Some(freshwater) = required_terrain.freshwater
+            && tile.is_freshwater(self) != freshwater
+        {
+            return false;
+        }
+
+        if !required_terrain
+            .terrain_type
+            .contains(&tile.terrain_type(self))
+            || !required_terrain
+                .base_terrain
+                .contains(&tile.base_terrain(self))
+        {
+            return false;
+        }
+
+        match natural_wonder {
+            NaturalWonder::GreatBarrierReef => {
+                let Some(neighbor_tile) = tile.neighbor_tile(neighbor_tile_direction, grid) else {
+                    return false;
+                };
+
+                let mut all_neigbor_tiles = HashSet::new();
+
+                all_neigbor_tiles.extend(tile.neighbor_tiles(grid));
+                all_neigbor_tiles.extend(neighbor_tile.neighbor_tiles(grid));
+
+                // We only check neighbors of the current tile and the neighbor tile.
+                // So we remove them from the set of all neighbor tiles.
+                all_neigbor_tiles.remove(&tile);
+                all_neigbor_tiles.remove(&neighbor_tile);
+
+                // The tile should meet the following conditions:
+                // 1. All neighboring tiles exist
+                // 2. All neighboring tiles are water and not lake, not ice
+                // 3. At least 4 neighboring tiles are coast
+                all_neigbor_tiles.len() == 8
+                    && all_neigbor_tiles.iter().all(|&tile| {
+                        tile.is_water(self)
+                            && tile.base_terrain(self) != BaseTerrain::Lake
+                            && tile.feature(self) != Some(Feature::Ice)
+                    })
+                    && all_neigbor_tiles
+                        .iter()
+                        .filter(|tile| tile.base_terrain(self) == BaseTerrain::Coast)
+                        .count()
+                        >= 4
+            }
+            _ => required_terrain.extra_conditions.iter().all(|unique| {
+                let unique = Unique::new(unique);
+                match unique.placeholder_text.as_str() {
+                    "Must be adjacent to [] [] tiles" => {
+                        let count = tile
+                            .neighbor_tiles(grid)
+                            .filter(|tile| {
+                                self.matches_wonder_filter(*tile, unique.params[1].as_str())
+                            })
+                            .count();
+                        count == unique.params[0].parse::<usize>().unwrap()
+                    }
+                    "Must be adjacent to [] to [] [] tiles" => {
+                        let count = tile
+                            .neighbor_tiles(grid)
+                            .filter(|tile| {
+                                self.matches_wonder_filter(*tile, unique.params[2].as_str())
+                            })
+                            .count();
+                        count >= unique.params[0].parse::<usize>().unwrap()
+                            && count <= unique.params[1].parse::<usize>().unwrap()
+                    }
+                    "Must not be on [] largest landmasses" => {
+                        // index is the ranking of the current landmass among all landmasses sorted by size from highest to lowest.
+                        let index = unique.params[0].parse::<usize>().unwrap();
+                        // Check if the tile isn't on the landmass with the given index
+                        land_area_id_and_size
+                            .get(index)
+                            .is_none_or(|&(id, _)| id != tile.area_id(self))
+                    }
+                    "Must be on [] largest landmasses" => {
+                        // index is the ranking of the current landmass among all landmasses sorted by size from highest to lowest.
+                        let index = unique.params[0].parse::<usize>().unwrap();
+                        // Check if the tile is on the landmass with the given index
+                        land_area_id_and_size
+                            .get(index)
+                            .is_some_and(|&(id, _)| id == tile.area_id(self))
+                    }
+                    _ => true,
+                }
+            }),
+        }
+    }
+
     fn matches_wonder_filter(&self, tile: Tile, filter: &str) -> bool {
         let terrain_type = tile.terrain_type(self);
         let base_terrain = tile.base_terrain(self);
@@ -672,3 +669,192 @@ impl TileMap {
         }
     }
 }
+
+/// Returns the number of natural wonders to place.
+///
+/// It is [`MapParameters::natural_wonder_count`] when it is set, otherwise it is based on the world size.
+fn num_natural_wonders_to_place(map_parameters: &MapParameters) -> u32 {
+    map_parameters
+        .natural_wonder_count
+        .unwrap_or(map_parameters.world_size_type_profile.num_natural_wonders)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map,
+        grid::{GridSize, HexGrid, MapGrid, WorldSizeType},
+        map_parameters::{MapParametersBuilder, WorldGrid},
+        ruleset::enums::TerrainType,
+        test_utils::test_map_parameters,
+    };
+
+    /// Tests that no natural wonder is placed when `natural_wonder_count` is `0`.
+    #[test]
+    fn test_zero_natural_wonders() {
+        let map_parameters = test_map_parameters().natural_wonder_count(0).build();
+        let map = generate_map(&map_parameters);
+
+        assert!(map.natural_wonder_list.iter().all(Option::is_none));
+    }
+
+    /// Tests that no natural wonder is on the outermost ring of a non-wrapping map by default.
+    #[test]
+    fn test_natural_wonders_avoid_border() {
+        use crate::grid::{Grid, WrapFlags};
+
+        let world_size = WorldSizeType::Standard;
+        let grid = MapGrid::Hex(HexGrid {
+            size: HexGrid::default_size(world_size),
+            wrap_flags: WrapFlags::empty(),
+            ..HexGrid::default()
+        });
+
+        for seed in 0..5 {
+            let map_parameters = MapParametersBuilder::new(WorldGrid::new(grid, world_size))
+                .seed(seed)
+                .natural_wonder_count(10)
+                .build();
+            let map = generate_map(&map_parameters);
+
+            for tile in map
+                .all_tiles()
+                .filter(|tile| tile.natural_wonder(&map).is_some())
+            {
+                let [x, y] = tile.to_offset(grid).to_array();
+                assert!(x > 0 && x < grid.width() as i32 - 1);
+                assert!(y > 0 && y < grid.height() as i32 - 1);
+            }
+        }
+    }
+
+    /// Tests that [`TileMap::natural_wonder_candidates`] doesn't place anything,
+    /// and that the candidates of Great Barrier Reef are all adjacent to water.
+    #[test]
+    fn test_natural_wonder_candidates() {
+        use crate::ruleset::enums::NaturalWonder;
+
+        let map_parameters = test_map_parameters().build();
+        let map = generate_map(&map_parameters);
+
+        let natural_wonder_list = map.natural_wonder_list.clone();
+        let candidates =
+            map.natural_wonder_candidates(NaturalWonder::GreatBarrierReef, &map_parameters);
+        assert_eq!(map.natural_wonder_list, natural_wonder_list);
+
+        assert!(!candidates.is_empty());
+        assert!(candidates.iter().all(|tile| {
+            tile.neighbors(&map)
+                .iter()
+                .any(|neighbor_tile| neighbor_tile.is_water(&map))
+        }));
+    }
+
+    /// Tests that [`TileMap::place_natural_wonder_at`] places Mount Fuji on an eligible flatland tile
+    /// with the impact on the strategic layer of the existing Mount Fuji rules, and rejects ineligible tiles.
+    /// Also tests that placing Rock of Gibraltar removes the resources on the neighbors whose terrain it changes.
+    #[test]
+    fn test_place_natural_wonder_at() {
+        use crate::{
+            ruleset::enums::{NaturalWonder, Resource},
+            tile_map::{Layer, PlacementError},
+        };
+
+        let map_parameters = test_map_parameters().build();
+        let mut map = generate_map(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        let tile = map
+            .natural_wonder_candidates(NaturalWonder::MountFuji, &map_parameters)
+            .into_iter()
+            .find(|tile| tile.terrain_type(&map) == TerrainType::Flatland)
+            .expect("No flatland tile is eligible for Mount Fuji");
+        let water_tile = map.all_tiles().find(|tile| tile.is_water(&map)).unwrap();
+
+        // Reset the strategic layer, so only the impact of Mount Fuji is on it.
+        map.layer_data[Layer::Strategic].fill(0);
+
+        assert_eq!(
+            map.place_natural_wonder_at(water_tile, NaturalWonder::MountFuji, &map_parameters),
+            Err(PlacementError::Ineligible(
+                water_tile,
+                NaturalWonder::MountFuji
+            ))
+        );
+        assert!(
+            map.layer_data[Layer::Strategic]
+                .iter()
+                .all(|&value| value == 0)
+        );
+
+        map.place_natural_wonder_at(tile, NaturalWonder::MountFuji, &map_parameters)
+            .unwrap();
+
+        assert_eq!(tile.natural_wonder(&map), Some(NaturalWonder::MountFuji));
+        assert_eq!(tile.terrain_type(&map), TerrainType::Mountain);
+        assert_eq!(tile.feature(&map), None);
+        assert_eq!(tile.resource(&map), None);
+
+        // Mount Fuji only forbids strategic resources on its own tile.
+        assert_eq!(map.layer_data[Layer::Strategic][tile.index()], 99);
+        assert!(
+            tile.neighbor_tiles(grid)
+                .all(|neighbor_tile| map.layer_data[Layer::Strategic][neighbor_tile.index()] == 0)
+        );
+
+        assert_eq!(
+            map.place_natural_wonder_at(tile, NaturalWonder::MountFuji, &map_parameters),
+            Err(PlacementError::Occupied(tile))
+        );
+
+        // Rock of Gibraltar turns its land neighbors to mountains, so their resources are removed.
+        let (tile, land_neighbor_tile) = map
+            .natural_wonder_candidates(NaturalWonder::RockOfGibraltar, &map_parameters)
+            .into_iter()
+            .find_map(|tile| {
+                let land_neighbor_tile = tile.neighbor_tiles(grid).find(|neighbor_tile| {
+                    neighbor_tile.is_land(&map)
+                        && neighbor_tile.terrain_type(&map) != TerrainType::Mountain
+                })?;
+                Some((tile, land_neighbor_tile))
+            })
+            .expect("No tile is eligible for Rock of Gibraltar");
+        land_neighbor_tile.set_resource(&mut map, Resource::Iron, 1);
+
+        map.place_natural_wonder_at(tile, NaturalWonder::RockOfGibraltar, &map_parameters)
+            .unwrap();
+
+        assert_eq!(land_neighbor_tile.terrain_type(&map), TerrainType::Mountain);
+        assert_eq!(land_neighbor_tile.resource(&map), None);
+
+        // The areas are recalculated after the terrain is changed.
+        let area_id_list = map.area_id_list.clone();
+        map.recalculate_areas(&map_parameters);
+        assert_eq!(map.area_id_list, area_id_list);
+    }
+
+    /// Tests that only the allowed natural wonders are placed when `allowed_natural_wonders` is set.
+    #[test]
+    fn test_allowed_natural_wonders() {
+        let map_parameters = test_map_parameters().build();
+        let map = generate_map(&map_parameters);
+        let allowed_natural_wonder = map
+            .natural_wonder_list
+            .iter()
+            .flatten()
+            .next()
+            .copied()
+            .unwrap();
+
+        let map_parameters = test_map_parameters()
+            .allowed_natural_wonders(vec![allowed_natural_wonder])
+            .build();
+        let map = generate_map(&map_parameters);
+
+        let mut placed_natural_wonders = map.natural_wonder_list.iter().flatten().peekable();
+        assert!(placed_natural_wonders.peek().is_some());
+        assert!(
+            placed_natural_wonders.all(|&natural_wonder| natural_wonder == allowed_natural_wonder)
+        );
+    }
+}
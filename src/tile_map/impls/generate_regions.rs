@@ -1,14 +1,14 @@
 use crate::{
     grid::*,
-    map_parameters::RegionDivideMethod,
+    map_parameters::{RegionDivideMethod, StartRestriction},
     ruleset::{RegionType, enums::*},
     tile::Tile,
     tile_map::*,
 };
-use enum_map::EnumMap;
+use enum_map::{Enum, EnumMap};
 use serde::{Deserialize, Serialize};
 use std::{
-    cmp::{max, min},
+    cmp::{Reverse, max, min},
     iter::Once,
     sync::OnceLock,
 };
@@ -17,20 +17,39 @@ impl TileMap {
     // function AssignStartingPlots:GenerateRegions(args)
     /// Generates regions for the map according civilization number and region divide method.
     ///
-    /// The number of regions is equal to the number of civilizations.
+    /// The number of regions is equal to the number of civilizations, so no region is generated when there is no civilization.
+    ///
+    /// When [`MapParameters::start_continent`] is [`StartRestriction::OldWorldOnly`],
+    /// all regions are divided from the biggest landmass, the same as [`RegionDivideMethod::Pangaea`].
     pub fn generate_regions(&mut self, map_parameters: &MapParameters) {
         let grid = self.world_grid.grid;
 
         let num_civilizations = map_parameters.world_size_type_profile.num_civilizations;
 
-        match map_parameters.region_divide_method {
+        // A map without civilizations has no regions.
+        if num_civilizations == 0 {
+            return;
+        }
+
+        // When all civilizations start on the old world, regions are divided the same as Pangaea.
+        let region_divide_method = match (
+            &map_parameters.region_divide_method,
+            map_parameters.start_continent,
+        ) {
+            (RegionDivideMethod::Continent, StartRestriction::OldWorldOnly) => {
+                &RegionDivideMethod::Pangaea
+            }
+            (region_divide_method, _) => region_divide_method,
+        };
+
+        match region_divide_method {
             RegionDivideMethod::Pangaea => {
                 // -- Identify the biggest landmass.
                 let biggest_landmass_id = self.get_biggest_land_area_id();
 
                 let landmass_region = Region::landmass_region(self, biggest_landmass_id);
 
-                self.divide_into_regions(num_civilizations, landmass_region);
+                self.divide_into_regions(map_parameters, num_civilizations, landmass_region);
             }
             RegionDivideMethod::Continent => {
                 let mut landmass_region_list: Vec<_> = self
@@ -87,24 +106,28 @@ impl TileMap {
 
                 for (index, region) in best_landmass_region_list.into_iter().enumerate() {
                     if number_of_civs_on_landmass[index] > 0 {
-                        self.divide_into_regions(number_of_civs_on_landmass[index], region);
+                        self.divide_into_regions(
+                            map_parameters,
+                            number_of_civs_on_landmass[index],
+                            region,
+                        );
                     }
                 }
             }
             RegionDivideMethod::WholeMapRectangle => {
                 let rectangle = Rectangle::new(
                     OffsetCoordinate::new(0, 0),
-                    grid.size.width,
-                    grid.size.height,
+                    grid.size().width,
+                    grid.size().height,
                     &grid,
                 );
 
                 let region = Region::rectangle_region(self, grid, rectangle);
-                self.divide_into_regions(num_civilizations, region);
+                self.divide_into_regions(map_parameters, num_civilizations, region);
             }
-            RegionDivideMethod::CustomRectangle(rectangle) => {
+            &RegionDivideMethod::CustomRectangle(rectangle) => {
                 let region = Region::rectangle_region(self, grid, rectangle);
-                self.divide_into_regions(num_civilizations, region);
+                self.divide_into_regions(map_parameters, num_civilizations, region);
             }
         }
     }
@@ -115,6 +138,7 @@ impl TileMap {
     ///
     /// # Arguments
     ///
+    /// - `map_parameters`: The map parameters.
     /// - `num_divisions`: The number of divisions to make.
     /// - `region`: The region to divide. It will be consumed.
     ///
@@ -125,7 +149,12 @@ impl TileMap {
     /// - In the original CIV5, the `chop_percent` values are intentionally set slightly lower than needed. This design choice helps ensure that the final results average out closer to the intended target.\
     ///   In our implementation, we use exact values for `chop_percent`, and use a special algorithm in [`Region::chop_into_two_regions`] to achieve more accurate results.
     ///   Please refer to [`Region::chop_into_two_regions`] for more details.
-    fn divide_into_regions(&mut self, num_divisions: u32, region: Region) {
+    fn divide_into_regions(
+        &mut self,
+        map_parameters: &MapParameters,
+        num_divisions: u32,
+        region: Region,
+    ) {
         let grid = self.world_grid.grid;
 
         let mut stack = Vec::with_capacity(MapParameters::MAX_CIVILIZATION_COUNT as usize);
@@ -136,7 +165,7 @@ impl TileMap {
                 1 => {
                     // If we have only one division, it does not need to be divided further. So we just add it to the region list.
                     current_region.measure_terrain(self);
-                    current_region.determine_region_type();
+                    current_region.determine_region_type(map_parameters.hill_as_region_type);
                     self.region_list.push(current_region);
                 }
                 2 => {
@@ -279,77 +308,14 @@ impl TileMap {
         tile: Tile,
         check_for_coastal_land: bool,
     ) -> i32 {
-        let mut tile_fertility = 0;
-        let terrain_type = tile.terrain_type(self);
-        let base_terrain = tile.base_terrain(self);
-        let feature_type = tile.feature(self);
-
-        // Measure Fertility -- Any cases absent from the process have a 0 value.
-        match terrain_type {
-            TerrainType::Mountain => {
-                // Note, mountains cannot belong to a landmass AreaID, so they usually go unmeasured.
-                return -2;
-            }
-            TerrainType::Hill => {
-                tile_fertility += 1;
-            }
-            _ => {}
-        }
-
-        match base_terrain {
-            BaseTerrain::Snow => {
-                return -1;
-            }
-            BaseTerrain::Grassland => {
-                tile_fertility += 3;
-            }
-            BaseTerrain::Plain => {
-                tile_fertility += 4;
-            }
-            BaseTerrain::Coast | BaseTerrain::Lake | BaseTerrain::Tundra => {
-                tile_fertility += 2;
-            }
-            BaseTerrain::Desert => {
-                tile_fertility += 1;
-            }
-            _ => {}
-        }
-
-        if let Some(feature_type) = feature_type {
-            match feature_type {
-                Feature::Oasis => {
-                    return 4; // Reducing Oasis value slightly.
-                }
-                Feature::Floodplain => {
-                    return 5; // Reducing Flood Plains value slightly.
-                }
-                Feature::Forest => tile_fertility += 0,
-                Feature::Jungle | Feature::Ice => tile_fertility -= 1,
-                Feature::Marsh => tile_fertility -= 2,
-                _ => {}
-            }
-        }
-
-        if tile.has_river(self) {
-            tile_fertility += 1;
-        }
-
-        if tile.is_freshwater(self) {
-            tile_fertility += 1;
-        }
-
-        if check_for_coastal_land && tile.is_coastal_land(self) {
-            tile_fertility += 2;
-        }
-
-        tile_fertility
+        tile.start_placement_fertility(self, check_for_coastal_land)
     }
 
     /// Get the rectangle which bounds the area with the given `area_id`.
     fn obtain_area_rectangle(&self, area_id: usize) -> Rectangle {
         let grid = self.world_grid.grid;
-        let map_height = grid.size.height;
-        let map_width = grid.size.width;
+        let map_height = grid.size().height;
+        let map_width = grid.size().width;
 
         let mut wrap_x = false;
         let mut wrap_y = false;
@@ -378,7 +344,7 @@ impl TileMap {
         // Check if the first and last columns of the map contain tiles that belong to the area.
         // If so, the landmass wraps around the map horizontally.
         // If not, the landmass does not wrap around the map horizontally.
-        if grid.wrap_flags.contains(WrapFlags::WrapX) {
+        if grid.wrap_flags().contains(WrapFlags::WrapX) {
             wrap_x = has_area_in_column(0) && has_area_in_column(map_width - 1);
         }
 
@@ -386,7 +352,7 @@ impl TileMap {
         // Check if the first and last rows of the map contain tiles that belong to the area.
         // If so, the landmass wraps around the map vertically.
         // If not, the landmass does not wrap around the map vertically.
-        if grid.wrap_flags.contains(WrapFlags::WrapY) {
+        if grid.wrap_flags().contains(WrapFlags::WrapY) {
             wrap_y = has_area_in_row(0) && has_area_in_row(map_height - 1);
         }
 
@@ -622,12 +588,20 @@ pub struct Region {
     pub region_type: RegionType,
     /// The starting tile of the civilization in this region. Ensure that method [`TileMap::choose_starting_tiles_of_civilization`] has been called before accessing this field.
     pub starting_tile: OnceLock<Tile>,
+    /// How the starting tile was placed. It is set together with [`Region::starting_tile`].
+    pub start_placement: OnceLock<StartPlacement>,
     /// The start location condition of the region.
     ///
     /// # Notes
     ///
     /// Before reading this field, you must ensure that we have run [`TileMap::normalize_start_tile_of_civilization`] to set this field.
     pub start_location_condition: OnceLock<StartLocationCondition>,
+    /// The start score of the region, which is used to inspect how fair the starting tile is.
+    ///
+    /// # Notes
+    ///
+    /// Before reading this field, you must ensure that we have run [`TileMap::normalize_start_tile_of_civilization`] to set this field.
+    pub start_score: OnceLock<RegionStartScore>,
 }
 
 impl Region {
@@ -649,7 +623,9 @@ impl Region {
             terrain_statistic: OnceLock::new(),
             region_type: RegionType::Undefined,
             starting_tile: OnceLock::new(),
+            start_placement: OnceLock::new(),
             start_location_condition: OnceLock::new(),
+            start_score: OnceLock::new(),
         }
     }
 
@@ -658,6 +634,29 @@ impl Region {
         self.fertility_sum as f64 / self.tile_count as f64
     }
 
+    /// Returns the land base terrain which most tiles in the region have, which can be used to label the region in a UI.
+    ///
+    /// The water base terrains, i.e. [`BaseTerrain::Ocean`], [`BaseTerrain::Coast`] and [`BaseTerrain::Lake`], are not counted.
+    /// When several base terrains have the same count, the one declared first in [`BaseTerrain`] is returned.
+    ///
+    /// Returns `None` if [`Region::measure_terrain`] has not been called, or the region has no land tile.
+    pub fn dominant_base_terrain(&self) -> Option<BaseTerrain> {
+        let terrain_statistic = self.terrain_statistic.get()?;
+
+        terrain_statistic
+            .base_terrain_count
+            .iter()
+            .filter(|&(base_terrain, &count)| {
+                count > 0
+                    && !matches!(
+                        base_terrain,
+                        BaseTerrain::Ocean | BaseTerrain::Coast | BaseTerrain::Lake
+                    )
+            })
+            .max_by_key(|&(base_terrain, &count)| (count, Reverse(base_terrain.into_usize())))
+            .map(|(base_terrain, _)| base_terrain)
+    }
+
     /// Get the region of the landmass according to the given `area_id`.
     ///
     /// # Notes
@@ -673,7 +672,7 @@ impl Region {
         Self::new(rectangle, Some(area_id), fertility_list)
     }
 
-    fn rectangle_region(tile_map: &TileMap, grid: HexGrid, rectangle: Rectangle) -> Self {
+    fn rectangle_region(tile_map: &TileMap, grid: MapGrid, rectangle: Rectangle) -> Self {
         let fertility_list = tile_map.measure_start_placement_fertility_in_rectangle(rectangle);
 
         let mut region = Self::new(rectangle, None, fertility_list);
@@ -688,7 +687,7 @@ impl Region {
     /// If it is wider, we divide it into two left and right regions.
     /// The first region will have a fertility sum that is `chop_percent` percent of the total fertility sum of the region.
     /// The second region will have the remaining fertility sum.
-    fn chop_into_two_regions(&self, grid: HexGrid, chop_percent: f32) -> (Region, Region) {
+    fn chop_into_two_regions(&self, grid: MapGrid, chop_percent: f32) -> (Region, Region) {
         // Now divide the region.
         let target_fertility = (self.fertility_sum as f32 * chop_percent / 100.) as i32;
 
@@ -856,7 +855,7 @@ impl Region {
     ///
     /// We don't need to call [`Region::remove_dead_row_and_column`] in this function,
     /// because the function has been called in [`Region::chop_into_two_regions`] function.
-    fn chop_into_three_regions(&self, grid: HexGrid) -> (Region, Region, Region) {
+    fn chop_into_three_regions(&self, grid: MapGrid) -> (Region, Region, Region) {
         let (first_section_region, remaining_region) = self.chop_into_two_regions(grid, 33.3);
 
         let (second_section_region, third_section_region) =
@@ -871,7 +870,7 @@ impl Region {
 
     // function AssignStartingPlots:RemoveDeadRows
     /// Removes the edge rows and columns of the region where all tiles' fertility is 0.
-    fn remove_dead_row_and_column(&mut self, grid: HexGrid) {
+    fn remove_dead_row_and_column(&mut self, grid: MapGrid) {
         let width = self.rectangle.width();
         let height = self.rectangle.height();
 
@@ -1036,7 +1035,9 @@ impl Region {
     }
 
     /// Determines region type based on [Region::terrain_statistic] and sets [Region::region_type] field.
-    pub fn determine_region_type(&mut self) {
+    ///
+    /// When `hill_as_region_type` is `false`, the region will never be classified as [`RegionType::Hill`].
+    pub fn determine_region_type(&mut self, hill_as_region_type: bool) {
         let terrain_statistic = self.terrain_statistic.get().unwrap();
         let terrain_type_count = &terrain_statistic.terrain_type_count;
         let base_terrain_count = &terrain_statistic.base_terrain_count;
@@ -1087,8 +1088,8 @@ impl Region {
         else if base_terrain_count[BaseTerrain::Desert] >= threshold_25 {
             region_type = RegionType::Desert;
         }
-        // Hill: Hill >= 41.5% of buildable terrain
-        else if terrain_type_count[TerrainType::Hill] >= threshold_415 {
+        // Hill: Hill >= 41.5% of buildable terrain, only when hills count as their own region type
+        else if hill_as_region_type && terrain_type_count[TerrainType::Hill] >= threshold_415 {
             region_type = RegionType::Hill;
         }
         // Plain: Plain >= 30% AND Plain * 0.7 > Grassland
@@ -1157,3 +1158,100 @@ pub struct StartLocationCondition {
     /// NOTICE: This is only check the number of jungle tiles in 2-tile radius of the start location, not contain the start location itself.
     pub jungle_count: i32,
 }
+
+/// The food, hammer and bonus resource tallies of the starting tile of a region.
+///
+/// The tallies are computed in [`TileMap::normalize_start_tile_of_civilization`] before the starting tile is balanced,
+/// so they reflect how good the starting tile is originally.
+/// The inner ring is the tiles at distance 1 from the starting tile, the outer ring is the tiles at distance 2.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RegionStartScore {
+    /// The food score of the inner ring.
+    pub inner_food_score: i32,
+    /// The food score of the outer ring.
+    pub outer_food_score: i32,
+    /// The hammer score of the inner ring.
+    pub inner_hammer_score: i32,
+    /// The hammer score of the outer ring.
+    pub outer_hammer_score: i32,
+    /// The hammer score which is available in the early game, from forests and tiles yielding 1 hammer.
+    pub early_hammer_score: i32,
+    /// The number of tiles which can have a bonus resource in the inner ring.
+    pub inner_can_have_bonus: i32,
+    /// The number of tiles which can have a bonus resource in the outer ring.
+    pub outer_can_have_bonus: i32,
+    /// The number of bad tiles in the inner ring, such as snow, or desert and tundra without river.
+    pub inner_bad_tiles: i32,
+    /// The number of bad tiles in the outer ring, such as snow, or desert and tundra without river.
+    pub outer_bad_tiles: i32,
+    /// The number of food bonus resources the starting tile needs to be balanced.
+    pub num_food_bonus_needed: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map,
+        map_parameters::{MapParametersBuilder, WorldAge, WorldGrid},
+        ruleset::RegionType,
+        test_utils::test_map_parameters,
+        tile::Tile,
+    };
+
+    /// Tests that no region is classified as [`RegionType::Hill`] when `hill_as_region_type` is `false`.
+    #[test]
+    fn test_hill_as_region_type_disabled() {
+        for seed in 0..5 {
+            // New world age generates more hills.
+            let map_parameters = MapParametersBuilder::new(WorldGrid::default())
+                .seed(seed)
+                .world_age(WorldAge::New)
+                .hill_as_region_type(false)
+                .build();
+            let map = generate_map(&map_parameters);
+
+            assert!(
+                map.region_list()
+                    .iter()
+                    .all(|region| region.region_type != RegionType::Hill),
+                "No region should be classified as hill"
+            );
+        }
+    }
+
+    /// Tests that the regions divided from the whole map rectangle cover all the land of the map together,
+    /// and that they ignore the area IDs.
+    #[test]
+    fn test_whole_map_rectangle_regions() {
+        use crate::map_parameters::RegionDivideMethod;
+        use std::collections::HashSet;
+
+        let map_parameters = test_map_parameters()
+            .region_divide_method(RegionDivideMethod::WholeMapRectangle)
+            .build();
+        let map = generate_map(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        assert_eq!(
+            map.region_list().len(),
+            map_parameters.civilization_list.len()
+        );
+        assert!(
+            map.region_list()
+                .iter()
+                .all(|region| region.area_id.is_none())
+        );
+
+        let covered_tiles: HashSet<Tile> = map
+            .region_list()
+            .iter()
+            .flat_map(|region| region.rectangle.all_cells(&grid).map(Tile::from_cell))
+            .collect();
+        // Only the dead rows and columns without fertility, which are water here, are cut from the edges of the regions.
+        assert!(
+            map.all_tiles()
+                .filter(|tile| tile.is_land(&map))
+                .all(|tile| covered_tiles.contains(&tile))
+        );
+    }
+}
@@ -72,7 +72,7 @@ impl TileMap {
 
         let grid = self.world_grid.grid;
 
-        let continents_fractal = self.continents_fractal();
+        let continents_fractal = self.continents_fractal(map_parameters);
 
         let flags = FractalFlags::empty();
 
@@ -96,7 +96,7 @@ impl TileMap {
 
         hills_fractal.ridge_builder(&mut self.random_number_generator, num_plates, flags, 1, 2);
 
-        let [water_threshold] = continents_fractal.height_thresholds_from_percents([water_percent]);
+        let water_threshold = continents_fractal.height_threshold_from_percent(water_percent);
 
         let [
             pass_threshold,
@@ -142,6 +142,8 @@ impl TileMap {
             let mountain_height = mountains_fractal.height(x, y);
             let hill_height = hills_fractal.height(x, y);
 
+            tile.set_elevation(self, height as u8);
+
             if height <= water_threshold {
                 tile.set_terrain_type(self, TerrainType::Water);
                 // No hills or mountains here, but check for tectonic islands if that setting is active.
@@ -171,11 +173,67 @@ impl TileMap {
                 tile.set_terrain_type(self, TerrainType::Flatland);
             };
         });
+
+        self.apply_hills_percent(map_parameters, &hills_fractal);
     }
 
-    pub fn continents_fractal(&mut self) -> CvFractal<HexGrid> {
-        // TODO: This should be as a customizable parameter of map in the future
-        let continent_grain = 2;
+    /// Reassigns the hills on the land according to [`MapParameters::hills_percent`].
+    ///
+    /// The non-mountain land tiles are sorted by their heights in `hills_fractal`,
+    /// the highest ones become [`TerrainType::Hill`] until the fraction of all the land tiles is reached,
+    /// and the others become [`TerrainType::Flatland`]. The mountains are never changed.
+    ///
+    /// It does nothing when [`MapParameters::hills_percent`] is [`MapParameters::HILLS_PERCENT_BY_WORLD_AGE`].
+    /// It is not called again by the generators which reshape the land after [`TileMap::generate_terrain_types`],
+    /// see [`MapParameters::hills_percent`] for the map types it applies to.
+    pub fn apply_hills_percent(
+        &mut self,
+        map_parameters: &MapParameters,
+        hills_fractal: &CvFractal<MapGrid>,
+    ) {
+        let hills_percent = map_parameters.hills_percent;
+        if hills_percent == MapParameters::HILLS_PERCENT_BY_WORLD_AGE {
+            return;
+        }
+
+        let grid = self.world_grid.grid;
+
+        let land_count = self.all_tiles().filter(|tile| tile.is_land(self)).count();
+
+        let mut candidate_tiles: Vec<_> = self
+            .all_tiles()
+            .filter(|tile| {
+                matches!(
+                    tile.terrain_type(self),
+                    TerrainType::Hill | TerrainType::Flatland
+                )
+            })
+            .map(|tile| {
+                let [x, y] = tile.to_offset(grid).to_array();
+                (tile, hills_fractal.height(x as u32, y as u32))
+            })
+            .collect();
+
+        // Sort by height in descending order, ties are broken by the tile index to keep the result deterministic.
+        candidate_tiles.sort_by_key(|&(tile, height)| (std::cmp::Reverse(height), tile));
+
+        let num_hills = (land_count as f64 * hills_percent).round() as usize;
+
+        for (i, (tile, _)) in candidate_tiles.into_iter().enumerate() {
+            if i < num_hills {
+                tile.set_terrain_type(self, TerrainType::Hill);
+            } else {
+                tile.set_terrain_type(self, TerrainType::Flatland);
+            }
+        }
+    }
+
+    /// Creates the continents fractal, which decides the land and water of the map.
+    ///
+    /// Its grain and resolution are [`MapParameters::fractal_grain`] and [`MapParameters::fractal_exp`].
+    pub fn continents_fractal(&mut self, map_parameters: &MapParameters) -> CvFractal<MapGrid> {
+        let continent_grain = u32::from(map_parameters.fractal_grain);
+        let fractal_exp = map_parameters.fractal_exp;
 
         // TODO: This should be as a customizable parameter of map in the future
         //
@@ -215,10 +273,7 @@ impl TileMap {
 
                 // Step 1: create the rift grid with `wrap_flags` set to `WrapFlags::empty()`,
                 //         other fields are the same as `grid`.
-                let rift_grid = HexGrid {
-                    wrap_flags: WrapFlags::empty(),
-                    ..grid
-                };
+                let rift_grid = grid.with_wrap_flags(WrapFlags::empty());
 
                 // Step 2: create the rift fractal with `rift_grid`,
                 //         the argument `flags` of the funtion `CvFractal::new()` is always set to `FractalFlags::empty()`.
@@ -230,12 +285,14 @@ impl TileMap {
                 CvFractalBuilder::new(grid)
                     .grain(continent_grain)
                     .flags(flags)
+                    .fractal_exp(fractal_exp)
                     .rift_fractal(&rift_fractal)
                     .build(&mut self.random_number_generator)
             }
             _ => CvFractalBuilder::new(grid)
                 .grain(continent_grain)
                 .flags(flags)
+                .fractal_exp(fractal_exp)
                 .build(&mut self.random_number_generator),
         };
 
@@ -252,3 +309,64 @@ impl TileMap {
         continents_fractal
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map,
+        grid::{GridSize, HexGrid, WorldSizeType},
+        map_parameters::{MapParametersBuilder, WorldGrid},
+        ruleset::enums::TerrainType,
+        test_utils::{TEST_SEED, test_map_parameters},
+    };
+
+    /// Tests that a higher fractal grain breaks the land into more and smaller landmasses.
+    #[test]
+    fn test_fractal_grain() {
+        use crate::tile_map::LandmassType;
+
+        let landmass_count_and_average_size = |fractal_grain: u8| {
+            let map_parameters = test_map_parameters().fractal_grain(fractal_grain).build();
+            let map = generate_map(&map_parameters);
+
+            let land_sizes: Vec<u32> = map
+                .landmass_list
+                .iter()
+                .filter(|landmass| landmass.landmass_type == LandmassType::Land)
+                .map(|landmass| landmass.size)
+                .collect();
+            let count = land_sizes.len();
+            (count, land_sizes.iter().sum::<u32>() as f64 / count as f64)
+        };
+
+        let (default_count, default_average_size) = landmass_count_and_average_size(2);
+        let (count, average_size) = landmass_count_and_average_size(5);
+
+        assert!(count > default_count);
+        assert!(average_size < default_average_size);
+    }
+
+    /// Tests that about half of the land becomes hills when the hills percent is `0.5`.
+    #[test]
+    fn test_hills_percent() {
+        let world_size = WorldSizeType::Large;
+        let grid = HexGrid {
+            size: HexGrid::default_size(world_size),
+            ..HexGrid::default()
+        };
+        let map_parameters = MapParametersBuilder::new(WorldGrid::new(grid, world_size))
+            .seed(TEST_SEED)
+            .hills_percent(0.5)
+            .build();
+        let map = generate_map(&map_parameters);
+
+        let land_count = map.land_tile_count();
+        let hill_count = map.tiles_with_terrain_type(TerrainType::Hill).count();
+        let hills_ratio = hill_count as f64 / land_count as f64;
+
+        assert!(
+            (hills_ratio - 0.5).abs() < 0.05,
+            "hills ratio: {hills_ratio}"
+        );
+    }
+}
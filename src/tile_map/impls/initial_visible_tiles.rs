@@ -0,0 +1,136 @@
+use crate::{
+    grid::Grid,
+    ruleset::enums::{Feature, TerrainType},
+    tile::Tile,
+    tile_map::TileMap,
+};
+
+impl TileMap {
+    /// Returns all tiles which can be seen from `civ_start` within `sight_radius` at the start of the game.
+    ///
+    /// This is the fog-of-war reveal at spawn. A tile is visible if it is within `sight_radius` of `civ_start`,
+    /// and no tile on the straight line between them blocks the sight.
+    /// A tile blocks the sight when its sight height is greater than the sight height of `civ_start`,
+    /// the sight height is computed as follows:
+    /// - [`TerrainType::Mountain`] is always higher than any other tile, so it always blocks the sight.
+    /// - [`TerrainType::Hill`] adds 1 to the sight height.
+    /// - [`Feature::Forest`] and [`Feature::Jungle`] add 1 to the sight height.
+    ///
+    /// The blocking tiles themselves are visible, only the tiles behind them are hidden.
+    /// The tiles adjacent to `civ_start` are always visible.
+    ///
+    /// The returned tiles include `civ_start` and are sorted by the tile index.
+    pub fn initial_visible_tiles(&self, civ_start: Tile, sight_radius: u32) -> Vec<Tile> {
+        let start_sight_height = self.sight_height(civ_start);
+
+        self.line_of_sight_tiles(civ_start, sight_radius, |tile| {
+            self.sight_height(tile) > start_sight_height
+        })
+    }
+
+    /// Returns all tiles within `range` of `from` which are not hidden behind a tile satisfying `blocks_sight`.
+    ///
+    /// A tile is hidden if any tile on the straight line between `from` and it (see [`MapGrid::line`](crate::grid::MapGrid::line)),
+    /// excluding both ends, blocks the sight. So the blocking tiles themselves are visible, and the tiles adjacent to `from` are always visible.
+    ///
+    /// It is the line of sight shared by [`TileMap::initial_visible_tiles`] and [`Tile::visible_tiles`].
+    /// The returned tiles include `from` and are sorted by the tile index.
+    pub(crate) fn line_of_sight_tiles(
+        &self,
+        from: Tile,
+        range: u32,
+        blocks_sight: impl Fn(Tile) -> bool,
+    ) -> Vec<Tile> {
+        let grid = self.world_grid.grid;
+
+        let start = from.to_offset(grid);
+
+        let to_tile = |offset_coordinate| {
+            grid.grid_coordinate_to_cell(offset_coordinate)
+                .map(Tile::from_cell)
+        };
+
+        let mut visible_tiles: Vec<Tile> =
+            grid.offsets_within_distance(start, range)
+                .into_iter()
+                .filter(|&offset_coordinate| {
+                    // The tiles out of the map are not visible.
+                    if to_tile(offset_coordinate).is_none() {
+                        return false;
+                    }
+                    // Check the tiles between `from` and the target tile, excluding both ends.
+                    let line = grid.line(start, offset_coordinate);
+                    line.iter().skip(1).take(line.len().saturating_sub(2)).all(
+                        |&offset_coordinate| {
+                            to_tile(offset_coordinate).is_some_and(|tile| !blocks_sight(tile))
+                        },
+                    )
+                })
+                .filter_map(to_tile)
+                .collect();
+
+        // When the map wraps and `range` is large, different offset coordinates may refer to the same tile.
+        visible_tiles.sort_unstable();
+        visible_tiles.dedup();
+
+        visible_tiles
+    }
+
+    /// Returns the sight height of the tile, which is used in [`TileMap::initial_visible_tiles`].
+    fn sight_height(&self, tile: Tile) -> u32 {
+        match tile.terrain_type(self) {
+            TerrainType::Mountain => u32::MAX,
+            terrain_type => {
+                let mut sight_height = 0;
+                if terrain_type == TerrainType::Hill {
+                    sight_height += 1;
+                }
+                if matches!(tile.feature(self), Some(Feature::Forest | Feature::Jungle)) {
+                    sight_height += 1;
+                }
+                sight_height
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ruleset::enums::TerrainType, test_utils::test_map_parameters, tile::Tile, tile_map::TileMap,
+    };
+
+    /// Tests that a mountain between the start and a far tile blocks the visibility of the tiles behind it.
+    #[test]
+    fn test_initial_visible_tiles() {
+        use crate::grid::{Direction, OffsetCoordinate};
+
+        let map_parameters = test_map_parameters().build();
+        let mut map = TileMap::new(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        let start = Tile::from_offset(OffsetCoordinate::new(20, 20), grid);
+        for tile in start.tiles_in_distance(3, grid) {
+            tile.set_terrain_type(&mut map, TerrainType::Flatland);
+        }
+
+        let direction = Direction::East;
+        let near_tile = start.neighbor_tile(direction, grid).unwrap();
+        let far_tile = near_tile.neighbor_tile(direction, grid).unwrap();
+        let farther_tile = far_tile.neighbor_tile(direction, grid).unwrap();
+
+        let visible_tiles = map.initial_visible_tiles(start, 3);
+        assert_eq!(
+            visible_tiles.len(),
+            start.tiles_in_distance(3, grid).count()
+        );
+
+        near_tile.set_terrain_type(&mut map, TerrainType::Mountain);
+
+        let visible_tiles = map.initial_visible_tiles(start, 3);
+        assert!(visible_tiles.contains(&start));
+        assert!(visible_tiles.contains(&near_tile));
+        assert!(!visible_tiles.contains(&far_tile));
+        assert!(!visible_tiles.contains(&farther_tile));
+    }
+}
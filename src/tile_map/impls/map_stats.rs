@@ -0,0 +1,50 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    ruleset::enums::{Resource, TerrainType},
+    tile_map::{ResourceStats, TileMap, WaterBodyKind},
+};
+
+impl TileMap {
+    /// Returns the statistic of the map, which helps to tune the map parameters.
+    ///
+    /// All the values are computed from the map, see [`MapStats`] for more information.
+    /// It should be called after the map is generated, otherwise some values may be `0` or empty.
+    pub fn map_stats(&self) -> MapStats {
+        MapStats {
+            land_ratio: self.land_tile_count() as f64 / self.all_tiles().count() as f64,
+            lake_count: self
+                .water_body_list
+                .iter()
+                .filter(|water_body| water_body.kind == WaterBodyKind::Lake)
+                .count() as u32,
+            river_count: self.river_list.len() as u32,
+            mountain_count: self.tiles_with_terrain_type(TerrainType::Mountain).count() as u32,
+            region_count: self.region_list.len() as u32,
+            civilization_count: self.starting_tile_and_civilization.len() as u32,
+            city_state_count: self.starting_tile_and_city_state.len() as u32,
+            resource_summary: self.resource_summary(),
+        }
+    }
+}
+
+/// The statistic of a generated map. See [`TileMap::map_stats`].
+#[derive(PartialEq, Clone, Debug)]
+pub struct MapStats {
+    /// The ratio of the land tiles to all the tiles, in the range of **[0.0, 1.0]**.
+    pub land_ratio: f64,
+    /// The number of the water bodies which are lakes, see [`WaterBodyKind::Lake`].
+    pub lake_count: u32,
+    /// The number of rivers in [`TileMap::river_list`].
+    pub river_count: u32,
+    /// The number of [`TerrainType::Mountain`] tiles.
+    pub mountain_count: u32,
+    /// The number of regions the map is divided into.
+    pub region_count: u32,
+    /// The number of civilizations which have a starting tile.
+    pub civilization_count: u32,
+    /// The number of city states which have a starting tile.
+    pub city_state_count: u32,
+    /// The statistic of every resource placed on the map, see [`TileMap::resource_summary`].
+    pub resource_summary: BTreeMap<Resource, ResourceStats>,
+}
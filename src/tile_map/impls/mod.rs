@@ -2,32 +2,56 @@
 
 mod add_features;
 mod add_rivers;
+mod apply_symmetry;
 mod assign_luxury_roles;
 mod balance_and_assign_start_locations_of_civilization;
 mod choose_starting_tiles_of_civilization;
+mod content_hash;
 mod fix_sugar_jungles;
 mod generate_area_and_landmass;
 mod generate_base_terrains;
+mod generate_equatorial_ocean;
 mod generate_lakes;
 mod generate_natural_wonders;
 mod generate_regions;
 mod generate_terrain_types;
+mod initial_visible_tiles;
+mod map_stats;
 mod place_city_states;
 mod place_resources;
+mod regenerate_region;
+mod regenerate_resources;
+mod remove_tiny_islands;
+mod resource_summary;
+mod separate_continents;
 mod shift_terrain_types;
+mod to_ascii;
+mod validate_rivers;
 
 pub(crate) use add_features::*;
 pub(crate) use add_rivers::*;
+pub(crate) use apply_symmetry::*;
 pub(crate) use assign_luxury_roles::*;
 pub(crate) use balance_and_assign_start_locations_of_civilization::*;
 pub(crate) use choose_starting_tiles_of_civilization::*;
+pub(crate) use content_hash::*;
 pub(crate) use fix_sugar_jungles::*;
 pub(crate) use generate_area_and_landmass::*;
 pub(crate) use generate_base_terrains::*;
+pub(crate) use generate_equatorial_ocean::*;
 pub(crate) use generate_lakes::*;
 pub(crate) use generate_natural_wonders::*;
 pub(crate) use generate_regions::*;
 pub(crate) use generate_terrain_types::*;
+pub(crate) use initial_visible_tiles::*;
+pub(crate) use map_stats::*;
 pub(crate) use place_city_states::*;
 pub(crate) use place_resources::*;
+pub(crate) use regenerate_region::*;
+pub(crate) use regenerate_resources::*;
+pub(crate) use remove_tiny_islands::*;
+pub(crate) use resource_summary::*;
+pub(crate) use separate_continents::*;
 pub(crate) use shift_terrain_types::*;
+pub(crate) use to_ascii::*;
+pub(crate) use validate_rivers::*;
@@ -58,6 +58,9 @@ impl TileMap {
                 } else {
                     num_city_states_discarded += 1;
                 }
+            } else if region_index.is_none() && self.region_list.is_empty() {
+                // There is no region to place the city state on.
+                num_city_states_discarded += 1;
             } else if region_index.is_none() && num_uninhabited_candidate_tiles == 0 {
                 // Place city state on a random region
                 let region_index = self
@@ -271,8 +274,7 @@ impl TileMap {
                 continue;
             }
 
-            let mut candidate_list = candidate_list.clone();
-            candidate_list.shuffle(&mut self.random_number_generator);
+            let mut candidate_list = self.shuffled(candidate_list);
             if check_collision {
                 // Place city state, avoiding collision
                 for tile in candidate_list {
@@ -323,6 +325,25 @@ impl TileMap {
         let mut uninhabited_areas_coastal_land_tiles = Vec::new();
         let mut uninhabited_areas_inland_tiles = Vec::new();
 
+        // A map without civilizations has no regions, so all the city states are placed on the uninhabited land, which is the whole map.
+        if self.region_list.is_empty() {
+            self.all_tiles().for_each(|tile| {
+                if tile.can_be_city_state_starting_tile(self, None) {
+                    if tile.is_coastal_land(self) {
+                        uninhabited_areas_coastal_land_tiles.push(tile);
+                    } else {
+                        uninhabited_areas_inland_tiles.push(tile);
+                    }
+                }
+            });
+
+            return CityStatesAssignment {
+                region_index_assignment: vec![None; num_city_states as usize],
+                uninhabited_areas_coastal_land_tiles,
+                uninhabited_areas_inland_tiles,
+            };
+        }
+
         /***** Assign the "Per Region" City States to their regions ******/
         let ratio = num_city_states as f64
             / map_parameters.world_size_type_profile.num_civilizations as f64;
@@ -939,3 +960,51 @@ struct CityStatesAssignment {
     /// inland areas. The tiles should be valid for city state placement.
     uninhabited_areas_inland_tiles: Vec<Tile>,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_map, test_utils::test_map_parameters, tile::Tile};
+
+    /// Tests that there is a start for each civilization and each city state.
+    #[test]
+    fn test_civilization_and_city_state_starts() {
+        let map_parameters = test_map_parameters().build();
+        let map = generate_map(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        let civilization_starts = map.civilization_starts();
+        assert_eq!(
+            civilization_starts.len(),
+            map_parameters.civilization_list.len()
+        );
+        for (offset_coordinate, civilization) in civilization_starts {
+            let tile = Tile::from_offset(offset_coordinate, grid);
+            assert_eq!(map.starting_tile_and_civilization[&tile], civilization);
+        }
+
+        assert_eq!(
+            map.city_state_starts().len(),
+            map.starting_tile_and_city_state.len()
+        );
+    }
+
+    /// Tests that the number of city states can be overridden.
+    #[test]
+    fn test_city_state_count() {
+        for city_state_count in [0, 3] {
+            let map_parameters = test_map_parameters()
+                .city_state_count(city_state_count)
+                .build();
+            assert_eq!(
+                map_parameters.city_state_list.len(),
+                city_state_count as usize
+            );
+
+            let map = generate_map(&map_parameters);
+            assert_eq!(
+                map.starting_tile_and_city_state.len(),
+                city_state_count as usize
+            );
+        }
+    }
+}
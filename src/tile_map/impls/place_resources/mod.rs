@@ -1,7 +1,7 @@
 use crate::{
-    ruleset::enums::*,
+    ruleset::{Ruleset, enums::*},
     tile::Tile,
-    tile_map::{Layer, TileMap},
+    tile_map::{Layer, PlacementError, TileMap},
 };
 use rand::{
     Rng, RngExt,
@@ -64,7 +64,7 @@ impl TileMap {
             .collect::<Vec<_>>();
         let dist = WeightedIndex::new(resource_weight).unwrap();
 
-        let num_resources_to_place = (tile_list.len() as u32).div_ceil(frequency);
+        let num_resources_to_place = (tile_list.len() as u32).div_ceil(frequency.max(1));
 
         let mut tile_list_iter = tile_list.iter();
 
@@ -253,6 +253,40 @@ impl TileMap {
             region_forest_flat_but_not_tundra_tile_list,
         ]
     }
+
+    /// Sets `resource` with `quantity` on `tile`, which is useful for scenario designers.
+    ///
+    /// Unlike [`Tile::set_resource`], the tile must meet one of the required terrains of the resource in `ruleset`,
+    /// see [`Tile::meets_required_terrain`]. The resource already on the tile is replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlacementError::Occupied`] if the tile has a natural wonder,
+    /// and [`PlacementError::IneligibleResource`] if the tile doesn't meet any of the required terrains of the resource, e.g. `Fish` on land.
+    /// The map is not changed when an error is returned.
+    pub fn set_resource_checked(
+        &mut self,
+        tile: Tile,
+        resource: Resource,
+        quantity: u32,
+        ruleset: &Ruleset,
+    ) -> Result<(), PlacementError> {
+        if tile.natural_wonder(self).is_some() {
+            return Err(PlacementError::Occupied(tile));
+        }
+
+        if !ruleset.resources[resource]
+            .required_terrain
+            .iter()
+            .any(|required_terrain| tile.meets_required_terrain(self, required_terrain))
+        {
+            return Err(PlacementError::IneligibleResource(tile, resource));
+        }
+
+        tile.set_resource(self, resource, quantity);
+
+        Ok(())
+    }
 }
 
 struct ResourceToPlace {
@@ -266,3 +300,183 @@ struct ResourceToPlace {
     /// - `min_radius` should >= `max_radius`.
     pub radius_range: (u32, u32),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ResourceToPlace;
+    use crate::{
+        generate_map,
+        grid::OffsetCoordinate,
+        map_parameters::{MapParametersBuilder, RippleConfig, WorldGrid},
+        ruleset::enums::{BaseTerrain, Resource},
+        test_utils::test_map_parameters,
+        tile::Tile,
+        tile_map::{Layer, TileMap},
+    };
+    use rand::seq::SliceRandom;
+
+    fn map_with_ripple_config(ripple_config: RippleConfig) -> TileMap {
+        let map_parameters = test_map_parameters().ripple_config(ripple_config).build();
+        TileMap::new(&map_parameters)
+    }
+
+    /// Tests the ripple values where the ripples of 2 resources overlap.
+    #[test]
+    fn test_ripple_config_overlap_value() {
+        // Returns the ripple values of a tile within the ripples of 2 resources, and a tile within the ripple of 1 resource.
+        let ripple_values = |ripple_config: RippleConfig| {
+            let mut map = map_with_ripple_config(ripple_config);
+            let grid = map.world_grid.grid;
+            let tile_at = |x, y| Tile::from_offset(OffsetCoordinate::new(x, y), grid);
+
+            map.place_impact_and_ripples(tile_at(10, 10), Layer::Strategic, 3);
+            map.place_impact_and_ripples(tile_at(12, 10), Layer::Strategic, 3);
+
+            (
+                map.impact_value(tile_at(11, 10), Layer::Strategic),
+                map.impact_value(tile_at(8, 10), Layer::Strategic),
+            )
+        };
+
+        let (overlap_value, single_value) = ripple_values(RippleConfig::default());
+        assert_eq!(overlap_value, 5);
+        assert_eq!(single_value, 2);
+
+        let (overlap_value, single_value) = ripple_values(RippleConfig {
+            overlap_increment: 0,
+            overlap_cap: 2,
+        });
+        assert_eq!(overlap_value, 2);
+        assert_eq!(single_value, 2);
+    }
+
+    /// Tests that the resources cluster more densely when the overlapping ripples are not stronger than a single ripple.
+    #[test]
+    fn test_ripple_config_clustering() {
+        // Places more resources than the tiles outside of any ripple, and returns the number of adjacent resource pairs.
+        let num_adjacent_resource_pairs = |ripple_config: RippleConfig| {
+            let mut map = map_with_ripple_config(ripple_config);
+            let grid = map.world_grid.grid;
+
+            let mut tile_list: Vec<Tile> = (10..22)
+                .flat_map(|y| {
+                    (10..22).map(move |x| Tile::from_offset(OffsetCoordinate::new(x, y), grid))
+                })
+                .collect();
+            tile_list.shuffle(&mut map.random_number_generator);
+
+            let resource_list_to_place = [ResourceToPlace {
+                resource: Resource::Wheat,
+                quantity: 1,
+                weight: 1,
+                radius_range: (1, 1),
+            }];
+            map.process_resource_list(3, Layer::Bonus, &tile_list, &resource_list_to_place);
+
+            let resource_tiles: Vec<Tile> = tile_list
+                .into_iter()
+                .filter(|tile| tile.resource(&map).is_some())
+                .collect();
+            resource_tiles
+                .iter()
+                .map(|tile| {
+                    tile.neighbor_tiles(grid)
+                        .filter(|neighbor_tile| resource_tiles.contains(neighbor_tile))
+                        .count()
+                })
+                .sum::<usize>()
+                / 2
+        };
+
+        let default_pairs = num_adjacent_resource_pairs(RippleConfig::default());
+        let flat_pairs = num_adjacent_resource_pairs(RippleConfig {
+            overlap_increment: 0,
+            overlap_cap: 2,
+        });
+        assert!(
+            flat_pairs > default_pairs,
+            "Flat ripples should cluster the resources more densely: {flat_pairs} adjacent pairs vs {default_pairs} by default."
+        );
+    }
+
+    /// Tests that [`MapParametersBuilder::build`] rejects an overlap cap which would block the placement of the resources.
+    #[test]
+    #[should_panic(expected = "must be less than 98")]
+    fn test_ripple_config_overlap_cap_too_high() {
+        MapParametersBuilder::new(WorldGrid::default())
+            .ripple_config(RippleConfig {
+                overlap_increment: 2,
+                overlap_cap: 98,
+            })
+            .build();
+    }
+
+    /// Tests that [`ResourceSetting::Custom`] with a bonus multiplier of 0 places far fewer bonus resources than [`ResourceSetting::Standard`].
+    ///
+    /// [`ResourceSetting::Custom`]: crate::map_parameters::ResourceSetting::Custom
+    /// [`ResourceSetting::Standard`]: crate::map_parameters::ResourceSetting::Standard
+    #[test]
+    fn test_custom_resource_setting() {
+        use crate::{
+            map_parameters::{ResourceMultiplier, ResourceSetting},
+            ruleset::ResourceCategory,
+        };
+
+        let bonus_tile_count = |resource_setting| {
+            let map_parameters = test_map_parameters()
+                .resource_setting(resource_setting)
+                .build();
+            let map = generate_map(&map_parameters);
+            map.resource_summary_by_type()[&ResourceCategory::Bonus].tile_count
+        };
+
+        let standard = bonus_tile_count(ResourceSetting::Standard);
+        let custom = bonus_tile_count(ResourceSetting::Custom {
+            strategic_multiplier: ResourceMultiplier::ONE,
+            luxury_multiplier: ResourceMultiplier::ONE,
+            bonus_multiplier: ResourceMultiplier::from_per_mille(0),
+        });
+        assert!(
+            custom * 2 < standard,
+            "custom: {custom}, standard: {standard}"
+        );
+    }
+
+    /// Tests that a resource is only set on a tile which meets one of its required terrains.
+    #[test]
+    fn test_set_resource_checked() {
+        use crate::{ruleset::enums::Resource, tile_map::PlacementError};
+
+        let map_parameters = test_map_parameters().build();
+        let mut map = generate_map(&map_parameters);
+        let ruleset = &map_parameters.ruleset;
+
+        let find_tile = |base_terrain: BaseTerrain| {
+            map.all_tiles()
+                .find(|tile| {
+                    tile.base_terrain(&map) == base_terrain
+                        && tile.feature(&map).is_none()
+                        && tile.natural_wonder(&map).is_none()
+                })
+                .unwrap()
+        };
+        let grassland_tile = find_tile(BaseTerrain::Grassland);
+        let coast_tile = find_tile(BaseTerrain::Coast);
+        let grassland_resource = grassland_tile.resource(&map);
+
+        assert_eq!(
+            map.set_resource_checked(grassland_tile, Resource::Fish, 1, ruleset),
+            Err(PlacementError::IneligibleResource(
+                grassland_tile,
+                Resource::Fish
+            ))
+        );
+        assert_eq!(grassland_tile.resource(&map), grassland_resource);
+
+        assert_eq!(
+            map.set_resource_checked(coast_tile, Resource::Fish, 1, ruleset),
+            Ok(())
+        );
+        assert_eq!(coast_tile.resource(&map), Some((Resource::Fish, 1)));
+    }
+}
@@ -15,6 +15,11 @@ impl TileMap {
             ResourceSetting::Sparse => 1.5,
             // Abundant, so reduce the number of tiles per bonus.
             ResourceSetting::Abundant => 2.0 / 3.0,
+            // Custom, so divide the number of tiles per bonus by the multiplier.
+            // When the multiplier is 0, the frequency saturates to `u32::MAX`.
+            ResourceSetting::Custom {
+                bonus_multiplier, ..
+            } => 1.0 / bonus_multiplier.as_f64(),
             _ => 1.0,
         };
 
@@ -562,7 +567,7 @@ impl TileMap {
             return;
         }
 
-        let num_fish_to_place = (coast_list.len() as u32).div_ceil(frequency);
+        let num_fish_to_place = (coast_list.len() as u32).div_ceil(frequency.max(1));
 
         // Keep track of how many fish have been placed.
         let mut placed_count = 0;
@@ -1,7 +1,7 @@
 use crate::{
     grid::*,
     map_parameters::{MapParameters, ResourceSetting},
-    ruleset::{Ruleset, enums::*},
+    ruleset::{ResourceCategory, Ruleset, enums::*},
     tile::Tile,
     tile_map::{Layer, TileMap},
 };
@@ -22,7 +22,6 @@ impl TileMap {
     ///
     /// Before running this function, [`TileMap::assign_luxury_roles`] function must be run.
     pub fn place_luxury_resources(&mut self, map_parameters: &MapParameters) {
-        let ruleset = &map_parameters.ruleset;
         let grid = self.world_grid.grid;
 
         let world_size = self.world_grid.world_size_type;
@@ -316,6 +315,9 @@ impl TileMap {
             match map_parameters.resource_setting {
                 ResourceSetting::Sparse => target_num -= 1,
                 ResourceSetting::Abundant => target_num += 1,
+                ResourceSetting::Custom {
+                    luxury_multiplier, ..
+                } => target_num = (target_num as f64 * luxury_multiplier.as_f64()).round() as i32,
                 _ => (),
             }
 
@@ -356,10 +358,14 @@ impl TileMap {
             // - It should be adjusted by the number of civilizations, and add a random number of luxuries according to the number of civilizations.
             let [target_luxury, loop_target] =
                 get_world_luxury_target_numbers(world_size, resource_setting);
-            let extra_luxury = self
-                .random_number_generator
-                .random_range(0..num_civilizations);
-            let num_placed_luxuries = self.num_placed_luxury_resources(ruleset);
+            // A map without civilizations gets no extra luxury.
+            let extra_luxury = if num_civilizations > 0 {
+                self.random_number_generator
+                    .random_range(0..num_civilizations)
+            } else {
+                0
+            };
+            let num_placed_luxuries = self.num_placed_luxury_resources();
             let num_random_luxury_target =
                 (target_luxury + extra_luxury).saturating_sub(num_placed_luxuries);
 
@@ -548,6 +554,10 @@ impl TileMap {
         /********** Process 6: Place Special Case Luxury Resources **********/
     }
 
+    /// Places Marble on the map.
+    ///
+    /// The number of Marble deposits is [`MapParameters::marble_count`] if it is set,
+    /// including the deposits already placed, e.g. at the civilization starts.
     fn place_marble(&mut self, map_parameters: &MapParameters) {
         let luxury = Resource::Marble;
         let marble_already_placed: u32 = self.placed_resource_count(luxury);
@@ -556,6 +566,9 @@ impl TileMap {
         let marble_target = match map_parameters.resource_setting {
             ResourceSetting::Sparse => (num_civilizations as f32 * 0.5).ceil() as i32,
             ResourceSetting::Abundant => (num_civilizations as f32 * 0.9).ceil() as i32,
+            ResourceSetting::Custom {
+                luxury_multiplier, ..
+            } => (num_civilizations as f64 * 0.75 * luxury_multiplier.as_f64()).ceil() as i32,
             _ => (num_civilizations as f32 * 0.75).ceil() as i32,
         };
 
@@ -599,9 +612,15 @@ impl TileMap {
             }
         });
 
-        let num_marble_to_place = max(2, marble_target - marble_already_placed as i32) as u32;
+        let num_marble_to_place = match map_parameters.marble_count {
+            Some(marble_count) => marble_count.saturating_sub(marble_already_placed),
+            None => max(2, marble_target - marble_already_placed as i32) as u32,
+        };
 
         let mut num_left_to_place = num_marble_to_place;
+        if num_marble_to_place == 0 {
+            return;
+        }
         if marble_tile_list.is_empty() {
             eprintln!("No eligible tiles available to place Marble!");
             return;
@@ -678,9 +697,9 @@ impl TileMap {
                         if base_terrain == BaseTerrain::Coast
                             && feature != Some(Feature::Ice)
                             && feature != Some(Feature::Atoll)
-                            && tile.neighbor_tiles(grid).any(|neighbor_tile| {
-                                neighbor_tile.terrain_type(self) != TerrainType::Water
-                            })
+                            && tile
+                                .neighbor_tiles(grid)
+                                .any(|neighbor_tile| neighbor_tile.is_land(self))
                         {
                             region_coast_next_to_land_tile_list.push(tile);
                         }
@@ -1075,10 +1094,9 @@ impl TileMap {
     }
 
     /// Returns the total quantity of luxury resources placed on the map.
-    fn num_placed_luxury_resources(&self, ruleset: &Ruleset) -> u32 {
-        (0..Resource::LENGTH)
-            .map(Resource::from_usize)
-            .filter(|&res| ruleset.resources[res].resource_type == "Luxury")
+    fn num_placed_luxury_resources(&self) -> u32 {
+        ResourceCategory::Luxury
+            .resources()
             .map(|res| self.placed_resource_count(res))
             .sum()
     }
@@ -1122,10 +1140,24 @@ fn get_region_luxury_target_numbers(
 /// The second number influences the minimum number of random luxuries that should be placed.
 /// It is important to note that it is just one factor in the formula for placing luxuries,
 /// meaning other elements (such as civilization count) also contribute to the final result.
+///
+/// For [`ResourceSetting::Custom`], the first number of [`ResourceSetting::Standard`] is scaled by the luxury multiplier.
 fn get_world_luxury_target_numbers(
     world_size_type: WorldSizeType,
     resource_setting: ResourceSetting,
 ) -> [u32; 2] {
+    if let ResourceSetting::Custom {
+        luxury_multiplier, ..
+    } = resource_setting
+    {
+        let [target_luxury, loop_target] =
+            get_world_luxury_target_numbers(world_size_type, ResourceSetting::Standard);
+        return [
+            (target_luxury as f64 * luxury_multiplier.as_f64()).round() as u32,
+            loop_target,
+        ];
+    }
+
     match (resource_setting, world_size_type) {
         (ResourceSetting::Sparse, WorldSizeType::Duel) => [14, 3],
         (ResourceSetting::Sparse, WorldSizeType::Tiny) => [24, 4],
@@ -1147,3 +1179,19 @@ fn get_world_luxury_target_numbers(
         (_, WorldSizeType::Huge) => [95, 6],
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_map, test_utils::test_map_parameters};
+
+    /// Tests that exactly `marble_count` marble resources are placed.
+    #[test]
+    fn test_marble_count() {
+        use crate::ruleset::enums::Resource;
+
+        let map_parameters = test_map_parameters().marble_count(3).build();
+        let map = generate_map(&map_parameters);
+
+        assert_eq!(map.tiles_with_resource(Resource::Marble).count(), 3);
+    }
+}
@@ -8,11 +8,17 @@ use rand::{Rng, RngExt, seq::SliceRandom};
 
 impl TileMap {
     /// Places strategic resources on the map.
+    ///
+    /// At last, the strategic resources with fewer deposits than [`MapParameters::min_strategic_deposits`] are topped up
+    /// on the eligible tiles with the lowest impact of [`Layer::Strategic`].
     pub fn place_strategic_resources(&mut self, map_parameters: &MapParameters) {
         let num_civilizations = map_parameters.world_size_type_profile.num_civilizations;
         // Adjust amounts, if applicable, based on Resource Setting.
         let (uran_amt, horse_amt, oil_amt, iron_amt, coal_amt, alum_amt) =
-            get_major_strategic_resource_quantity_values(map_parameters.resource_setting);
+            get_major_strategic_resource_quantity_values(
+                map_parameters.resource_setting,
+                map_parameters.world_grid.world_size_type,
+            );
 
         // Adjust appearance rate per Resource Setting chosen by user.
         let bonus_multiplier = match map_parameters.resource_setting {
@@ -20,6 +26,12 @@ impl TileMap {
             ResourceSetting::Sparse => 1.5,
             // Abundant, so reduce the number of tiles per bonus.
             ResourceSetting::Abundant => 2.0 / 3.0,
+            // Custom, so divide the number of tiles per bonus by the multiplier.
+            // When the multiplier is 0, the frequency saturates to `u32::MAX`.
+            ResourceSetting::Custom {
+                strategic_multiplier,
+                ..
+            } => 1.0 / strategic_multiplier.as_f64(),
             _ => 1.0,
         };
 
@@ -358,6 +370,132 @@ impl TileMap {
                 &resources_to_place,
             );
         }
+
+        self.place_minimum_strategic_deposits(map_parameters);
+    }
+
+    /// Tops up every strategic resource with fewer deposits than its minimum in [`MapParameters::min_strategic_deposits`].
+    ///
+    /// A deposit is a tile with the resource, its quantity is the same as the major deposits placed by [`TileMap::place_strategic_resources`].
+    /// The additional deposits are placed on the tiles which meet one of the required terrains of the resource.
+    /// The tiles out of the impact of [`Layer::Strategic`] are preferred, then the tiles with the lowest impact.
+    /// Like the major deposits, each additional deposit places a ripple with a radius from [`ResourceToPlace::radius_range`],
+    /// so the additional deposits are spread out instead of being clustered.
+    /// The tiles with an impact of at least `98` are never used, so fewer deposits may be placed than required.
+    fn place_minimum_strategic_deposits(&mut self, map_parameters: &MapParameters) {
+        if map_parameters.min_strategic_deposits.is_empty() {
+            return;
+        }
+
+        let ruleset = &map_parameters.ruleset;
+
+        let (uran_amt, horse_amt, oil_amt, iron_amt, coal_amt, alum_amt) =
+            get_major_strategic_resource_quantity_values(
+                map_parameters.resource_setting,
+                map_parameters.world_grid.world_size_type,
+            );
+
+        // Sort the resources to keep the result deterministic, because the iteration order of `HashMap` is random.
+        let mut min_strategic_deposits: Vec<_> =
+            map_parameters.min_strategic_deposits.iter().collect();
+        min_strategic_deposits.sort_unstable();
+
+        for (&resource, &min_deposits) in min_strategic_deposits {
+            let num_deposits = self
+                .resource_list
+                .iter()
+                .filter(|tile_resource| tile_resource.is_some_and(|(r, _)| r == resource))
+                .count() as u32;
+
+            if num_deposits >= min_deposits {
+                continue;
+            }
+
+            // The radius ranges are the same as one of the major deposits of the resource placed above.
+            let resource_to_place = match resource {
+                Resource::Uranium => ResourceToPlace {
+                    resource,
+                    quantity: uran_amt,
+                    weight: 100,
+                    radius_range: (1, 1),
+                },
+                Resource::Horses => ResourceToPlace {
+                    resource,
+                    quantity: horse_amt,
+                    weight: 100,
+                    radius_range: (1, 4),
+                },
+                Resource::Oil => ResourceToPlace {
+                    resource,
+                    quantity: oil_amt,
+                    weight: 100,
+                    radius_range: (1, 1),
+                },
+                Resource::Iron => ResourceToPlace {
+                    resource,
+                    quantity: iron_amt,
+                    weight: 100,
+                    radius_range: (1, 2),
+                },
+                Resource::Coal => ResourceToPlace {
+                    resource,
+                    quantity: coal_amt,
+                    weight: 100,
+                    radius_range: (1, 2),
+                },
+                Resource::Aluminum => ResourceToPlace {
+                    resource,
+                    quantity: alum_amt,
+                    weight: 100,
+                    radius_range: (1, 2),
+                },
+                // The other strategic resources defined by the ruleset.
+                _ => ResourceToPlace {
+                    resource,
+                    quantity: 1,
+                    weight: 100,
+                    radius_range: (1, 1),
+                },
+            };
+
+            let required_terrain_list = &ruleset.resources[resource].required_terrain;
+
+            let mut candidate_tile_list: Vec<Tile> = self
+                .all_tiles()
+                .filter(|tile| {
+                    tile.resource(self).is_none()
+                        && tile.natural_wonder(self).is_none()
+                        && required_terrain_list.iter().any(|required_terrain| {
+                            tile.meets_required_terrain(self, required_terrain)
+                        })
+                })
+                .collect();
+            candidate_tile_list.shuffle(&mut self.random_number_generator);
+
+            for _ in num_deposits..min_deposits {
+                let Some(&tile) = candidate_tile_list
+                    .iter()
+                    .filter(|tile| {
+                        self.layer_data[Layer::Strategic][tile.index()] < 98
+                            && tile.resource(self).is_none()
+                    })
+                    .min_by_key(|tile| self.layer_data[Layer::Strategic][tile.index()])
+                else {
+                    #[cfg(debug_assertions)]
+                    eprintln!(
+                        "Not enough valid locations to place at least {min_deposits} deposits of {resource:?}."
+                    );
+                    break;
+                };
+
+                let (min_radius, max_radius) = resource_to_place.radius_range;
+                let radius = self
+                    .random_number_generator
+                    .random_range(min_radius..=max_radius);
+                tile.set_resource(self, resource, resource_to_place.quantity);
+                self.place_impact_and_ripples(tile, Layer::Strategic, radius);
+            }
+        }
     }
 
     // function AssignStartingPlots:PlaceOilInTheSea
@@ -374,7 +512,7 @@ impl TileMap {
         let sea_oil_amt = if let ResourceSetting::Abundant = map_parameters.resource_setting {
             6
         } else {
-            4
+            scale_strategic_resource_quantity(4, map_parameters.resource_setting)
         };
         let num_land_oil = self.placed_resource_count(Resource::Oil);
 
@@ -413,7 +551,7 @@ impl TileMap {
         let [uran_amt, horse_amt, oil_amt, iron_amt, coal_amt, alum_amt] =
             get_small_strategic_resource_quantity_values(map_parameters.resource_setting);
 
-        let num_to_place = (tile_list.len() as u32).div_ceil(frequency);
+        let num_to_place = (tile_list.len() as u32).div_ceil(frequency.max(1));
 
         let mut num_left_to_place = num_to_place;
 
@@ -753,4 +891,88 @@ fn get_small_strategic_resource_quantity_values(resource_setting: ResourceSettin
     };
 
     [uran_amt, horse_amt, oil_amt, iron_amt, coal_amt, alum_amt]
+        .map(|amount| scale_strategic_resource_quantity(amount, resource_setting))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_map, grid::WorldSizeType, test_utils::test_map_parameters, tile::Tile};
+
+    /// Tests that the strategic resource quantities on Huge maps are not less than on Standard maps.
+    #[test]
+    fn test_strategic_resource_quantity_scaling() {
+        use crate::{
+            map_parameters::ResourceSetting, tile_map::get_major_strategic_resource_quantity_values,
+        };
+
+        let quantities = |resource_setting, world_size_type| {
+            let (uranium, horse, oil, iron, coal, aluminum) =
+                get_major_strategic_resource_quantity_values(resource_setting, world_size_type);
+            [uranium, horse, oil, iron, coal, aluminum]
+        };
+
+        for resource_setting in [
+            ResourceSetting::Sparse,
+            ResourceSetting::Standard,
+            ResourceSetting::Abundant,
+        ] {
+            let standard = quantities(resource_setting, WorldSizeType::Standard);
+            let huge = quantities(resource_setting, WorldSizeType::Huge);
+            assert!(
+                huge.iter()
+                    .zip(standard)
+                    .all(|(&huge, standard)| huge >= standard)
+            );
+        }
+
+        // Abundant still yields not less than Standard, which yields not less than Sparse.
+        let sparse = quantities(ResourceSetting::Sparse, WorldSizeType::Huge);
+        let standard = quantities(ResourceSetting::Standard, WorldSizeType::Huge);
+        let abundant = quantities(ResourceSetting::Abundant, WorldSizeType::Huge);
+        assert!((0..6).all(|i| sparse[i] <= standard[i] && standard[i] <= abundant[i]));
+    }
+
+    /// Tests that a strategic resource is topped up to its minimum number of deposits,
+    /// and the additional deposits are kept apart by their ripples.
+    #[test]
+    fn test_min_strategic_deposits() {
+        use crate::ruleset::enums::Resource;
+        use std::collections::HashMap;
+
+        let iron_tiles = |min_iron_deposits: u32| {
+            let map_parameters = test_map_parameters()
+                .min_strategic_deposits(HashMap::from([(Resource::Iron, min_iron_deposits)]))
+                .build();
+            let map = generate_map(&map_parameters);
+
+            let iron_tiles: Vec<Tile> = map
+                .all_tiles()
+                .filter(|tile| {
+                    tile.resource(&map)
+                        .is_some_and(|(resource, _)| resource == Resource::Iron)
+                })
+                .collect();
+            (map, iron_tiles)
+        };
+
+        assert!(iron_tiles(5).1.len() >= 5);
+
+        let (_, default_iron_tiles) = iron_tiles(0);
+        let min_iron_deposits = default_iron_tiles.len() + 30;
+        let (map, topped_up_iron_tiles) = iron_tiles(min_iron_deposits as u32);
+        assert!(topped_up_iron_tiles.len() >= min_iron_deposits);
+
+        let grid = map.world_grid.grid;
+        let additional_iron_tiles: Vec<Tile> = topped_up_iron_tiles
+            .into_iter()
+            .filter(|tile| !default_iron_tiles.contains(tile))
+            .collect();
+        for tile in &additional_iron_tiles {
+            assert!(
+                tile.neighbor_tiles(grid)
+                    .all(|neighbor_tile| !additional_iron_tiles.contains(&neighbor_tile)),
+                "The additional deposits should not be next to each other."
+            );
+        }
+    }
 }
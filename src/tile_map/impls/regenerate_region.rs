@@ -0,0 +1,199 @@
+use crate::{map_parameters::MapParameters, ruleset::enums::*, tile::Tile, tile_map::TileMap};
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
+
+impl TileMap {
+    /// Re-rolls the terrain and resources of a single region, leaving the rest of the map intact.
+    ///
+    /// The terrain type, base terrain and feature of the eligible tiles in the region are re-rolled among these tiles,
+    /// so the terrain statistic of the region is kept, and the region type and the balance of the starting tile are not broken.
+    /// Then the resources of the eligible tiles are removed and placed again on random eligible tiles,
+    /// only where the tile meets one of the required terrains of the resource, see [`TileMap::set_resource_checked`].
+    /// A resource which can't be placed on any remaining eligible tile is dropped.
+    ///
+    /// A tile is eligible when:
+    /// - It is in the region's rectangle, and it is on the region's landmass if [`Region::area_id`] is `Some`.
+    /// - It is flatland or hill.
+    /// - It has no river and no natural wonder.
+    /// - It is not within 3 tiles of a civilization or city state starting tile.
+    ///
+    /// The random number generator used here is forked from the seed of the map and `region_index`,
+    /// so the result is deterministic and does not affect [`TileMap::random_number_generator`].
+    /// The seed of the map is [`MapParameters::seed_bytes`] if it is `Some`, otherwise [`MapParameters::seed`].
+    ///
+    /// [`Region::area_id`]: crate::tile_map::Region::area_id
+    ///
+    /// # Panics
+    ///
+    /// Panics if `region_index` is out of bounds of [`TileMap::region_list`].
+    pub fn regenerate_region(&mut self, region_index: usize, map_parameters: &MapParameters) {
+        const START_TILE_PROTECTION_RADIUS: u32 = 3;
+
+        let grid = self.world_grid.grid;
+
+        let region = &self.region_list[region_index];
+
+        let eligible_tiles: Vec<Tile> = region
+            .rectangle
+            .all_cells(&grid)
+            .map(Tile::from_cell)
+            .filter(|tile| {
+                region
+                    .area_id
+                    .is_none_or(|area_id| tile.area_id(self) == area_id)
+                    && matches!(
+                        tile.terrain_type(self),
+                        TerrainType::Flatland | TerrainType::Hill
+                    )
+                    && !tile.has_river(self)
+                    && tile.natural_wonder(self).is_none()
+                    && !tile
+                        .tiles_in_distance(START_TILE_PROTECTION_RADIUS, grid)
+                        .any(|tile| {
+                            self.starting_tile_and_civilization.contains_key(&tile)
+                                || self.starting_tile_and_city_state.contains_key(&tile)
+                        })
+            })
+            .collect();
+
+        // Fork a child random number generator from the seed of the map and the region index.
+        let seed = map_parameters.seeded_rng().next_u64()
+            ^ (region_index as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let mut random_number_generator = StdRng::seed_from_u64(seed);
+
+        // Re-roll the terrain. The terrain type, base terrain and feature of a tile are kept together,
+        // so every feature stays on a terrain where it can exist.
+        let mut terrain_list: Vec<_> = eligible_tiles
+            .iter()
+            .map(|tile| {
+                (
+                    tile.terrain_type(self),
+                    tile.base_terrain(self),
+                    tile.feature(self),
+                )
+            })
+            .collect();
+
+        terrain_list.shuffle(&mut random_number_generator);
+
+        for (&tile, (terrain_type, base_terrain, feature)) in
+            eligible_tiles.iter().zip(terrain_list)
+        {
+            tile.set_terrain_type(self, terrain_type);
+            tile.set_base_terrain(self, base_terrain);
+            match feature {
+                Some(feature) => tile.set_feature(self, feature),
+                None => tile.clear_feature(self),
+            }
+        }
+
+        // Re-roll the resources on the new terrain.
+        let mut resource_list: Vec<_> = eligible_tiles
+            .iter()
+            .filter_map(|tile| tile.resource(self))
+            .collect();
+        eligible_tiles
+            .iter()
+            .for_each(|tile| tile.clear_resource(self));
+
+        resource_list.shuffle(&mut random_number_generator);
+
+        let mut free_tiles = eligible_tiles;
+        for (resource, quantity) in resource_list {
+            free_tiles.shuffle(&mut random_number_generator);
+            if let Some(index) = free_tiles.iter().position(|&tile| {
+                self.set_resource_checked(tile, resource, quantity, &map_parameters.ruleset)
+                    .is_ok()
+            }) {
+                free_tiles.swap_remove(index);
+            }
+        }
+
+        // Terrain types are changed, so recalculate the areas and landmasses.
+        // Only flatland and hill are swapped, they are both passable land,
+        // so the area IDs stay the same and [`Region::area_id`] is still valid.
+        self.recalculate_areas(map_parameters);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_map, test_utils::test_map_parameters, tile::Tile};
+
+    /// Tests that regenerating one region leaves the tiles of other regions unchanged,
+    /// and every resource in the region is on a terrain where it can be placed.
+    #[test]
+    fn test_regenerate_region() {
+        let map_parameters = test_map_parameters().build();
+        let original_map = generate_map(&map_parameters);
+        let mut map = generate_map(&map_parameters);
+
+        map.regenerate_region(0, &map_parameters);
+
+        let grid = map.world_grid.grid;
+        for region in map.region_list().iter().skip(1) {
+            for tile in region.rectangle.all_cells(&grid).map(Tile::from_cell) {
+                if region
+                    .area_id
+                    .is_some_and(|area_id| tile.area_id(&original_map) != area_id)
+                {
+                    continue;
+                }
+                assert_eq!(tile.terrain_type(&map), tile.terrain_type(&original_map));
+                assert_eq!(tile.base_terrain(&map), tile.base_terrain(&original_map));
+                assert_eq!(tile.feature(&map), tile.feature(&original_map));
+                assert_eq!(tile.resource(&map), tile.resource(&original_map));
+            }
+        }
+
+        // The resources moved to another terrain in the regenerated region can be placed there.
+        let ruleset = &map_parameters.ruleset;
+        let region = &map.region_list()[0];
+        let mut num_changed_tiles = 0;
+        for tile in region.rectangle.all_cells(&grid).map(Tile::from_cell) {
+            let content = |map| {
+                (
+                    tile.terrain_type(map),
+                    tile.base_terrain(map),
+                    tile.feature(map),
+                    tile.resource(map),
+                )
+            };
+            if content(&map) == content(&original_map) {
+                continue;
+            }
+            num_changed_tiles += 1;
+            if let Some((resource, _)) = tile.resource(&map) {
+                assert!(
+                    ruleset.resources[resource]
+                        .required_terrain
+                        .iter()
+                        .any(|required_terrain| tile.meets_required_terrain(&map, required_terrain))
+                );
+            }
+        }
+        assert!(num_changed_tiles > 0);
+    }
+
+    /// Tests that the regeneration depends on `seed_bytes` when it is set, even if `seed` is the same.
+    #[test]
+    fn test_regenerate_region_with_seed_bytes() {
+        // Every regeneration is applied to the same map.
+        let original_map_parameters = test_map_parameters().seed_bytes([1; 32]).build();
+
+        let regenerated_map = |seed_bytes| {
+            let mut map = generate_map(&original_map_parameters);
+            let map_parameters = test_map_parameters().seed_bytes(seed_bytes).build();
+            map.regenerate_region(0, &map_parameters);
+            map
+        };
+
+        assert_eq!(
+            regenerated_map([1; 32]).content_hash(),
+            regenerated_map([1; 32]).content_hash()
+        );
+        assert_ne!(
+            regenerated_map([1; 32]).content_hash(),
+            regenerated_map([2; 32]).content_hash()
+        );
+    }
+}
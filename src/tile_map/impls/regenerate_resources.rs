@@ -0,0 +1,127 @@
+use crate::{
+    map_parameters::MapParameters,
+    tile_map::{Layer, LuxuryResourceRole, TileMap},
+};
+
+impl TileMap {
+    /// Re-rolls all the resources of the map, leaving the rest of the map intact.
+    ///
+    /// This function runs the resource stages of the pipeline again, in the same order as [`Generator::generate`](crate::map_generator::Generator::generate):
+    /// 1. Remove all the resources from the map.
+    /// 2. Reset [`Layer::Strategic`], [`Layer::Luxury`], [`Layer::Bonus`], [`Layer::Fish`] and [`Layer::Marble`] in [`TileMap::layer_data`],
+    ///    then place the impact of the civilization starting tiles, the natural wonders and the city state starting tiles on these layers again.
+    /// 3. Re-assign the luxury roles, then place luxury, strategic and bonus resources again, with [`MapParameters::ripple_config`] of `map_parameters`.
+    /// 4. Mirror the resources if [`MapParameters::symmetry`] is set, see [`TileMap::apply_symmetry_to_placements`].
+    ///
+    /// Terrain, features, rivers, natural wonders and starting tiles are preserved.
+    /// The starting tiles are not normalized again, so the resources placed by the normalization,
+    /// e.g. [`MapParameters::starting_bonus_resource`], are not placed again.
+    /// The random number generator is not reset, so every call produces a different resource layout.
+    pub fn regenerate_resources(&mut self, map_parameters: &MapParameters) {
+        self.resource_list.fill(None);
+        self.ripple_config = map_parameters.ripple_config;
+
+        for layer in [
+            Layer::Strategic,
+            Layer::Luxury,
+            Layer::Bonus,
+            Layer::Fish,
+            Layer::Marble,
+        ] {
+            self.layer_data[layer].fill(0);
+        }
+
+        let civilization_starting_tiles: Vec<_> = self
+            .starting_tile_and_civilization
+            .keys()
+            .copied()
+            .collect();
+        for tile in civilization_starting_tiles {
+            self.place_impact_on_resource_layers(tile, Layer::Civilization);
+        }
+
+        // A natural wonder may occupy more than one tile, the impact is placed on each of them.
+        let natural_wonder_tiles: Vec<_> = self
+            .all_tiles()
+            .filter(|tile| tile.natural_wonder(self).is_some())
+            .collect();
+        for tile in natural_wonder_tiles {
+            self.place_impact_on_resource_layers(tile, Layer::NaturalWonder);
+        }
+
+        let city_state_starting_tiles: Vec<_> =
+            self.starting_tile_and_city_state.keys().copied().collect();
+        for tile in city_state_starting_tiles {
+            self.place_impact_on_resource_layers(tile, Layer::CityState);
+        }
+
+        self.luxury_resource_role = LuxuryResourceRole::default();
+        self.region_exclusive_luxury_list.clear();
+
+        self.assign_luxury_roles(map_parameters);
+        self.place_luxury_resources(map_parameters);
+        self.place_strategic_resources(map_parameters);
+        self.place_bonus_resources(map_parameters);
+
+        self.apply_symmetry_to_placements(map_parameters);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_map, map_parameters::Symmetry, test_utils::test_map_parameters};
+
+    /// Tests that re-rolling resources twice produces different resource layouts,
+    /// while terrain, features, rivers and starting tiles stay the same.
+    #[test]
+    fn test_regenerate_resources() {
+        let map_parameters = test_map_parameters().build();
+        let mut map = generate_map(&map_parameters);
+
+        let terrain_type_list = map.terrain_type_list.clone();
+        let elevation_list = map.elevation_list.clone();
+        let base_terrain_list = map.base_terrain_list.clone();
+        let feature_list = map.feature_list.clone();
+        let river_list = map.river_list.clone();
+        let natural_wonder_list = map.natural_wonder_list.clone();
+        let civilization_starts = map.civilization_starts();
+        let city_state_starts = map.city_state_starts();
+
+        map.regenerate_resources(&map_parameters);
+        let first_resource_list = map.resource_list.clone();
+
+        map.regenerate_resources(&map_parameters);
+        let second_resource_list = map.resource_list.clone();
+
+        assert_ne!(first_resource_list, second_resource_list);
+        assert!(first_resource_list.iter().any(Option::is_some));
+        assert!(second_resource_list.iter().any(Option::is_some));
+
+        assert_eq!(map.terrain_type_list, terrain_type_list);
+        assert_eq!(map.elevation_list, elevation_list);
+        assert_eq!(map.base_terrain_list, base_terrain_list);
+        assert_eq!(map.feature_list, feature_list);
+        assert_eq!(map.river_list, river_list);
+        assert_eq!(map.natural_wonder_list, natural_wonder_list);
+        assert_eq!(map.civilization_starts(), civilization_starts);
+        assert_eq!(map.city_state_starts(), city_state_starts);
+    }
+
+    /// Tests that the re-rolled resources of a symmetric map are mirrored.
+    #[test]
+    fn test_regenerate_resources_with_symmetry() {
+        let map_parameters = test_map_parameters().symmetry(Symmetry::Rotational).build();
+        let mut map = generate_map(&map_parameters);
+
+        map.regenerate_resources(&map_parameters);
+
+        for tile in map.all_tiles() {
+            let Some(mirror_tile) = map.symmetric_tile(tile, Symmetry::Rotational) else {
+                continue;
+            };
+            if tile.natural_wonder(&map).is_none() && mirror_tile.natural_wonder(&map).is_none() {
+                assert_eq!(tile.resource(&map), mirror_tile.resource(&map));
+            }
+        }
+    }
+}
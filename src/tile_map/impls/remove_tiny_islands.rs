@@ -0,0 +1,110 @@
+use crate::{
+    map_parameters::MapParameters,
+    ruleset::enums::{BaseTerrain, TerrainType},
+    tile::Tile,
+    tile_map::{LandmassType, River, TileMap},
+};
+
+impl TileMap {
+    /// Turns the tiny islands back into water according to [`MapParameters::remove_tiny_islands`].
+    ///
+    /// An island is a land [`Landmass`](crate::tile_map::Landmass), it's tiny when its size is at most [`MapParameters::tiny_island_max_size`].
+    /// Its tiles become [`TerrainType::Water`] with [`BaseTerrain::Coast`], their features are removed,
+    /// and the river edges along them are removed, the rivers are split there.
+    /// The areas are recalculated if any island is removed.
+    ///
+    /// It does nothing when [`MapParameters::remove_tiny_islands`] is `false`.
+    ///
+    /// # Notes
+    ///
+    /// This method should be called after [`TileMap::recalculate_areas`] and before [`TileMap::generate_regions`],
+    /// because it finds the islands with the landmasses, and the regions should not contain the removed islands.
+    pub fn remove_tiny_islands(&mut self, map_parameters: &MapParameters) {
+        if !map_parameters.remove_tiny_islands {
+            return;
+        }
+
+        let grid = self.world_grid.grid;
+
+        let tiny_island_tiles: Vec<Tile> = self
+            .all_tiles()
+            .filter(|tile| {
+                let landmass = &self.landmass_list[tile.landmass_id(self)];
+                landmass.landmass_type == LandmassType::Land
+                    && landmass.size <= map_parameters.tiny_island_max_size
+            })
+            .collect();
+
+        if tiny_island_tiles.is_empty() {
+            return;
+        }
+
+        for &tile in &tiny_island_tiles {
+            tile.set_terrain_type(self, TerrainType::Water);
+            tile.set_elevation(self, 0);
+            tile.set_base_terrain(self, BaseTerrain::Coast);
+            tile.clear_feature(self);
+        }
+
+        let mut river_list = Vec::new();
+        for river in std::mem::take(&mut self.river_list) {
+            let mut kept_river = River::new();
+            for river_edge in river {
+                let neighbor_tile = river_edge
+                    .tile
+                    .neighbor_tile(river_edge.edge_direction(grid), grid);
+                if tiny_island_tiles.contains(&river_edge.tile)
+                    || neighbor_tile.is_some_and(|tile| tiny_island_tiles.contains(&tile))
+                {
+                    if !kept_river.is_empty() {
+                        river_list.push(std::mem::take(&mut kept_river));
+                    }
+                } else {
+                    kept_river.push(river_edge);
+                }
+            }
+            if !kept_river.is_empty() {
+                river_list.push(kept_river);
+            }
+        }
+        self.river_list = river_list;
+
+        self.recalculate_areas(map_parameters);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_map, test_utils::test_map_parameters};
+
+    /// Tests that no single-tile island is left when the tiny islands are removed.
+    #[test]
+    fn test_remove_tiny_islands() {
+        use crate::tile_map::{AreaFlags, LandmassType};
+
+        let single_tile_island_count = |remove_tiny_islands: bool| {
+            let map_parameters = test_map_parameters()
+                .remove_tiny_islands(remove_tiny_islands)
+                .build();
+            let map = generate_map(&map_parameters);
+
+            let land_area_count = map
+                .area_list
+                .iter()
+                .filter(|area| !area.area_flags.contains(AreaFlags::Water) && area.size == 1)
+                .count();
+            let landmass_count = map
+                .landmass_list
+                .iter()
+                .filter(|landmass| {
+                    landmass.landmass_type == LandmassType::Land && landmass.size == 1
+                })
+                .count();
+            (land_area_count, landmass_count)
+        };
+
+        let (_, landmass_count) = single_tile_island_count(false);
+        assert!(landmass_count > 0);
+        assert_eq!(single_tile_island_count(true), (0, 0));
+    }
+}
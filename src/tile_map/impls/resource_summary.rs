@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    ruleset::{ResourceCategory, enums::Resource},
+    tile_map::TileMap,
+};
+
+impl TileMap {
+    /// Returns the statistic of every resource placed on the map.
+    ///
+    /// Only the resources which are placed on at least one tile are included in the returned map.
+    /// It is computed from [`TileMap::resource_list`], so it is read-only and deterministic.
+    pub fn resource_summary(&self) -> BTreeMap<Resource, ResourceStats> {
+        let mut resource_summary: BTreeMap<Resource, ResourceStats> = BTreeMap::new();
+
+        for &(resource, quantity) in self.resource_list.iter().flatten() {
+            resource_summary
+                .entry(resource)
+                .or_default()
+                .add(1, quantity);
+        }
+
+        resource_summary
+    }
+
+    /// Returns the statistic of the resources placed on the map, grouped by [`Resource::category`].
+    pub fn resource_summary_by_type(&self) -> BTreeMap<ResourceCategory, ResourceStats> {
+        let mut resource_summary_by_type: BTreeMap<ResourceCategory, ResourceStats> =
+            BTreeMap::new();
+
+        for (resource, resource_stats) in self.resource_summary() {
+            resource_summary_by_type
+                .entry(resource.category())
+                .or_default()
+                .add(resource_stats.tile_count, resource_stats.total_quantity);
+        }
+
+        resource_summary_by_type
+    }
+}
+
+/// The statistic of a resource or a group of resources placed on the map.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub struct ResourceStats {
+    /// The number of tiles which have the resource.
+    pub tile_count: u32,
+    /// The total quantity of the resource on all these tiles.
+    pub total_quantity: u32,
+}
+
+impl ResourceStats {
+    fn add(&mut self, tile_count: u32, quantity: u32) {
+        self.tile_count += tile_count;
+        self.total_quantity += quantity;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate_map, map_parameters::MapType, test_utils::test_map_parameters};
+
+    /// Tests that a standard fractal map has at least one strategic and one luxury resource type.
+    #[test]
+    fn test_resource_summary() {
+        use crate::ruleset::ResourceCategory;
+
+        let map_parameters = test_map_parameters().map_type(MapType::Fractal).build();
+        let map = generate_map(&map_parameters);
+
+        let resource_summary = map.resource_summary();
+        let resource_tile_count = map.resource_list.iter().flatten().count();
+        assert_eq!(
+            resource_summary
+                .values()
+                .map(|stats| stats.tile_count as usize)
+                .sum::<usize>(),
+            resource_tile_count
+        );
+
+        for category in [ResourceCategory::Strategic, ResourceCategory::Luxury] {
+            assert!(
+                resource_summary
+                    .keys()
+                    .any(|resource| resource.category() == category)
+            );
+        }
+
+        let resource_summary_by_type = map.resource_summary_by_type();
+        assert!(resource_summary_by_type[&ResourceCategory::Strategic].tile_count > 0);
+        assert!(resource_summary_by_type[&ResourceCategory::Luxury].tile_count > 0);
+    }
+}
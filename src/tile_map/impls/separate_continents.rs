@@ -0,0 +1,126 @@
+use std::cmp::Reverse;
+
+use crate::{
+    map_parameters::MapParameters, ruleset::enums::TerrainType, tile::Tile, tile_map::TileMap,
+};
+
+impl TileMap {
+    /// Sinks the land tiles between the major landmasses according to [`MapParameters::min_continent_gap`].
+    ///
+    /// A land tile is sunk into [`TerrainType::Water`] when a tile of another major landmass is within `min_continent_gap` tiles of it,
+    /// and its landmass is smaller than the other one. When two landmasses have the same size, the one found later is sunk.
+    /// Sinking tiles may split a landmass, so the landmasses are found again until no land tile is sunk.
+    ///
+    /// It does nothing when [`MapParameters::min_continent_gap`] is `0`.
+    ///
+    /// # Notes
+    ///
+    /// This method should be called after [`TileMap::generate_equatorial_ocean`] and before [`TileMap::recalculate_areas`],
+    /// because it works on the terrain types only, and the areas should be calculated on the separated landmasses.
+    pub fn separate_continents(&mut self, map_parameters: &MapParameters) {
+        let gap = map_parameters.min_continent_gap;
+
+        if gap == 0 {
+            return;
+        }
+
+        loop {
+            // Find the landmasses by the terrain types, because the areas are not calculated yet.
+            let land_bodies = self.connected_components(|tile| tile.is_land(self));
+
+            let mut landmass_id_list = vec![None; self.terrain_type_list.len()];
+            for (landmass_id, land_tiles) in land_bodies.iter().enumerate() {
+                for tile in land_tiles {
+                    landmass_id_list[tile.index()] = Some(landmass_id);
+                }
+            }
+            let landmass_size_list: Vec<u32> = land_bodies
+                .iter()
+                .map(|land_tiles| land_tiles.len() as u32)
+                .collect();
+
+            // The ID of the major landmass which the tile belongs to.
+            let major_landmass_id = |tile: Tile| {
+                landmass_id_list[tile.index()].filter(|&landmass_id| {
+                    landmass_size_list[landmass_id] > map_parameters.tiny_island_max_size
+                })
+            };
+            // The landmass with the lower rank is sunk.
+            let rank = |landmass_id: usize| (landmass_size_list[landmass_id], Reverse(landmass_id));
+
+            let sunk_tiles: Vec<Tile> = self
+                .all_tiles()
+                .filter(|&tile| {
+                    let Some(landmass_id) = major_landmass_id(tile) else {
+                        return false;
+                    };
+
+                    tile.tiles_within_distance(gap, self).any(|other_tile| {
+                        major_landmass_id(other_tile).is_some_and(|other_landmass_id| {
+                            other_landmass_id != landmass_id
+                                && rank(landmass_id) < rank(other_landmass_id)
+                        })
+                    })
+                })
+                .collect();
+
+            if sunk_tiles.is_empty() {
+                break;
+            }
+
+            for tile in sunk_tiles {
+                tile.set_terrain_type(self, TerrainType::Water);
+                tile.set_elevation(self, 0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map, map_parameters::MapType, test_utils::test_map_parameters, tile::Tile,
+        tile_map::TileMap,
+    };
+
+    /// Tests that no two major landmasses are within [`MapParameters::min_continent_gap`] tiles of each other.
+    ///
+    /// [`MapParameters::min_continent_gap`]: crate::map_parameters::MapParameters::min_continent_gap
+    #[test]
+    fn test_min_continent_gap() {
+        use crate::{map_parameters::MapParameters, tile_map::LandmassType};
+
+        let gap = 3;
+
+        // Returns whether a tile of a major landmass is within `gap` tiles of another major landmass.
+        let has_close_landmasses = |map: &TileMap, map_parameters: &MapParameters| {
+            let major_landmass_id = |tile: Tile| {
+                let landmass_id = tile.landmass_id(map);
+                let landmass = &map.landmass_list[landmass_id];
+                (landmass.landmass_type == LandmassType::Land
+                    && landmass.size > map_parameters.tiny_island_max_size)
+                    .then_some(landmass_id)
+            };
+
+            map.all_tiles().any(|tile| {
+                major_landmass_id(tile).is_some_and(|landmass_id| {
+                    tile.tiles_within_distance(gap, map).any(|other_tile| {
+                        major_landmass_id(other_tile)
+                            .is_some_and(|other_landmass_id| other_landmass_id != landmass_id)
+                    })
+                })
+            })
+        };
+
+        let map_parameters = test_map_parameters().map_type(MapType::Fractal).build();
+        let map = generate_map(&map_parameters);
+        assert!(has_close_landmasses(&map, &map_parameters));
+
+        let map_parameters = test_map_parameters()
+            .map_type(MapType::Fractal)
+            .min_continent_gap(gap)
+            .build();
+        let map = generate_map(&map_parameters);
+        assert!(!has_close_landmasses(&map, &map_parameters));
+    }
+}
@@ -28,7 +28,8 @@ impl TileMap {
         }
 
         let mut terrain_type_list = Vec::with_capacity((grid.width() * grid.height()) as usize);
-        // Populate new terrain type list with shifted values
+        let mut elevation_list = Vec::with_capacity((grid.width() * grid.height()) as usize);
+        // Populate new terrain type list and elevation list with shifted values
         for y in 0..grid.height() as i32 {
             for x in 0..grid.width() as i32 {
                 let source_x = x + x_shift;
@@ -36,10 +37,12 @@ impl TileMap {
                 let source_tile =
                     Tile::from_offset(OffsetCoordinate::new(source_x, source_y), grid);
                 terrain_type_list.push(source_tile.terrain_type(self));
+                elevation_list.push(source_tile.elevation(self));
             }
         }
 
         self.terrain_type_list = terrain_type_list;
+        self.elevation_list = elevation_list.into();
     }
 
     fn determine_x_shift(&mut self) -> i32 {
@@ -52,7 +55,7 @@ impl TileMap {
 
         self.all_tiles().for_each(|tile| {
             let [x, _] = tile.to_offset(grid).to_array();
-            if tile.terrain_type(self) != TerrainType::Water {
+            if tile.is_land(self) {
                 land_totals[x as usize] += 1;
             }
         });
@@ -101,7 +104,7 @@ impl TileMap {
 
         self.all_tiles().for_each(|tile| {
             let [_, y] = tile.to_offset(grid).to_array();
-            if tile.terrain_type(self) != TerrainType::Water {
+            if tile.is_land(self) {
                 land_totals[y as usize] += 1;
             }
         });
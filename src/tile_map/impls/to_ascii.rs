@@ -0,0 +1,131 @@
+use crate::{
+    grid::*,
+    ruleset::enums::{BaseTerrain, TerrainType},
+    tile::Tile,
+    tile_map::TileMap,
+};
+
+impl TileMap {
+    /// Renders the map as a text string, which is useful for debugging map generation without a renderer.
+    ///
+    /// Each row of the grid is rendered as one line, from the northernmost row to the southernmost row,
+    /// and every tile is rendered as a single character separated by spaces.
+    /// When the hex orientation is [`HexOrientation::Pointy`], the shifted rows (odd rows for [`Offset::Odd`],
+    /// even rows for [`Offset::Even`]) are indented by one space to reflect the hex offset.
+    /// Rows of a square grid are never indented.
+    ///
+    /// The characters are:
+    ///
+    /// | Character | Tile                  |
+    /// |-----------|-----------------------|
+    /// | `~`       | Ocean                 |
+    /// | `-`       | Coast                 |
+    /// | `o`       | Lake                  |
+    /// | `^`       | Mountain              |
+    /// | `#`       | Hill                  |
+    /// | `.`       | Grassland (flatland)  |
+    /// | `,`       | Plain (flatland)      |
+    /// | `:`       | Desert (flatland)     |
+    /// | `_`       | Tundra (flatland)     |
+    /// | `*`       | Snow (flatland)       |
+    ///
+    /// Features, natural wonders, resources and rivers are not rendered.
+    pub fn to_ascii(&self) -> String {
+        let grid = self.world_grid.grid;
+        let width = grid.width() as i32;
+        let height = grid.height() as i32;
+
+        let mut ascii = String::with_capacity((height * (width * 2 + 1)) as usize);
+
+        for y in (0..height).rev() {
+            let is_shifted_row = match grid {
+                MapGrid::Hex(hex_grid) => match (hex_grid.layout.orientation, hex_grid.offset) {
+                    (HexOrientation::Pointy, Offset::Odd) => y % 2 == 1,
+                    (HexOrientation::Pointy, Offset::Even) => y % 2 == 0,
+                    (HexOrientation::Flat, _) => false,
+                },
+                MapGrid::Square(_) => false,
+            };
+
+            if is_shifted_row {
+                ascii.push(' ');
+            }
+
+            for x in 0..width {
+                let tile = Tile::from_offset(OffsetCoordinate::new(x, y), grid);
+                if x > 0 {
+                    ascii.push(' ');
+                }
+                ascii.push(self.ascii_symbol(tile));
+            }
+
+            ascii.push('\n');
+        }
+
+        ascii
+    }
+
+    /// Returns the character used by [`TileMap::to_ascii`] to render the tile.
+    fn ascii_symbol(&self, tile: Tile) -> char {
+        match (tile.terrain_type(self), tile.base_terrain(self)) {
+            (TerrainType::Mountain, _) => '^',
+            (TerrainType::Hill, _) => '#',
+            (_, BaseTerrain::Ocean) => '~',
+            (_, BaseTerrain::Coast) => '-',
+            (_, BaseTerrain::Lake) => 'o',
+            (_, BaseTerrain::Grassland) => '.',
+            (_, BaseTerrain::Plain) => ',',
+            (_, BaseTerrain::Desert) => ':',
+            (_, BaseTerrain::Tundra) => '_',
+            (_, BaseTerrain::Snow) => '*',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        grid::{GridSize, HexGrid, WorldSizeType},
+        map_parameters::{MapParametersBuilder, WorldGrid},
+        ruleset::enums::{BaseTerrain, TerrainType},
+        test_utils::TEST_SEED,
+        tile::Tile,
+        tile_map::TileMap,
+    };
+
+    /// Tests the output of `TileMap::to_ascii` on a Duel map.
+    #[test]
+    fn test_to_ascii() {
+        let world_size = WorldSizeType::Duel;
+        let grid = HexGrid {
+            size: HexGrid::default_size(world_size),
+            ..HexGrid::default()
+        };
+        let map_parameters = MapParametersBuilder::new(WorldGrid::new(grid, world_size))
+            .seed(TEST_SEED)
+            .build();
+        let mut map = TileMap::new(&map_parameters);
+
+        let tiles = [
+            (TerrainType::Mountain, BaseTerrain::Grassland),
+            (TerrainType::Hill, BaseTerrain::Plain),
+            (TerrainType::Flatland, BaseTerrain::Grassland),
+            (TerrainType::Flatland, BaseTerrain::Desert),
+            (TerrainType::Water, BaseTerrain::Coast),
+            (TerrainType::Water, BaseTerrain::Lake),
+        ];
+        for (index, (terrain_type, base_terrain)) in tiles.into_iter().enumerate() {
+            let tile = Tile::new(index);
+            tile.set_terrain_type(&mut map, terrain_type);
+            tile.set_base_terrain(&mut map, base_terrain);
+        }
+
+        let ascii = map.to_ascii();
+        let lines: Vec<_> = ascii.lines().collect();
+
+        assert_eq!(lines.len(), 24);
+        // The northernmost row is odd, so it is shifted.
+        assert_eq!(lines[0], format!(" ~{}", " ~".repeat(39)));
+        assert_eq!(lines[23], format!("^ # . : - o{}", " ~".repeat(34)));
+    }
+}
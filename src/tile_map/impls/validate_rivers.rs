@@ -0,0 +1,146 @@
+use crate::{
+    grid::{Grid, OffsetCoordinate},
+    tile::Tile,
+    tile_map::{
+        River, RiverEdge, RiverError, TileMap, next_flow_directions, river_flow_directions,
+    },
+};
+
+impl TileMap {
+    /// Checks every river in [`TileMap::river_list`] and returns the errors found, ordered by the river index.
+    ///
+    /// A river is valid when:
+    /// - The flow direction of every edge is a direction rivers can flow along on the grid,
+    ///   i.e. a corner direction on a hex grid, or an edge direction on a square grid.
+    /// - Every edge starts at the corner where the previous edge ends, see [`RiverEdge::start_and_end_corner_directions`].
+    ///   The corners are compared across the wrapping edges of the map as well.
+    /// - Every edge follows the previous edge like the rivers generated by [`TileMap::add_rivers`],
+    ///   i.e. it turns to the left or right, or goes straight on a square grid.
+    ///
+    /// Only the first invalid edge of each river is reported, because the edges after it can't be checked against it.
+    pub fn validate_rivers(&self) -> Vec<RiverError> {
+        self.river_list
+            .iter()
+            .enumerate()
+            .filter_map(|(river_index, river)| self.validate_river(river_index, river))
+            .collect()
+    }
+
+    /// Drops the invalid edges found by [`TileMap::validate_rivers`] from the rivers.
+    ///
+    /// Each broken river is truncated at its first invalid edge, so only the valid part from its source is kept.
+    /// The rivers which become empty are removed from [`TileMap::river_list`], so the indices of the rivers after them change.
+    pub fn repair_rivers(&mut self) {
+        let river_errors = self.validate_rivers();
+
+        for river_error in river_errors {
+            self.river_list[river_error.river_index()].truncate(river_error.edge_index());
+        }
+
+        self.river_list.retain(|river| !river.is_empty());
+    }
+
+    /// Returns the error of the first invalid edge in `river`, or `None` if the river is valid.
+    fn validate_river(&self, river_index: usize, river: &River) -> Option<RiverError> {
+        let grid = self.world_grid.grid;
+
+        let mut previous_edge: Option<&RiverEdge> = None;
+
+        for (edge_index, river_edge) in river.iter().enumerate() {
+            if !river_flow_directions(grid).contains(&river_edge.flow_direction) {
+                return Some(RiverError::InvalidFlowDirection(river_index, edge_index));
+            }
+
+            if let Some(previous_edge) = previous_edge {
+                if !next_flow_directions(previous_edge.flow_direction, grid)
+                    .contains(&river_edge.flow_direction)
+                {
+                    return Some(RiverError::InconsistentFlow(river_index, edge_index));
+                }
+
+                if !self.is_continuous(previous_edge, river_edge) {
+                    return Some(RiverError::Discontinuous(river_index, edge_index));
+                }
+            }
+
+            previous_edge = Some(river_edge);
+        }
+
+        None
+    }
+
+    /// Checks if `next_edge` starts at the corner where `previous_edge` ends.
+    fn is_continuous(&self, previous_edge: &RiverEdge, next_edge: &RiverEdge) -> bool {
+        let grid = self.world_grid.grid;
+
+        // The tile of the next edge touches the shared corner, so it is within distance 2 of the tile of the previous edge.
+        // The distance is 2 rather than 1 because a square only touches its diagonal neighbors at a corner.
+        // Its offset coordinate is computed from the offset coordinate of the previous edge without wrapping,
+        // so the corners can be compared across the wrapping edges of the map.
+        let previous_offset = previous_edge.tile.to_offset(grid);
+        let next_offsets: Vec<OffsetCoordinate> = grid
+            .offsets_within_distance(previous_offset, 2)
+            .into_iter()
+            .filter(|&offset_coordinate| {
+                grid.grid_coordinate_to_cell(offset_coordinate)
+                    .map(Tile::from_cell)
+                    == Some(next_edge.tile)
+            })
+            .collect();
+
+        let [_, previous_end] = previous_edge.start_and_end_corner_directions(grid);
+        let [next_start, _] = next_edge.start_and_end_corner_directions(grid);
+
+        let previous_end_corner = grid.corner(previous_offset, previous_end);
+
+        next_offsets.into_iter().any(|next_offset| {
+            let next_start_corner = grid.corner(next_offset, next_start);
+            // Corners computed from different tile centers may differ slightly because of floating point errors.
+            previous_end_corner.abs_diff_eq(next_start_corner, 1e-3)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::generate_test_map, tile::Tile};
+
+    /// Tests that the generated rivers are valid, and [`TileMap::validate_rivers`] flags the rivers broken on purpose.
+    #[test]
+    fn test_validate_rivers() {
+        use crate::tile_map::RiverError;
+
+        let mut map = generate_test_map();
+        assert_eq!(map.validate_rivers(), vec![]);
+
+        let river_index = map
+            .river_list
+            .iter()
+            .position(|river| river.len() >= 3)
+            .unwrap();
+        let river_count = map.river_list.len();
+
+        // The second edge flows back.
+        let river = &mut map.river_list[river_index];
+        let flow_direction = river[1].flow_direction;
+        river[1].flow_direction = river[0].flow_direction.opposite();
+        assert_eq!(
+            map.validate_rivers(),
+            vec![RiverError::InconsistentFlow(river_index, 1)]
+        );
+        map.river_list[river_index][1].flow_direction = flow_direction;
+
+        // The third edge is moved to a tile far away.
+        let river = &mut map.river_list[river_index];
+        river[2].tile = Tile::new((river[1].tile.index() + 20) % map.terrain_type_list.len());
+        assert_eq!(
+            map.validate_rivers(),
+            vec![RiverError::Discontinuous(river_index, 2)]
+        );
+
+        map.repair_rivers();
+        assert_eq!(map.validate_rivers(), vec![]);
+        assert_eq!(map.river_list[river_index].len(), 2);
+        assert_eq!(map.river_list.len(), river_count);
+    }
+}
@@ -28,17 +28,27 @@
 //! Different layers have different ripple behaviors.
 //! See [`TileMap::layer_data`] and [`TileMap::place_impact_and_ripples`] for detailed implementation.
 
-use crate::{grid::*, map_parameters::*, ruleset::enums::*, tile::Tile};
+use crate::{
+    grid::*,
+    map_parameters::*,
+    ruleset::{RegionType, Ruleset, Yields, enums::*},
+    tile::Tile,
+};
 use arrayvec::ArrayVec;
 use enum_map::{Enum, EnumMap, enum_map};
-use rand::{RngExt, SeedableRng, rngs::StdRng};
+use glam::Vec2;
+use rand::{Rng, RngExt, SeedableRng, rngs::StdRng, seq::SliceRandom};
 use std::{
     cmp::{max, min},
-    collections::BTreeMap,
+    collections::{BTreeMap, VecDeque},
 };
 
+#[cfg(feature = "export")]
+pub mod export;
+mod field;
 mod impls;
 
+pub use field::Field;
 pub(crate) use impls::*;
 
 #[derive(PartialEq, Debug)]
@@ -46,6 +56,11 @@ pub struct TileMap {
     /// Random number generator seeded for reproducible map generation.
     pub random_number_generator: StdRng,
 
+    /// The seed from which the random number generator of each subsystem is derived.
+    /// It is `None` when [`MapParameters::subsystem_rng`] is `false`.
+    /// See [`TileMap::subsystem_rng`] for more information.
+    subsystem_seed: Option<u64>,
+
     /// World grid configuration including size, orientation, and wrap settings.
     pub world_grid: WorldGrid,
 
@@ -56,6 +71,12 @@ pub struct TileMap {
     /// Indexed by [`Tile::index()`].
     pub terrain_type_list: Vec<TerrainType>,
 
+    /// Elevation for each tile, in the range of **[0, 255]**.
+    /// It is the height of the continents fractal used to generate terrain types,
+    /// tiles whose elevation is not greater than the water threshold are water (except tectonic islands).
+    /// Indexed by [`Tile`].
+    pub elevation_list: Field<u8>,
+
     /// Base terrain (Ocean/Coast/Grassland/etc.) for each tile.
     /// Indexed by [`Tile::index()`].
     pub base_terrain_list: Vec<BaseTerrain>,
@@ -80,12 +101,19 @@ pub struct TileMap {
     /// Indexed by [`Tile::index()`].
     pub landmass_id_list: Vec<usize>,
 
+    /// Water body ID for each tile, `None` for land tiles.
+    /// Indexed by [`Tile::index()`].
+    pub water_body_id_list: Vec<Option<usize>>,
+
     /// List of all areas (connected regions). Index matches area IDs.
     pub area_list: Vec<Area>,
 
     /// List of all landmasses. Index matches landmass IDs.
     pub landmass_list: Vec<Landmass>,
 
+    /// List of all water bodies. Index matches water body IDs.
+    pub water_body_list: Vec<WaterBody>,
+
     /// Mapping of civilization starting tiles to their assigned nations.
     pub starting_tile_and_civilization: BTreeMap<Tile, Nation>,
 
@@ -127,6 +155,9 @@ pub struct TileMap {
 
     /// Tracks luxury resource role assignments (region, city-state, special, random, unused).
     luxury_resource_role: LuxuryResourceRole,
+
+    /// How the ripples of the resources add up where they overlap, see [`MapParameters::ripple_config`].
+    ripple_config: RippleConfig,
 }
 
 impl TileMap {
@@ -146,11 +177,17 @@ impl TileMap {
     ///
     /// Allocates vectors with capacity equal to total tile count (width × height).
     pub fn new(map_parameters: &MapParameters) -> Self {
-        let random_number_generator = StdRng::seed_from_u64(map_parameters.seed);
+        let random_number_generator = map_parameters.seeded_rng();
+
+        // Draw the subsystem seed from another generator with the same seed,
+        // so that `random_number_generator` is not advanced.
+        let subsystem_seed = map_parameters
+            .subsystem_rng
+            .then(|| map_parameters.seeded_rng().next_u64());
 
         let world_grid = map_parameters.world_grid;
-        let height = world_grid.grid.size.height;
-        let width = world_grid.grid.size.width;
+        let height = world_grid.grid.size().height;
+        let width = world_grid.grid.size().width;
 
         let size = (height * width) as usize;
 
@@ -162,23 +199,63 @@ impl TileMap {
 
         Self {
             random_number_generator,
+            subsystem_seed,
             world_grid,
             river_list: Vec::new(),
             terrain_type_list: vec![TerrainType::Water; size],
+            elevation_list: Field::new(size, 0),
             base_terrain_list: vec![BaseTerrain::Ocean; size],
             feature_list: vec![None; size],
             natural_wonder_list: vec![None; size],
             resource_list: vec![None; size],
             area_id_list: Vec::with_capacity(size),
             landmass_id_list: Vec::with_capacity(size),
+            water_body_id_list: Vec::with_capacity(size),
             area_list: Vec::new(),
             landmass_list: Vec::new(),
+            water_body_list: Vec::new(),
             region_list,
             layer_data,
             starting_tile_and_civilization: BTreeMap::new(),
             starting_tile_and_city_state: BTreeMap::new(),
             luxury_resource_role: LuxuryResourceRole::default(),
             region_exclusive_luxury_list: ArrayVec::new(),
+            ripple_config: map_parameters.ripple_config,
+        }
+    }
+
+    /// Returns a random number generator derived from the seed of the map and `namespace`.
+    ///
+    /// Each subsystem of the map generation (such as rivers, features, regions, natural wonders and resources)
+    /// draws from its own namespaced random number generator, so changing how many random numbers one subsystem consumes
+    /// does not shift the random numbers drawn by the others.
+    /// The same seed and namespace always return the same random number generator.
+    ///
+    /// Returns `None` when [`MapParameters::subsystem_rng`] is `false`.
+    pub(crate) fn subsystem_rng(&self, namespace: &str) -> Option<StdRng> {
+        let namespace_hash = fnv1a_hash(FNV_OFFSET_BASIS, namespace.as_bytes());
+        self.subsystem_seed
+            .map(|subsystem_seed| StdRng::seed_from_u64(subsystem_seed ^ namespace_hash))
+    }
+
+    /// Returns a copy of `items` shuffled with [`TileMap::random_number_generator`].
+    ///
+    /// It draws the same random numbers as cloning `items` and calling [`SliceRandom::shuffle`] on it,
+    /// so the call sites can be converted without changing the generated map.
+    pub fn shuffled<T: Clone>(&mut self, items: &[T]) -> Vec<T> {
+        let mut items = items.to_vec();
+        items.shuffle(&mut self.random_number_generator);
+        items
+    }
+
+    /// Replaces [`TileMap::random_number_generator`] with [`TileMap::subsystem_rng`] of `namespace`.
+    ///
+    /// It is called before each subsystem in [`Generator::generate`](crate::map_generator::Generator::generate).
+    /// The steps before the first subsystem, such as terrain generation, still draw from the random number generator seeded by [`MapParameters::seed`].
+    /// It does nothing when [`MapParameters::subsystem_rng`] is `false`, so the generation keeps drawing from one random number generator.
+    pub(crate) fn use_subsystem_rng(&mut self, namespace: &str) {
+        if let Some(subsystem_rng) = self.subsystem_rng(namespace) {
+            self.random_number_generator = subsystem_rng;
         }
     }
 
@@ -191,6 +268,556 @@ impl TileMap {
         (0..((size.width * size.height) as usize)).map(Tile::new)
     }
 
+    /// Checks if the tile is on the outermost ring of the map along the axes that do not wrap.
+    pub(crate) fn is_on_map_border(&self, tile: Tile) -> bool {
+        let grid = self.world_grid.grid;
+        let [x, y] = tile.to_offset(grid).to_array();
+
+        (!grid.wrap_x() && (x == 0 || x == grid.width() as i32 - 1))
+            || (!grid.wrap_y() && (y == 0 || y == grid.height() as i32 - 1))
+    }
+
+    /// Returns the list of regions the map is divided into.
+    ///
+    /// The index of each element is the region index. It is empty before [`TileMap::generate_regions`] is called.
+    pub fn region_list(&self) -> &[Region] {
+        &self.region_list
+    }
+
+    /// Returns the type of each region, which can be used to label the regions in a UI.
+    ///
+    /// The index of each element is the region index, the same as [`TileMap::region_list`].
+    /// It is empty before [`TileMap::generate_regions`] is called.
+    pub fn region_types(&self) -> Vec<RegionType> {
+        self.region_list
+            .iter()
+            .map(|region| region.region_type)
+            .collect()
+    }
+
+    /// Returns the role of each luxury resource, such as which luxury types are exclusive to regions and which are disabled.
+    ///
+    /// It is empty before [`TileMap::assign_luxury_roles`] is called. See [`LuxuryResourceRole`] for more information.
+    pub fn luxury_resource_role(&self) -> &LuxuryResourceRole {
+        &self.luxury_resource_role
+    }
+
+    /// Returns the start score of each region, which can be used to flag maps whose starting tiles are lopsided.
+    ///
+    /// The index of each element is the region index. See [`RegionStartScore`] for more information.
+    /// It is empty before [`TileMap::balance_and_assign_start_locations_of_civilization`] is called.
+    pub fn region_start_scores(&self) -> Vec<RegionStartScore> {
+        self.region_list
+            .iter()
+            .filter_map(|region| region.start_score.get().copied())
+            .collect()
+    }
+
+    /// Returns the index of the region which `tile` belongs to, or `None` if it belongs to no region.
+    ///
+    /// A tile belongs to a region when it is in [`Region::rectangle`], and it is in the area [`Region::area_id`] if that is `Some`.
+    /// It is the same membership used to choose the starting tile of the region.
+    /// It always returns `None` before [`TileMap::generate_regions`] is called.
+    pub fn region_of_tile(&self, tile: Tile) -> Option<usize> {
+        let grid = self.world_grid.grid;
+
+        self.region_list.iter().position(|region| {
+            region.rectangle.contains(tile.to_cell(), &grid)
+                && region
+                    .area_id
+                    .is_none_or(|area_id| tile.area_id(self) == area_id)
+        })
+    }
+
+    /// Returns how the starting tile of each region was placed, which is useful to debug the maps with bad starting tiles.
+    ///
+    /// The elements are sorted by the region index. See [`StartPlacement`] for more information.
+    /// It is empty before [`TileMap::choose_starting_tiles_of_civilization`] is called.
+    pub fn last_start_placement_reasons(&self) -> Vec<StartPlacementOutcome> {
+        self.region_list
+            .iter()
+            .enumerate()
+            .filter_map(|(region_index, region)| {
+                region
+                    .start_placement
+                    .get()
+                    .map(|&start_placement| StartPlacementOutcome {
+                        region_index,
+                        start_placement,
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the starting tile of each civilization as an offset coordinate, together with the civilization.
+    ///
+    /// The list is sorted by the tile index, i.e. in the same order as [`TileMap::starting_tile_and_civilization`].
+    pub fn civilization_starts(&self) -> Vec<(OffsetCoordinate, Nation)> {
+        let grid = self.world_grid.grid;
+        self.starting_tile_and_civilization
+            .iter()
+            .map(|(tile, &civilization)| (tile.to_offset(grid), civilization))
+            .collect()
+    }
+
+    /// Returns the starting tile of each city state as an offset coordinate, together with the city state.
+    ///
+    /// The list is sorted by the tile index, i.e. in the same order as [`TileMap::starting_tile_and_city_state`].
+    pub fn city_state_starts(&self) -> Vec<(OffsetCoordinate, Nation)> {
+        let grid = self.world_grid.grid;
+        self.starting_tile_and_city_state
+            .iter()
+            .map(|(tile, &city_state)| (tile.to_offset(grid), city_state))
+            .collect()
+    }
+
+    /// Returns all land tiles whose elevation is not greater than `threshold`.
+    ///
+    /// These are the land tiles that would become water if the sea level rose to `threshold`.
+    /// Raising `threshold` never removes a tile from the returned list.
+    pub fn tiles_below_elevation(&self, threshold: u8) -> Vec<Tile> {
+        self.all_tiles()
+            .filter(|tile| tile.is_land(self) && tile.elevation(self) <= threshold)
+            .collect()
+    }
+
+    /// Returns the tiles whose terrain type is `terrain_type`, in the order of the tile index.
+    pub fn tiles_with_terrain_type(
+        &self,
+        terrain_type: TerrainType,
+    ) -> impl Iterator<Item = Tile> + '_ {
+        self.all_tiles()
+            .filter(move |tile| tile.terrain_type(self) == terrain_type)
+    }
+
+    /// Returns the tiles whose base terrain is `base_terrain`, in the order of the tile index.
+    pub fn tiles_with_base_terrain(
+        &self,
+        base_terrain: BaseTerrain,
+    ) -> impl Iterator<Item = Tile> + '_ {
+        self.all_tiles()
+            .filter(move |tile| tile.base_terrain(self) == base_terrain)
+    }
+
+    /// Returns the tiles which have `feature`, in the order of the tile index.
+    pub fn tiles_with_feature(&self, feature: Feature) -> impl Iterator<Item = Tile> + '_ {
+        self.all_tiles()
+            .filter(move |tile| tile.feature(self) == Some(feature))
+    }
+
+    /// Returns the tiles which have `resource` in any quantity, in the order of the tile index.
+    pub fn tiles_with_resource(&self, resource: Resource) -> impl Iterator<Item = Tile> + '_ {
+        self.all_tiles().filter(move |tile| {
+            tile.resource(self)
+                .is_some_and(|(tile_resource, _)| tile_resource == resource)
+        })
+    }
+
+    /// Returns all the land tiles which have fresh water, in the order of the tile index.
+    ///
+    /// They are the tiles for which [`Tile::is_freshwater`] is true, i.e. the land tiles along a river or adjacent to a lake or an oasis,
+    /// and the oasis tiles themselves, which are the source of the fresh water.
+    ///
+    /// It is computed in one pass over the rivers and the tiles,
+    /// so it is much faster than calling [`Tile::is_freshwater`] on every tile.
+    pub fn freshwater_tiles(&self) -> Vec<Tile> {
+        let grid = self.world_grid.grid;
+
+        let mut is_freshwater = vec![false; self.terrain_type_list.len()];
+
+        // A river edge gives fresh water to the tiles on both sides of it.
+        for river_edge in self.river_list.iter().flatten() {
+            is_freshwater[river_edge.tile.index()] = true;
+            if let Some(neighbor_tile) = river_edge
+                .tile
+                .neighbor_tile(river_edge.edge_direction(grid), grid)
+            {
+                is_freshwater[neighbor_tile.index()] = true;
+            }
+        }
+
+        for tile in self.all_tiles() {
+            let is_oasis = tile.feature(self) == Some(Feature::Oasis);
+            if is_oasis || tile.base_terrain(self) == BaseTerrain::Lake {
+                for neighbor_tile in tile.neighbor_tiles(grid) {
+                    is_freshwater[neighbor_tile.index()] = true;
+                }
+            }
+            if is_oasis {
+                is_freshwater[tile.index()] = true;
+            }
+        }
+
+        self.all_tiles()
+            .filter(|tile| is_freshwater[tile.index()] && tile.is_land(self))
+            .collect()
+    }
+
+    /// Returns the number of land tiles in the map, including hills and mountains.
+    pub fn land_tile_count(&self) -> usize {
+        self.all_tiles().filter(|tile| tile.is_land(self)).count()
+    }
+
+    /// Returns whether each tile is land, which can be used to render a minimap.
+    ///
+    /// The element is `true` when the tile is land, and `false` when the tile is water.
+    /// The index of each element is the tile index, just like the other per-tile lists such as [`TileMap::terrain_type_list`].
+    pub fn land_water_mask(&self) -> Vec<bool> {
+        self.all_tiles().map(|tile| tile.is_land(self)).collect()
+    }
+
+    /// Returns [`TileMap::land_water_mask`] packed into bytes, 1 bit per tile.
+    ///
+    /// The tile with index `i` is stored in bit `i % 8` (the least significant bit is bit 0) of byte `i / 8`.
+    /// The bit is `1` when the tile is land. The unused bits of the last byte are `0`.
+    pub fn land_water_bitmap(&self) -> Vec<u8> {
+        let mut bitmap = vec![0; self.terrain_type_list.len().div_ceil(8)];
+
+        for tile in self.all_tiles().filter(|tile| tile.is_land(self)) {
+            bitmap[tile.index() / 8] |= 1 << (tile.index() % 8);
+        }
+
+        bitmap
+    }
+
+    /// Returns the distance from each tile to the nearest sea tile, that is a tile with [`BaseTerrain::Coast`] or [`BaseTerrain::Ocean`].
+    ///
+    /// Sea tiles get `0`, the tiles next to the sea get `1`, and so on.
+    /// Lakes are not the sea, so a lake gets its distance to the sea just like land.
+    /// The distance is the number of rings between the tile and the nearest sea tile, counted with grid neighbors, so it respects the wrapping of the map.
+    /// If there is no sea on the map, all other tiles get [`u32::MAX`].
+    pub fn coast_distance_list(&self) -> Field<u32> {
+        let grid = self.world_grid.grid;
+
+        let mut coast_distance_list = Field::new(self.terrain_type_list.len(), u32::MAX);
+
+        // Multi-source BFS from all sea tiles.
+        let mut queue = VecDeque::new();
+        for tile in self.all_tiles().filter(|tile| {
+            matches!(
+                tile.base_terrain(self),
+                BaseTerrain::Coast | BaseTerrain::Ocean
+            )
+        }) {
+            coast_distance_list[tile] = 0;
+            queue.push_back(tile);
+        }
+
+        while let Some(tile) = queue.pop_front() {
+            let distance = coast_distance_list[tile] + 1;
+            for neighbor_tile in tile.neighbor_tiles(grid) {
+                if coast_distance_list[neighbor_tile] == u32::MAX {
+                    coast_distance_list[neighbor_tile] = distance;
+                    queue.push_back(neighbor_tile);
+                }
+            }
+        }
+
+        coast_distance_list
+    }
+
+    /// Returns the nearest tile to `from` which satisfies `predicate`, and the distance between them.
+    ///
+    /// The tiles are searched ring by ring with [`Tile::tiles_at_distance`], starting from `from` itself at distance `0`,
+    /// so it can be used to find the nearest coast, the nearest mountain, etc.
+    /// The distance respects the wrapping of the map, see [`Tile::distance_to`].
+    /// When several tiles at the same distance satisfy `predicate`, the first one in the ring is returned.
+    ///
+    /// Returns `None` if no tile on the map satisfies `predicate`.
+    pub fn nearest_tile_matching(
+        &self,
+        from: Tile,
+        predicate: impl Fn(Tile) -> bool,
+    ) -> Option<(Tile, u32)> {
+        let grid = self.world_grid.grid;
+
+        if predicate(from) {
+            return Some((from, 0));
+        }
+
+        for distance in 1.. {
+            // On a wrapping map, a ring wider than half of the map wraps onto tiles which are actually closer to `from`,
+            // so only the tiles whose real distance is `distance` belong to the ring.
+            let mut ring = from
+                .tiles_at_distance(distance, grid)
+                .filter(|&tile| from.distance_to(tile, self) == distance)
+                .peekable();
+
+            // Every tile farther away has a neighbor one step closer, so an empty ring means the whole map has been searched.
+            ring.peek()?;
+
+            if let Some(tile) = ring.find(|&tile| predicate(tile)) {
+                return Some((tile, distance));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the choke points of the land, sorted by the tile index.
+    ///
+    /// A choke point is a land tile whose removal would split the land it is on into more pieces,
+    /// i.e. an articulation point of the graph in which the land tiles are connected to their land neighbors.
+    /// For example, the tiles of a 1-tile wide isthmus between two continents are choke points.
+    /// The connections respect the wrapping of the map.
+    pub fn land_choke_points(&self) -> Vec<Tile> {
+        let grid = self.world_grid.grid;
+        let size = self.terrain_type_list.len();
+
+        let land_neighbors = |tile: Tile| -> Vec<Tile> {
+            tile.neighbor_tiles(grid)
+                .filter(|neighbor_tile| neighbor_tile.is_land(self))
+                .collect()
+        };
+
+        // Tarjan's algorithm with an explicit stack, so large landmasses don't overflow the call stack.
+        // `discovery_time` is `u32::MAX` for the tiles which are not visited yet.
+        let mut discovery_time = vec![u32::MAX; size];
+        let mut low_time = vec![u32::MAX; size];
+        let mut is_choke_point = vec![false; size];
+        let mut time = 0;
+
+        for root in self.all_tiles().filter(|tile| tile.is_land(self)) {
+            if discovery_time[root.index()] != u32::MAX {
+                continue;
+            }
+
+            discovery_time[root.index()] = time;
+            low_time[root.index()] = time;
+            time += 1;
+
+            let mut root_child_count = 0;
+            // Each element is a tile, its parent, its land neighbors and the index of the next neighbor to visit.
+            let mut stack = vec![(root, None, land_neighbors(root), 0)];
+
+            while let Some((tile, parent, neighbors, next_index)) = stack.last_mut() {
+                let tile = *tile;
+                let parent = *parent;
+
+                if let Some(&neighbor_tile) = neighbors.get(*next_index) {
+                    *next_index += 1;
+
+                    if discovery_time[neighbor_tile.index()] == u32::MAX {
+                        discovery_time[neighbor_tile.index()] = time;
+                        low_time[neighbor_tile.index()] = time;
+                        time += 1;
+                        if tile == root {
+                            root_child_count += 1;
+                        }
+                        stack.push((neighbor_tile, Some(tile), land_neighbors(neighbor_tile), 0));
+                    } else if Some(neighbor_tile) != parent {
+                        low_time[tile.index()] =
+                            low_time[tile.index()].min(discovery_time[neighbor_tile.index()]);
+                    }
+                } else {
+                    stack.pop();
+
+                    if let Some(parent) = parent {
+                        low_time[parent.index()] =
+                            low_time[parent.index()].min(low_time[tile.index()]);
+                        // No tile under `tile` connects to a tile visited before `parent`, so removing `parent` cuts them off.
+                        if parent != root
+                            && low_time[tile.index()] >= discovery_time[parent.index()]
+                        {
+                            is_choke_point[parent.index()] = true;
+                        }
+                    }
+                }
+            }
+
+            // The root is a choke point if the land beyond it can only be reached through it in more than one direction.
+            if root_child_count > 1 {
+                is_choke_point[root.index()] = true;
+            }
+        }
+
+        self.all_tiles()
+            .filter(|tile| is_choke_point[tile.index()])
+            .collect()
+    }
+
+    /// Returns the cost to move into `tile`, read from the terrain definitions of `ruleset`.
+    ///
+    /// The cost is the movement cost of the topmost element of the tile which defines one,
+    /// that is the feature, then the terrain type, then the base terrain, the same as how the game reads it.
+    /// For example, with the default ruleset grassland costs `1`, hill and forest cost `2`, a forested hill costs `2` too, and marsh costs `3`.
+    /// The cost is at least `1`, even if no element of the tile defines a movement cost such as a flatland lake.
+    ///
+    /// Returns `None` if the tile is impassable, see [`Tile::is_impassable`].
+    /// Water tiles are not impassable, units which can't enter water should check [`Tile::is_water`] themselves.
+    pub fn movement_cost(&self, tile: Tile, ruleset: &Ruleset) -> Option<u32> {
+        if tile.is_impassable(self, ruleset) {
+            return None;
+        }
+
+        // A movement cost of `0` means the element doesn't define its own cost.
+        let feature_cost = tile
+            .feature(self)
+            .map_or(0, |feature| ruleset.features[feature].movement_cost);
+        let terrain_type_cost = ruleset.terrain_types[tile.terrain_type(self)].movement_cost;
+        let base_cost = ruleset.base_terrains[tile.base_terrain(self)].movement_cost;
+
+        let movement_cost = [feature_cost, terrain_type_cost, base_cost]
+            .into_iter()
+            .find(|&movement_cost| movement_cost > 0)
+            .unwrap_or(1);
+
+        Some(movement_cost as u32)
+    }
+
+    /// Returns the yields of `tile`, read from the yields of the terrains, the feature, the natural wonder and the resource in `ruleset`.
+    ///
+    /// The yields are accumulated in this order: base terrain, terrain type, feature, natural wonder and resource.
+    /// An element whose `override_stats` is `true` replaces the yields accumulated before it, otherwise its yields are added.
+    /// For example, with the default ruleset grassland yields `2` food, a hill replaces it with `2` production,
+    /// and a forest replaces both with `1` food and `1` production.
+    ///
+    /// It is a preview of the value of the tile for tools, the improvements, buildings and other game rules are not considered.
+    pub fn tile_yield(&self, tile: Tile, ruleset: &Ruleset) -> Yields {
+        let accumulate = |yields: Yields, other_yields: Yields, override_stats: bool| {
+            if override_stats {
+                other_yields
+            } else {
+                yields + other_yields
+            }
+        };
+
+        let mut yields = ruleset.base_terrains[tile.base_terrain(self)].yields;
+
+        let terrain_type_info = &ruleset.terrain_types[tile.terrain_type(self)];
+        yields = accumulate(
+            yields,
+            terrain_type_info.yields,
+            terrain_type_info.override_stats,
+        );
+
+        if let Some(feature) = tile.feature(self) {
+            let feature_info = &ruleset.features[feature];
+            yields = accumulate(yields, feature_info.yields, feature_info.override_stats);
+        }
+
+        if let Some(natural_wonder) = tile.natural_wonder(self) {
+            let natural_wonder_info = &ruleset.natural_wonders[natural_wonder];
+            yields = accumulate(
+                yields,
+                natural_wonder_info.yields,
+                natural_wonder_info.override_stats,
+            );
+        }
+
+        if let Some((resource, _)) = tile.resource(self) {
+            yields = yields + ruleset.resources[resource].yields;
+        }
+
+        yields
+    }
+
+    /// Converts every river in [`TileMap::river_list`] into a polyline of pixel points, which is easier to draw than [`RiverEdge`]s.
+    ///
+    /// Each river edge is drawn from its start corner to its end corner, see [`RiverEdge::start_and_end_corner_directions`].
+    /// When an edge starts at the corner where the previous edge ends, the shared corner is only added once,
+    /// so a continuous river becomes one stroke with one more point than its edges.
+    ///
+    /// The points are computed with [`MapGrid::corner`], so they are in the same pixel space as the tiles.
+    /// When a river crosses the wrapped edge of the map, the two sides of the map are joined directly by a straight segment.
+    pub fn river_polylines(&self) -> Vec<Vec<Vec2>> {
+        let grid = self.world_grid.grid;
+
+        self.river_list
+            .iter()
+            .map(|river| {
+                let mut polyline: Vec<Vec2> = Vec::with_capacity(river.len() + 1);
+
+                for river_edge in river {
+                    let offset_coordinate = river_edge.tile.to_offset(grid);
+                    let [start, end] = river_edge
+                        .start_and_end_corner_directions(grid)
+                        .map(|direction| grid.corner(offset_coordinate, direction));
+
+                    // Corners computed from different tile centers may differ slightly because of floating point errors.
+                    if polyline
+                        .last()
+                        .is_none_or(|last| !last.abs_diff_eq(start, 1e-3))
+                    {
+                        polyline.push(start);
+                    }
+                    polyline.push(end);
+                }
+
+                polyline
+            })
+            .collect()
+    }
+
+    /// Returns the river mouths of the map, i.e. the coast tiles where a river flows into the sea, sorted by the tile index.
+    ///
+    /// A river ends when it reaches water, so the mouth of a river is the tile at the end corner of its last edge,
+    /// see [`RiverEdge::start_and_end_corner_directions`]. Only the tiles whose base terrain is [`BaseTerrain::Coast`] are mouths,
+    /// the rivers flowing into a lake or joining another river have no mouth.
+    ///
+    /// It is useful to render deltas or to place the resources related to rivers.
+    pub fn river_mouths(&self) -> Vec<Tile> {
+        let grid = self.world_grid.grid;
+
+        let mut river_mouths: Vec<Tile> = self
+            .river_list
+            .iter()
+            .filter_map(|river| river.last())
+            .filter_map(|river_edge| {
+                let offset_coordinate = river_edge.tile.to_offset(grid);
+                let edge_direction = river_edge.edge_direction(grid);
+                let [_, end_corner_direction] = river_edge.start_and_end_corner_directions(grid);
+                let end_corner = grid.corner(offset_coordinate, end_corner_direction);
+
+                // On hex grids, 3 tiles meet at the end corner: the 2 tiles on both sides of the edge, and the tile at the mouth.
+                // On square grids, 4 tiles meet at the end corner, the mouth is the neighbor of the edge tile among the 2 tiles ahead.
+                grid.edge_direction_array()
+                    .into_iter()
+                    .filter(|&direction| direction != edge_direction)
+                    .map(|direction| grid.neighbor_offset(offset_coordinate, direction))
+                    .find(|&neighbor_offset_coordinate| {
+                        grid.corners(neighbor_offset_coordinate)
+                            .iter()
+                            .any(|corner| corner.abs_diff_eq(end_corner, 1e-3))
+                    })
+                    .and_then(|neighbor_offset_coordinate| {
+                        grid.grid_coordinate_to_cell(neighbor_offset_coordinate)
+                    })
+                    .map(Tile::from_cell)
+            })
+            .filter(|tile| tile.base_terrain(self) == BaseTerrain::Coast)
+            .collect();
+
+        river_mouths.sort_unstable();
+        river_mouths.dedup();
+
+        river_mouths
+    }
+
+    /// Returns the impact or ripple value of `tile` on `layer`.
+    ///
+    /// It is a shortcut for reading [`TileMap::layer_data`], see it for the meaning of the values on each layer.
+    pub fn impact_value(&self, tile: Tile, layer: Layer) -> u32 {
+        self.layer_data[layer][tile.index()]
+    }
+
+    /// Returns whether `tile` is blocked on `layer`, that is, no more element of the layer can be placed on it.
+    ///
+    /// The check is the same as the placement code of the layer:
+    /// - [`Layer::CityState`], [`Layer::NaturalWonder`] and [`Layer::Marble`]: the impact value is not `0`,
+    ///   these elements are only placed on the tiles outside of any ripple.
+    /// - [`Layer::Strategic`], [`Layer::Luxury`], [`Layer::Bonus`] and [`Layer::Fish`]: the impact value is at least `98`,
+    ///   these resources are placed on the tile with the lowest ripple value when no tile is outside of any ripple.
+    /// - [`Layer::Civilization`]: the impact value is `99`, a starting tile may be placed within the ripple of another one.
+    pub fn is_blocked(&self, tile: Tile, layer: Layer) -> bool {
+        let impact_value = self.impact_value(tile, layer);
+        match layer {
+            Layer::CityState | Layer::NaturalWonder | Layer::Marble => impact_value != 0,
+            Layer::Strategic | Layer::Luxury | Layer::Bonus | Layer::Fish => impact_value >= 98,
+            Layer::Civilization => impact_value == 99,
+        }
+    }
+
     /// Place impact and ripples for a given tile and layer.
     ///
     /// When you add an element (such as a starting tile of civilization, a city state, a natural wonder, a marble, or a resource...) to the map,
@@ -228,13 +855,10 @@ impl TileMap {
             }
             Layer::CityState => {
                 self.place_impact_and_ripples_for_resource(tile, Layer::CityState, 4);
-
-                self.place_impact_and_ripples_for_resource(tile, Layer::Luxury, 3);
-                // Strategic layer, should be at start point only. That means if we are placing a city state at current tile, forbid to place strategic resources on it.
-                self.place_impact_and_ripples_for_resource(tile, Layer::Strategic, 0);
-                self.place_impact_and_ripples_for_resource(tile, Layer::Bonus, 3);
-                self.place_impact_and_ripples_for_resource(tile, Layer::Fish, 3);
-                self.place_impact_and_ripples_for_resource(tile, Layer::Marble, 3);
+                // Natural wonders should not be placed on or next to city states,
+                // when they are placed after the city states, see `WonderResourceOrder::ResourcesFirst`.
+                self.place_impact_and_ripples_for_resource(tile, Layer::NaturalWonder, 1);
+                self.place_impact_on_resource_layers(tile, Layer::CityState);
             }
             Layer::NaturalWonder => {
                 self.place_impact_and_ripples_for_resource(
@@ -242,34 +866,16 @@ impl TileMap {
                     Layer::NaturalWonder,
                     self.world_grid.size().height / 5,
                 );
-                let natural_wonder = tile.natural_wonder(self);
-                if let Some(natural_wonder) = natural_wonder {
-                    match natural_wonder {
-                        NaturalWonder::MountFuji => {
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Strategic, 0);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Luxury, 0);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Bonus, 0);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::CityState, 0);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Marble, 1);
-                        }
-                        NaturalWonder::Krakatoa | NaturalWonder::GreatBarrierReef => {
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Strategic, 1);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Luxury, 1);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Bonus, 1);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::CityState, 1);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Marble, 1);
-                            // The tile beneath natural wonders on water should block fish resources.
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Fish, 1);
-                        }
-                        _ => {
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Strategic, 1);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Luxury, 1);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Bonus, 1);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::CityState, 1);
-                            self.place_impact_and_ripples_for_resource(tile, Layer::Marble, 1);
-                        }
-                    }
+                // City states should not be placed on or next to natural wonders.
+                if let Some(natural_wonder) = tile.natural_wonder(self) {
+                    let radius = if natural_wonder == NaturalWonder::MountFuji {
+                        0
+                    } else {
+                        1
+                    };
+                    self.place_impact_and_ripples_for_resource(tile, Layer::CityState, radius);
                 }
+                self.place_impact_on_resource_layers(tile, Layer::NaturalWonder);
             }
             Layer::Marble => {
                 self.place_impact_and_ripples_for_resource(tile, Layer::Luxury, 1);
@@ -279,6 +885,59 @@ impl TileMap {
         }
     }
 
+    /// Places the impact and ripples of a civilization, a city state or a natural wonder on the resource layers.
+    ///
+    /// The resource layers are [`Layer::Strategic`], [`Layer::Luxury`], [`Layer::Bonus`], [`Layer::Fish`] and [`Layer::Marble`].
+    /// `source` is the layer of the element on `tile`, it should be [`Layer::Civilization`], [`Layer::CityState`] or [`Layer::NaturalWonder`].
+    ///
+    /// This is called by [`TileMap::place_impact_and_ripples`] when the element is placed,
+    /// and by [`TileMap::regenerate_resources`] when the resource layers are reset.
+    pub(crate) fn place_impact_on_resource_layers(&mut self, tile: Tile, source: Layer) {
+        match source {
+            Layer::Civilization => {
+                self.place_impact_and_ripples_for_resource(tile, Layer::Luxury, 3);
+                // Strategic layer, should be at start point only. That means if we are placing a civilization at current tile, forbid to place strategic resources on it.
+                self.place_impact_and_ripples_for_resource(tile, Layer::Strategic, 0);
+                self.place_impact_and_ripples_for_resource(tile, Layer::Bonus, 3);
+                self.place_impact_and_ripples_for_resource(tile, Layer::Fish, 3);
+            }
+            Layer::CityState => {
+                self.place_impact_and_ripples_for_resource(tile, Layer::Luxury, 3);
+                // Strategic layer, should be at start point only. That means if we are placing a city state at current tile, forbid to place strategic resources on it.
+                self.place_impact_and_ripples_for_resource(tile, Layer::Strategic, 0);
+                self.place_impact_and_ripples_for_resource(tile, Layer::Bonus, 3);
+                self.place_impact_and_ripples_for_resource(tile, Layer::Fish, 3);
+                self.place_impact_and_ripples_for_resource(tile, Layer::Marble, 3);
+            }
+            Layer::NaturalWonder => match tile.natural_wonder(self) {
+                Some(NaturalWonder::MountFuji) => {
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Strategic, 0);
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Luxury, 0);
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Bonus, 0);
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Marble, 1);
+                }
+                Some(NaturalWonder::Krakatoa | NaturalWonder::GreatBarrierReef) => {
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Strategic, 1);
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Luxury, 1);
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Bonus, 1);
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Marble, 1);
+                    // The tile beneath natural wonders on water should block fish resources.
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Fish, 1);
+                }
+                Some(_) => {
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Strategic, 1);
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Luxury, 1);
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Bonus, 1);
+                    self.place_impact_and_ripples_for_resource(tile, Layer::Marble, 1);
+                }
+                None => {}
+            },
+            _ => unreachable!(
+                "Only civilizations, city states and natural wonders impact the resource layers."
+            ),
+        }
+    }
+
     // function AssignStartingPlots:PlaceImpactAndRipples
     /// Places the impact and ripple values for a starting tile of civilization.
     ///
@@ -290,11 +949,7 @@ impl TileMap {
         let ripple_values = [97, 95, 92, 89, 69, 57, 24, 15];
 
         // Start points need to impact the resource layers.
-        self.place_impact_and_ripples_for_resource(tile, Layer::Luxury, 3);
-        // Strategic layer, should be at start point only. That means if we are placing a civilization at current tile, forbid to place strategic resources on it.
-        self.place_impact_and_ripples_for_resource(tile, Layer::Strategic, 0);
-        self.place_impact_and_ripples_for_resource(tile, Layer::Bonus, 3);
-        self.place_impact_and_ripples_for_resource(tile, Layer::Fish, 3);
+        self.place_impact_on_resource_layers(tile, Layer::Civilization);
         // Natural Wonders layer, set a minimum distance of 5 tiles (4 ripples) away.
         self.place_impact_and_ripples_for_resource(tile, Layer::NaturalWonder, 4);
 
@@ -367,7 +1022,7 @@ impl TileMap {
             return;
         }
 
-        if radius > 0 && radius < (grid.size.height / 2) {
+        if radius > 0 && radius < (grid.size().height / 2) {
             for distance in 1..=radius {
                 // `distance` is the distance from the center tile to the current tile.
                 // The larger the distance, the smaller the ripple value.
@@ -383,8 +1038,11 @@ impl TileMap {
                                 if current_value != 0 {
                                     // First choose the greater of the two, existing value or current ripple.
                                     let stronger_value = max(current_value, ripple_value);
-                                    // Now increase it by 2 to reflect that multiple civs are in range of this plot.
-                                    let overlap_value = min(50, stronger_value + 2);
+                                    // Now increase it (by 2 by default) to reflect that multiple civs are in range of this plot.
+                                    let overlap_value = min(
+                                        self.ripple_config.overlap_cap,
+                                        stronger_value + self.ripple_config.overlap_increment,
+                                    );
                                     current_value = overlap_value;
                                 } else {
                                     current_value = ripple_value;
@@ -409,7 +1067,7 @@ impl TileMap {
     /// If successful, it returns `true`, otherwise it returns `false`.
     pub fn attempt_to_place_hill_at_tile(&mut self, tile: Tile) -> bool {
         if tile.resource(self).is_none()
-            && tile.terrain_type(self) != TerrainType::Water
+            && tile.is_land(self)
             && tile.feature(self) != Some(Feature::Forest)
             && !tile.has_river(self)
         {
@@ -644,18 +1302,48 @@ impl TileMap {
 /// # Notes
 ///
 /// In some maps, If we cannot place oil in the sea, we should increase the resource amounts on land to compensate.
+///
+/// On [`WorldSizeType::Large`] and [`WorldSizeType::Huge`] maps, the quantity of oil and iron is increased by 1,
+/// because the same quantities feel thin on such big maps.
+///
+/// For [`ResourceSetting::Custom`], the quantities of [`ResourceSetting::Standard`] are scaled by the strategic multiplier.
 pub fn get_major_strategic_resource_quantity_values(
     resource_setting: ResourceSetting,
+    world_size_type: WorldSizeType,
 ) -> (u32, u32, u32, u32, u32, u32) {
-    let (uran_amt, horse_amt, oil_amt, iron_amt, coal_amt, alum_amt) = match resource_setting {
-        ResourceSetting::Sparse => (2, 4, 5, 4, 5, 5),
-        ResourceSetting::Abundant => (4, 6, 9, 9, 10, 10),
-        _ => (4, 4, 7, 6, 7, 8), // Default
-    };
+    let (uran_amt, horse_amt, mut oil_amt, mut iron_amt, coal_amt, alum_amt) =
+        match resource_setting {
+            ResourceSetting::Sparse => (2, 4, 5, 4, 5, 5),
+            ResourceSetting::Abundant => (4, 6, 9, 9, 10, 10),
+            _ => (4, 4, 7, 6, 7, 8), // Default
+        };
+
+    if matches!(world_size_type, WorldSizeType::Large | WorldSizeType::Huge) {
+        oil_amt += 1;
+        iron_amt += 1;
+    }
+
+    let [uran_amt, horse_amt, oil_amt, iron_amt, coal_amt, alum_amt] =
+        [uran_amt, horse_amt, oil_amt, iron_amt, coal_amt, alum_amt]
+            .map(|amount| scale_strategic_resource_quantity(amount, resource_setting));
 
     (uran_amt, horse_amt, oil_amt, iron_amt, coal_amt, alum_amt)
 }
 
+/// Scales the quantity of a strategic deposit by the strategic multiplier of [`ResourceSetting::Custom`].
+///
+/// For the other resource settings, the quantity is returned unchanged.
+/// The scaled quantity is at least 1, because a deposit with no quantity is meaningless.
+fn scale_strategic_resource_quantity(quantity: u32, resource_setting: ResourceSetting) -> u32 {
+    match resource_setting {
+        ResourceSetting::Custom {
+            strategic_multiplier,
+            ..
+        } => ((quantity as f64 * strategic_multiplier.as_f64()).round() as u32).max(1),
+        _ => quantity,
+    }
+}
+
 /// The `Layer` enum represents a layer associated with an element added to the map.
 /// Each element is linked to a specific variant of the `Layer`.
 ///
@@ -710,6 +1398,106 @@ pub enum Layer {
     Civilization,
 }
 
+/// The initial value of [`fnv1a_hash`].
+pub(crate) const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+
+/// Feeds `bytes` into the FNV-1a hash `hash`, and returns the new hash.
+///
+/// FNV-1a is stable across platforms and Rust versions unlike `DefaultHasher`.
+/// Start with [`FNV_OFFSET_BASIS`] for a new hash.
+pub(crate) fn fnv1a_hash(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+/// The error returned by [`TileMap::place_natural_wonder_at`] and [`TileMap::set_resource_checked`]
+/// when the natural wonder or the resource can't be placed on the tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementError {
+    /// The tile is a starting tile of a civilization or a city state, or it already has a natural wonder.
+    Occupied(Tile),
+    /// The tile doesn't meet the requirements of the natural wonder, see [`TileMap::natural_wonder_candidates`].
+    Ineligible(Tile, NaturalWonder),
+    /// The tile doesn't meet any of the required terrains of the resource, see [`ResourceInfo::required_terrain`](crate::ruleset::ResourceInfo::required_terrain).
+    IneligibleResource(Tile, Resource),
+}
+
+impl std::fmt::Display for PlacementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlacementError::Occupied(tile) => {
+                write!(f, "Tile {} is already occupied", tile.index())
+            }
+            PlacementError::Ineligible(tile, natural_wonder) => write!(
+                f,
+                "Tile {} is not eligible for the natural wonder {:?}",
+                tile.index(),
+                natural_wonder
+            ),
+            PlacementError::IneligibleResource(tile, resource) => write!(
+                f,
+                "Tile {} is not eligible for the resource {:?}",
+                tile.index(),
+                resource
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PlacementError {}
+
+/// The error returned by [`TileMap::validate_rivers`] when a river is broken.
+///
+/// The first element of each variant is the index of the river in [`TileMap::river_list`],
+/// and the second element is the index of the first invalid edge in the river.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RiverError {
+    /// The flow direction of the edge is not a corner direction of the grid, e.g. [`Direction::East`] on a pointy hex grid.
+    InvalidFlowDirection(usize, usize),
+    /// The edge doesn't start at the corner where the previous edge ends.
+    Discontinuous(usize, usize),
+    /// The flow direction of the edge is not a turn to the left or right from the previous edge, e.g. the river flows back.
+    InconsistentFlow(usize, usize),
+}
+
+impl RiverError {
+    /// Returns the index of the broken river in [`TileMap::river_list`].
+    pub fn river_index(&self) -> usize {
+        match *self {
+            RiverError::InvalidFlowDirection(river_index, _)
+            | RiverError::Discontinuous(river_index, _)
+            | RiverError::InconsistentFlow(river_index, _) => river_index,
+        }
+    }
+
+    /// Returns the index of the first invalid edge in the broken river.
+    pub fn edge_index(&self) -> usize {
+        match *self {
+            RiverError::InvalidFlowDirection(_, edge_index)
+            | RiverError::Discontinuous(_, edge_index)
+            | RiverError::InconsistentFlow(_, edge_index) => edge_index,
+        }
+    }
+}
+
+impl std::fmt::Display for RiverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            RiverError::InvalidFlowDirection(..) => "has an invalid flow direction",
+            RiverError::Discontinuous(..) => "doesn't start where the previous edge ends",
+            RiverError::InconsistentFlow(..) => "doesn't flow on from the previous edge",
+        };
+        write!(
+            f,
+            "Edge {} of river {} {}",
+            self.edge_index(),
+            self.river_index(),
+            reason
+        )
+    }
+}
+
 /// Represents a river in the tile map.
 pub type River = Vec<RiverEdge>;
 
@@ -742,9 +1530,25 @@ impl RiverEdge {
     ///
     /// Returns an array containing the start and end corner directions of the current tile.
     /// According to the start and end corners, we can draw the river edge on the current tile.
-    pub fn start_and_end_corner_directions(&self, grid: HexGrid) -> [Direction; 2] {
+    pub fn start_and_end_corner_directions(&self, grid: MapGrid) -> [Direction; 2] {
         use {Direction::*, HexOrientation::*};
 
+        let grid = match grid {
+            MapGrid::Hex(grid) => grid,
+            MapGrid::Square(_) => {
+                // On square grids, the river edge is on the east or south edge of the tile.
+                return match self.flow_direction {
+                    North => [SouthEast, NorthEast], // North flow connects SE and NE corners
+                    South => [NorthEast, SouthEast], // South flow connects NE and SE corners
+                    East => [SouthWest, SouthEast],  // East flow connects SW and SE corners
+                    West => [SouthEast, SouthWest],  // West flow connects SE and SW corners
+                    NorthEast | SouthEast | SouthWest | NorthWest => {
+                        panic!("Invalid flow direction for square orientation")
+                    }
+                };
+            }
+        };
+
         // Match on both orientation and flow direction simultaneously
         match (grid.layout.orientation, self.flow_direction) {
             // Pointy-top orientation cases
@@ -777,9 +1581,20 @@ impl RiverEdge {
     /// # Returns
     ///
     /// Returns the edge direction corresponding to the given flow direction in the current tile.
-    pub fn edge_direction(&self, grid: HexGrid) -> Direction {
+    pub fn edge_direction(&self, grid: MapGrid) -> Direction {
         use {Direction::*, HexOrientation::*};
 
+        let grid = match grid {
+            MapGrid::Hex(grid) => grid,
+            MapGrid::Square(_) => {
+                return match self.flow_direction {
+                    North | South => East,
+                    East | West => South,
+                    _ => panic!("Invalid flow direction for square orientation"),
+                };
+            }
+        };
+
         match (grid.layout.orientation, self.flow_direction) {
             // Pointy orientation cases
             (Pointy, North | South) => East,
@@ -796,3 +1611,441 @@ impl RiverEdge {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        generate_map,
+        grid::{HexGrid, MapGrid, WorldSizeType},
+        map_parameters::{MapParametersBuilder, MapType, WorldGrid},
+        ruleset::{
+            RegionType,
+            enums::{BaseTerrain, TerrainType},
+        },
+        test_utils::{TEST_SEED, generate_test_map, test_map_parameters},
+        tile::Tile,
+        tile_map::TileMap,
+    };
+
+    /// Tests that raising the sea level threshold never removes a tile from `TileMap::tiles_below_elevation`.
+    #[test]
+    fn test_tiles_below_elevation_monotonic() {
+        let map = generate_test_map();
+
+        let mut previous_tiles = Vec::new();
+        for threshold in 0..=u8::MAX {
+            let tiles = map.tiles_below_elevation(threshold);
+            assert!(
+                previous_tiles.iter().all(|tile| tiles.contains(tile)),
+                "Raising the threshold should not remove any tile"
+            );
+            previous_tiles = tiles;
+        }
+        assert!(!previous_tiles.is_empty());
+    }
+
+    /// Tests that the same `seed_bytes` yields identical maps, which differ from the maps generated by the `u64` seed.
+    #[test]
+    fn test_seed_bytes() {
+        let seed_bytes: [u8; 32] = std::array::from_fn(|i| i as u8);
+
+        let map_parameters = test_map_parameters().seed_bytes(seed_bytes).build();
+        let map_a = generate_map(&map_parameters);
+        let map_b = generate_map(&map_parameters);
+        assert_eq!(map_a, map_b);
+
+        let map_parameters = test_map_parameters().build();
+        let map_c = generate_map(&map_parameters);
+        assert_ne!(map_a.terrain_type_list, map_c.terrain_type_list);
+    }
+
+    /// Tests that the land/water mask and bitmap agree with the land tiles of the map.
+    #[test]
+    fn test_land_water_mask() {
+        let map = generate_test_map();
+
+        let mask = map.land_water_mask();
+        assert_eq!(mask.len(), map.all_tiles().count());
+        assert_eq!(
+            mask.iter().filter(|&&is_land| is_land).count(),
+            map.land_tile_count()
+        );
+
+        let bitmap = map.land_water_bitmap();
+        assert_eq!(bitmap.len(), mask.len().div_ceil(8));
+        for (index, &is_land) in mask.iter().enumerate() {
+            assert_eq!(bitmap[index / 8] >> (index % 8) & 1 == 1, is_land);
+        }
+    }
+
+    /// Tests that the tiles next to the sea are 1 tile away from the coast,
+    /// and the distance of other tiles, including lakes, is 1 more than the nearest neighbor.
+    #[test]
+    fn test_coast_distance_list() {
+        let map_parameters = test_map_parameters().map_type(MapType::Pangaea).build();
+        let map = generate_map(&map_parameters);
+        let grid = map.world_grid.grid;
+
+        let coast_distance_list = map.coast_distance_list();
+
+        for tile in map.all_tiles() {
+            let distance = coast_distance_list[tile];
+            if matches!(
+                tile.base_terrain(&map),
+                BaseTerrain::Coast | BaseTerrain::Ocean
+            ) {
+                assert_eq!(distance, 0);
+            } else {
+                let min_neighbor_distance = tile
+                    .neighbor_tiles(grid)
+                    .map(|neighbor_tile| coast_distance_list[neighbor_tile])
+                    .min()
+                    .unwrap();
+                assert_eq!(distance, min_neighbor_distance + 1);
+            }
+        }
+
+        // Lakes are not the sea.
+        let lake_tile = map
+            .all_tiles()
+            .find(|tile| tile.base_terrain(&map) == BaseTerrain::Lake)
+            .expect("The map should have a lake");
+        assert!(coast_distance_list[lake_tile] > 0);
+
+        // A pangaea has a deep interior.
+        assert!(coast_distance_list.values().any(|&distance| distance > 2));
+    }
+
+    /// Tests that [`TileMap::shuffled`] gives the same order for the same seed, and keeps every item.
+    #[test]
+    fn test_shuffled() {
+        let map_parameters = test_map_parameters().build();
+        let mut map = TileMap::new(&map_parameters);
+        let mut other_map = TileMap::new(&map_parameters);
+
+        let tiles: Vec<Tile> = map.all_tiles().take(100).collect();
+        let shuffled_tiles = map.shuffled(&tiles);
+        assert_eq!(shuffled_tiles, other_map.shuffled(&tiles));
+        assert_ne!(shuffled_tiles, tiles);
+
+        let mut sorted_tiles = shuffled_tiles;
+        sorted_tiles.sort();
+        assert_eq!(sorted_tiles, tiles);
+    }
+
+    /// Tests that a forested hill costs more than bare grassland, and a mountain is impassable.
+    #[test]
+    fn test_movement_cost() {
+        use crate::{grid::OffsetCoordinate, ruleset::enums::Feature};
+
+        let map_parameters = test_map_parameters().build();
+        let mut map = generate_map(&map_parameters);
+        let ruleset = &map_parameters.ruleset;
+
+        let [grassland_tile, forested_hill_tile, mountain_tile] =
+            [0, 1, 2].map(|x| Tile::from_offset(OffsetCoordinate::new(x, 10), map.world_grid.grid));
+
+        for tile in [grassland_tile, forested_hill_tile, mountain_tile] {
+            tile.set_base_terrain(&mut map, BaseTerrain::Grassland);
+            tile.clear_feature(&mut map);
+            tile.clear_natural_wonder(&mut map);
+        }
+        grassland_tile.set_terrain_type(&mut map, TerrainType::Flatland);
+        forested_hill_tile.set_terrain_type(&mut map, TerrainType::Hill);
+        forested_hill_tile.set_feature(&mut map, Feature::Forest);
+        mountain_tile.set_terrain_type(&mut map, TerrainType::Mountain);
+
+        assert_eq!(map.movement_cost(grassland_tile, ruleset), Some(1));
+        // The forest on top of the hill decides the cost, the costs are not added up.
+        assert_eq!(map.movement_cost(forested_hill_tile, ruleset), Some(2));
+        assert_eq!(map.movement_cost(mountain_tile, ruleset), None);
+
+        grassland_tile.set_feature(&mut map, Feature::Marsh);
+        assert_eq!(map.movement_cost(grassland_tile, ruleset), Some(3));
+    }
+
+    /// Tests that the tiles of all the base terrains together are exactly all the tiles of the map,
+    /// and that the other `tiles_with_*` methods agree with the per-tile lists.
+    #[test]
+    fn test_tiles_with() {
+        use crate::ruleset::enums::{Feature, Resource};
+        use enum_map::Enum;
+
+        let map = generate_test_map();
+
+        let mut tiles: Vec<_> = (0..BaseTerrain::LENGTH)
+            .flat_map(|i| map.tiles_with_base_terrain(BaseTerrain::from_usize(i)))
+            .collect();
+        tiles.sort();
+        assert_eq!(tiles, map.all_tiles().collect::<Vec<_>>());
+
+        assert_eq!(
+            map.tiles_with_terrain_type(TerrainType::Hill).count(),
+            map.terrain_type_list
+                .iter()
+                .filter(|&&terrain_type| terrain_type == TerrainType::Hill)
+                .count()
+        );
+        assert!(
+            map.tiles_with_feature(Feature::Forest)
+                .all(|tile| tile.feature(&map) == Some(Feature::Forest))
+        );
+        assert!(
+            map.tiles_with_resource(Resource::Wheat)
+                .all(|tile| tile.resource(&map).unwrap().0 == Resource::Wheat)
+        );
+    }
+
+    /// Tests that [`TileMap::freshwater_tiles`] includes every oasis tile,
+    /// and agrees with [`Tile::is_freshwater`] on the other tiles.
+    #[test]
+    fn test_freshwater_tiles() {
+        use crate::ruleset::enums::Feature;
+
+        let map = generate_test_map();
+
+        let freshwater_tiles = map.freshwater_tiles();
+
+        assert!(
+            map.tiles_with_feature(Feature::Oasis)
+                .all(|tile| freshwater_tiles.contains(&tile))
+        );
+
+        let expected: Vec<_> = map
+            .all_tiles()
+            .filter(|tile| tile.is_freshwater(&map) || tile.feature(&map) == Some(Feature::Oasis))
+            .collect();
+        assert_eq!(freshwater_tiles, expected);
+    }
+
+    /// Tests that every river becomes a polyline with at least one point per river edge.
+    #[test]
+    fn test_river_polylines() {
+        let map = generate_test_map();
+
+        let river_polylines = map.river_polylines();
+
+        assert!(!map.river_list.is_empty());
+        assert_eq!(river_polylines.len(), map.river_list.len());
+        for (polyline, river) in river_polylines.iter().zip(&map.river_list) {
+            assert!(polyline.len() >= river.len());
+        }
+    }
+
+    /// Tests that every river mouth is a coast tile adjacent to both tiles of a river edge.
+    #[test]
+    fn test_river_mouths() {
+        use crate::ruleset::enums::BaseTerrain;
+
+        let map = generate_test_map();
+        let grid = map.world_grid.grid;
+
+        let river_mouths = map.river_mouths();
+        assert!(!river_mouths.is_empty());
+
+        for river_mouth in river_mouths {
+            assert_eq!(river_mouth.base_terrain(&map), BaseTerrain::Coast);
+            assert!(
+                river_mouth
+                    .neighbor_tiles(grid)
+                    .any(|tile| tile.is_land(&map))
+            );
+
+            let neighbor_tiles: Vec<_> = river_mouth.neighbor_tiles(grid).collect();
+            assert!(map.river_list.iter().flatten().any(|river_edge| {
+                let neighbor_tile = river_edge
+                    .tile
+                    .neighbor_tile(river_edge.edge_direction(grid), grid);
+                neighbor_tiles.contains(&river_edge.tile)
+                    && neighbor_tile.is_some_and(|tile| neighbor_tiles.contains(&tile))
+            }));
+        }
+    }
+
+    /// Tests that every civilization starting tile belongs to a region, and that different starting tiles belong to different regions.
+    #[test]
+    fn test_region_of_tile() {
+        use std::collections::HashSet;
+
+        let map = generate_test_map();
+
+        let region_indices: HashSet<usize> = map
+            .starting_tile_and_civilization
+            .keys()
+            .map(|&tile| map.region_of_tile(tile).unwrap())
+            .collect();
+        assert_eq!(
+            region_indices.len(),
+            map.starting_tile_and_civilization.len()
+        );
+
+        for (region_index, region) in map.region_list().iter().enumerate() {
+            let starting_tile = *region.starting_tile.get().unwrap();
+            assert_eq!(map.region_of_tile(starting_tile), Some(region_index));
+        }
+    }
+
+    /// Tests that [`TileMap::impact_value`] reads the impacts of the civilization and city state starts,
+    /// and that [`TileMap::is_blocked`] agrees with it.
+    #[test]
+    fn test_impact_value() {
+        use crate::tile_map::Layer;
+
+        let map = generate_test_map();
+
+        assert!(!map.starting_tile_and_civilization.is_empty());
+        for &tile in map.starting_tile_and_civilization.keys() {
+            assert_eq!(map.impact_value(tile, Layer::Civilization), 99);
+            assert!(map.is_blocked(tile, Layer::Civilization));
+        }
+
+        // A city state can't be placed within the ripple of another element, so any nonzero value blocks it.
+        assert!(!map.starting_tile_and_city_state.is_empty());
+        for &tile in map.starting_tile_and_city_state.keys() {
+            assert_eq!(map.impact_value(tile, Layer::CityState), 99);
+            assert!(map.is_blocked(tile, Layer::CityState));
+        }
+        let rippled_tile = map
+            .all_tiles()
+            .find(|&tile| map.impact_value(tile, Layer::CityState) == 1)
+            .expect("The starting tiles of civilizations should ripple on the city state layer");
+        assert!(map.is_blocked(rippled_tile, Layer::CityState));
+        let empty_tile = map
+            .all_tiles()
+            .find(|&tile| map.impact_value(tile, Layer::CityState) == 0)
+            .unwrap();
+        assert!(!map.is_blocked(empty_tile, Layer::CityState));
+    }
+
+    /// Tests that every region has a defined region type and a land dominant base terrain.
+    #[test]
+    fn test_region_types() {
+        let map = generate_test_map();
+
+        let region_types = map.region_types();
+        assert_eq!(region_types.len(), map.region_list().len());
+        assert!(
+            region_types
+                .iter()
+                .all(|&region_type| region_type != RegionType::Undefined)
+        );
+
+        for region in map.region_list() {
+            let dominant_base_terrain = region.dominant_base_terrain().unwrap();
+            assert!(!matches!(
+                dominant_base_terrain,
+                BaseTerrain::Ocean | BaseTerrain::Coast | BaseTerrain::Lake
+            ));
+        }
+    }
+
+    /// Tests that [`TileMap::nearest_tile_matching`] finds the nearest matching tile across the wrapping map edge,
+    /// and returns `None` when no tile matches.
+    #[test]
+    fn test_nearest_tile_matching() {
+        use crate::grid::{Grid, OffsetCoordinate};
+
+        let map_parameters = test_map_parameters().build();
+        let mut map = TileMap::new(&map_parameters);
+        let grid = map.world_grid.grid;
+        let width = grid.width() as i32;
+
+        for tile in map.all_tiles() {
+            tile.set_terrain_type(&mut map, TerrainType::Flatland);
+            tile.set_base_terrain(&mut map, BaseTerrain::Grassland);
+        }
+
+        let tile_at = |x, y| Tile::from_offset(OffsetCoordinate::new(x, y), grid);
+        let is_water = |tile: Tile| tile.is_water(&map);
+
+        // No water on the map, the whole map is searched.
+        assert_eq!(map.nearest_tile_matching(tile_at(10, 10), is_water), None);
+
+        // A water tile near the left edge, which is closer to the inland tile across the wrapping right edge.
+        let water_tile = tile_at(1, 10);
+        water_tile.set_terrain_type(&mut map, TerrainType::Water);
+        water_tile.set_base_terrain(&mut map, BaseTerrain::Coast);
+
+        let is_water = |tile: Tile| tile.is_water(&map);
+        let inland_tile = tile_at(width - 3, 10);
+        assert_eq!(
+            map.nearest_tile_matching(inland_tile, is_water),
+            Some((water_tile, 4))
+        );
+        assert_eq!(
+            map.nearest_tile_matching(water_tile, is_water),
+            Some((water_tile, 0))
+        );
+    }
+
+    /// Tests that the tiles of an isthmus between two blobs of land are choke points.
+    #[test]
+    fn test_land_choke_points() {
+        use crate::grid::{OffsetCoordinate, Size, WrapFlags};
+
+        let world_size = WorldSizeType::Duel;
+        let grid = MapGrid::Hex(HexGrid {
+            size: Size::new(10, 7),
+            wrap_flags: WrapFlags::empty(),
+            ..HexGrid::default()
+        });
+        let map_parameters = MapParametersBuilder::new(WorldGrid::new(grid, world_size))
+            .seed(TEST_SEED)
+            .build();
+        let mut map = TileMap::new(&map_parameters);
+
+        let tile_at = |x, y| Tile::from_offset(OffsetCoordinate::new(x, y), grid);
+
+        // Two blobs of 7 tiles, joined by the isthmus tile between their nearest tiles.
+        let isthmus_tile = tile_at(4, 3);
+        for blob_center in [tile_at(2, 3), tile_at(6, 3)] {
+            for tile in std::iter::once(blob_center).chain(blob_center.neighbor_tiles(grid)) {
+                tile.set_terrain_type(&mut map, TerrainType::Flatland);
+            }
+        }
+        isthmus_tile.set_terrain_type(&mut map, TerrainType::Flatland);
+
+        // The isthmus tile and the tiles of the blobs next to it.
+        assert_eq!(
+            map.land_choke_points(),
+            vec![tile_at(3, 3), isthmus_tile, tile_at(5, 3)]
+        );
+
+        // Without the isthmus, the blobs have no choke points.
+        isthmus_tile.set_terrain_type(&mut map, TerrainType::Water);
+        assert_eq!(map.land_choke_points(), vec![]);
+    }
+
+    /// Tests that [`TileMap::tile_yield`] adds the yields of the resource, and that a hill overrides the yields of the base terrain.
+    #[test]
+    fn test_tile_yield() {
+        use crate::ruleset::{Ruleset, enums::Resource};
+
+        let ruleset = Ruleset::default();
+        let map_parameters = test_map_parameters().build();
+        let mut map = TileMap::new(&map_parameters);
+
+        let grassland_tile = Tile::new(0);
+        let wheat_tile = Tile::new(1);
+        for tile in [grassland_tile, wheat_tile] {
+            tile.set_terrain_type(&mut map, TerrainType::Flatland);
+            tile.set_base_terrain(&mut map, BaseTerrain::Grassland);
+        }
+        wheat_tile.set_resource(&mut map, Resource::Wheat, 1);
+
+        let grassland_yield = map.tile_yield(grassland_tile, &ruleset);
+        let wheat_yield = map.tile_yield(wheat_tile, &ruleset);
+        assert_eq!(
+            grassland_yield,
+            ruleset.base_terrains[BaseTerrain::Grassland].yields
+        );
+        assert!(wheat_yield.food > grassland_yield.food);
+
+        // A hill overrides the yields of the base terrain.
+        grassland_tile.set_terrain_type(&mut map, TerrainType::Hill);
+        assert_eq!(
+            map.tile_yield(grassland_tile, &ruleset),
+            ruleset.terrain_types[TerrainType::Hill].yields
+        );
+    }
+}